@@ -0,0 +1,75 @@
+/*
+ * Copyright (c) 2024 Jan Range
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ *
+ */
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use mdmodels::{datamodel::DataModel, exporters::Templates};
+use std::path::Path;
+
+/// Templates that are rendered through the Jinja exporter.
+const TEMPLATES: [Templates; 10] = [
+    Templates::XmlSchema,
+    Templates::Markdown,
+    Templates::CompactMarkdown,
+    Templates::Shacl,
+    Templates::Shex,
+    Templates::PythonDataclass,
+    Templates::PythonPydanticXML,
+    Templates::PythonPydantic,
+    Templates::MkDocs,
+    Templates::Typescript,
+];
+
+/// Fixtures used to benchmark the rendering.
+const FIXTURES: [&str; 2] = [
+    "tests/data/model.md",
+    "tests/data/model_full_documentation.md",
+];
+
+fn bench_render(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render");
+
+    for fixture in FIXTURES {
+        let model = DataModel::from_markdown(Path::new(fixture)).expect("Failed to parse fixture");
+        let name = Path::new(fixture).file_stem().unwrap().to_str().unwrap();
+
+        for template in TEMPLATES.iter() {
+            group.bench_with_input(
+                BenchmarkId::new(template.to_string(), name),
+                &model,
+                |b, model| {
+                    b.iter(|| {
+                        model
+                            .clone()
+                            .convert_to(template, None)
+                            .expect("Failed to render template")
+                    })
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_render);
+criterion_main!(benches);
@@ -21,9 +21,11 @@
  *
  */
 
-use crate::{markdown::position::Position, xmltype::XMLType};
+use crate::{
+    exporters::Templates, markdown::position::Position, validation::BASIC_TYPES, xmltype::XMLType,
+};
 use serde::{de::Visitor, Deserialize, Serialize};
-use std::{error::Error, fmt, str::FromStr};
+use std::{collections::HashMap, error::Error, fmt, str::FromStr};
 
 #[cfg(feature = "python")]
 use pyo3::{pyclass, pymethods};
@@ -31,6 +33,9 @@ use pyo3::{pyclass, pymethods};
 #[cfg(feature = "wasm")]
 use tsify_next::Tsify;
 
+/// Option keys whose values are regular expressions.
+pub(crate) const PATTERN_OPTIONS: [&str; 2] = ["pattern", "regex"];
+
 /// Represents an attribute with various properties and options.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "python", pyclass(get_all))]
@@ -50,13 +55,31 @@ pub struct Attribute {
     pub docstring: String,
     /// List of additional options for the attribute.
     pub options: Vec<AttrOption>,
+    /// List of custom options declared in `allow-unknown-options` of the frontmatter.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_options: Vec<AttrOption>,
     /// Term associated with the attribute, if any.
     pub term: Option<String>,
     /// Indicates if the attribute is required.
     pub required: bool,
+    /// Indicates if the attribute is deprecated.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// Indicates if the attribute is read-only, e.g. a server-generated identifier.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub readonly: bool,
+    /// Alternative name used when serializing the attribute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /// Example values of the attribute.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<String>,
     /// Default value for the attribute.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<DataType>,
+    /// Constant value of the attribute, e.g. of a discriminator field.
+    #[serde(rename = "const", default, skip_serializing_if = "Option::is_none")]
+    pub const_value: Option<DataType>,
     /// XML type information for the attribute.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub xml: Option<XMLType>,
@@ -79,12 +102,18 @@ impl Attribute {
             dtypes: Vec::new(),
             docstring: String::new(),
             options: Vec::new(),
+            custom_options: Vec::new(),
             is_array: false,
             is_id: false,
             term: None,
             required,
+            deprecated: false,
+            readonly: false,
+            alias: None,
+            examples: Vec::new(),
             xml: Some(XMLType::from_str(name.as_str()).unwrap()),
             default: None,
+            const_value: None,
             is_enum: false,
             position: None,
         }
@@ -120,7 +149,21 @@ impl Attribute {
             OptionKey::Description => self.docstring = option.value,
             OptionKey::Default => self.default = Some(DataType::from_str(&option.value)?),
             OptionKey::Multiple => self.is_array = option.value.to_lowercase() == "true",
-            OptionKey::Other => self.options.push(option),
+            OptionKey::Deprecated => self.deprecated = option.value.to_lowercase() == "true",
+            OptionKey::Readonly => self.readonly = option.value.to_lowercase() == "true",
+            OptionKey::Alias => self.alias = Some(option.value),
+            OptionKey::Examples => self.examples = split_examples(&option.value),
+            OptionKey::Const => {
+                self.const_value = Some(DataType::from_str(option.value.trim_matches('"'))?)
+            }
+            OptionKey::Known | OptionKey::Other => {
+                if PATTERN_OPTIONS.contains(&option.key.as_str()) {
+                    regex::Regex::new(&option.value)
+                        .map_err(|e| format!("Invalid pattern '{}': {}", option.value, e))?;
+                }
+
+                self.options.push(option)
+            }
             OptionKey::Xml => {
                 self.set_xml(XMLType::from_str(&option.value).expect("Invalid XML type"))
             }
@@ -221,6 +264,112 @@ impl Attribute {
         pattern.replace_all(dtype, "string").to_string()
     }
 
+    /// Converts the attribute to a markdown list item with its options.
+    ///
+    /// Required attributes are written in bold and identifiers use the
+    /// `Identifier` type. Options that hold their default value, such as the
+    /// XML name derived from the attribute name, are omitted.
+    ///
+    /// # Returns
+    ///
+    /// A markdown string that is parsed back into an equivalent attribute.
+    pub fn to_markdown(&self) -> String {
+        let mut lines = Vec::new();
+
+        if self.required {
+            lines.push(format!("- **{}**", self.name));
+        } else {
+            lines.push(format!("- {}", self.name));
+        }
+
+        let single_array = self.is_array && self.dtypes.len() == 1;
+        let dtypes: Vec<String> = self
+            .dtypes
+            .iter()
+            .enumerate()
+            .map(|(i, dtype)| {
+                let dtype = if self.is_id && i == 0 && dtype == "string" {
+                    "Identifier"
+                } else {
+                    dtype.as_str()
+                };
+
+                if single_array {
+                    format!("{}[]", dtype)
+                } else {
+                    dtype.to_string()
+                }
+            })
+            .collect();
+
+        let mut option = |key: &str, value: String| lines.push(format!("  - {}: {}", key, value));
+
+        option("Type", dtypes.join(", "));
+
+        if self.is_array && !single_array {
+            option("Multiple", "true".to_string());
+        }
+
+        if let Some(term) = &self.term {
+            option("Term", term.clone());
+        }
+
+        if !self.docstring.is_empty() {
+            option("Description", self.docstring.clone());
+        }
+
+        if let Some(xml) = &self.xml {
+            if Some(xml) != XMLType::from_str(&self.name).ok().as_ref() {
+                let value = match xml {
+                    XMLType::Attribute { name, .. } => format!("@{}", name),
+                    XMLType::Element { name, .. } => name.clone(),
+                };
+                option("XML", value);
+            }
+        }
+
+        if let Some(default) = &self.default {
+            option("Default", default.to_markdown(false));
+        }
+
+        if let Some(value) = &self.const_value {
+            option("Const", value.to_markdown(true));
+        }
+
+        if self.deprecated {
+            option("Deprecated", "true".to_string());
+        }
+
+        if self.readonly {
+            option("ReadOnly", "true".to_string());
+        }
+
+        if let Some(alias) = &self.alias {
+            option("Alias", alias.clone());
+        }
+
+        if !self.examples.is_empty() {
+            let examples: Vec<String> = self
+                .examples
+                .iter()
+                .map(|example| {
+                    if example.contains(',') {
+                        format!("\"{}\"", example)
+                    } else {
+                        example.clone()
+                    }
+                })
+                .collect();
+            option("Examples", examples.join(", "));
+        }
+
+        for attr_option in self.options.iter().chain(self.custom_options.iter()) {
+            option(&attr_option.key, attr_option.value.clone());
+        }
+
+        lines.join("\n")
+    }
+
     /// Converts the attribute to a JSON schema.
     ///
     /// # Returns
@@ -247,6 +396,155 @@ impl Attribute {
     pub fn set_xml(&mut self, xml: XMLType) {
         self.xml = Some(xml);
     }
+
+    /// Checks if the attribute is marked as read-only via the `ReadOnly` option.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the attribute is read-only, `false` otherwise.
+    pub fn is_readonly(&self) -> bool {
+        self.readonly
+    }
+
+    /// Gets the alternative name used when serializing the attribute.
+    ///
+    /// # Returns
+    ///
+    /// The alias given by the `Alias` option, or `None` if the attribute has none.
+    pub fn get_alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    /// Gets the constant value of the attribute.
+    ///
+    /// # Returns
+    ///
+    /// The value given by the `Const` option, or `None` if the attribute is not constant.
+    pub fn get_const(&self) -> Option<&DataType> {
+        self.const_value.as_ref()
+    }
+
+    /// Checks if the attribute is marked as base64-encoded via the `Base64` option.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the attribute has a `Base64: true` option, `false` otherwise.
+    pub fn is_base64(&self) -> bool {
+        self.get_option("base64")
+            .is_some_and(|o| o.value().trim().eq_ignore_ascii_case("true"))
+    }
+
+    /// Checks if the attribute is excluded from a template via the `Exclude` option.
+    ///
+    /// The option lists template names separated by commas, e.g. `Exclude: typescript, kotlin`.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The template to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the template is listed in the `Exclude` option, `false` otherwise.
+    pub fn is_excluded_from(&self, template: &Templates) -> bool {
+        let template = template.to_string();
+
+        self.options
+            .iter()
+            .filter(|o| o.key == "exclude")
+            .flat_map(|o| o.value.split(','))
+            .any(|name| name.trim().eq_ignore_ascii_case(&template))
+    }
+
+    /// Gets the minimum number of items of an array attribute.
+    ///
+    /// # Returns
+    ///
+    /// The value given by the `MinItems` option, or `None` if it is missing or not a count.
+    pub fn get_min_items(&self) -> Option<usize> {
+        self.get_count_option("minitems")
+    }
+
+    /// Gets the maximum number of items of an array attribute.
+    ///
+    /// # Returns
+    ///
+    /// The value given by the `MaxItems` option, or `None` if it is missing or not a count.
+    pub fn get_max_items(&self) -> Option<usize> {
+        self.get_count_option("maxitems")
+    }
+
+    /// Parses the value of an option as a non-negative integer.
+    fn get_count_option(&self, key: &str) -> Option<usize> {
+        self.get_option(key)
+            .and_then(|o| o.value().trim().parse().ok())
+    }
+
+    /// Gets a stored option by its key.
+    ///
+    /// Keys are matched case-insensitively, as options are stored with lowercase keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the option.
+    ///
+    /// # Returns
+    ///
+    /// The first option with the given key, or `None` if there is none.
+    pub fn get_option(&self, key: &str) -> Option<&AttrOption> {
+        self.options
+            .iter()
+            .find(|o| o.key().eq_ignore_ascii_case(key))
+    }
+
+    /// Checks if an option with the given key is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the option.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the attribute has the option, `false` otherwise.
+    pub fn has_option(&self, key: &str) -> bool {
+        self.get_option(key).is_some()
+    }
+
+    /// Gets all stored options indexed by their key.
+    ///
+    /// # Returns
+    ///
+    /// A map from option keys to options. If a key occurs twice, the first option is kept.
+    pub fn options_map(&self) -> HashMap<&str, &AttrOption> {
+        let mut map = HashMap::new();
+        for option in &self.options {
+            map.entry(option.key()).or_insert(option);
+        }
+        map
+    }
+
+    /// Checks if the attribute is only typed by basic types.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the attribute has data types and all of them are basic types, `false` otherwise.
+    pub fn is_primitive(&self) -> bool {
+        !self.dtypes.is_empty()
+            && self
+                .dtypes
+                .iter()
+                .all(|dtype| BASIC_TYPES.contains(&dtype.as_str()))
+    }
+
+    /// Checks if the attribute references an object or enumeration.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any data type is not a basic type, `false` otherwise.
+    pub fn has_complex_type(&self) -> bool {
+        self.dtypes
+            .iter()
+            .any(|dtype| !BASIC_TYPES.contains(&dtype.as_str()))
+    }
 }
 
 /// Represents an option for an attribute.
@@ -368,6 +666,33 @@ impl PartialEq for DataType {
     }
 }
 
+impl DataType {
+    /// Converts the value to its representation in a markdown option.
+    ///
+    /// # Arguments
+    ///
+    /// * `quoted` - Whether strings are wrapped in double quotes.
+    fn to_markdown(&self, quoted: bool) -> String {
+        match self {
+            DataType::Boolean(b) => b.to_string(),
+            DataType::Integer(i) => i.to_string(),
+            DataType::Float(f) => format!("{:?}", f),
+            DataType::String(s) => {
+                let value = s
+                    .strip_prefix('"')
+                    .and_then(|s| s.strip_suffix('"'))
+                    .unwrap_or(s);
+
+                if quoted {
+                    format!("\"{}\"", value)
+                } else {
+                    value.to_string()
+                }
+            }
+        }
+    }
+}
+
 impl FromStr for DataType {
     type Err = String;
 
@@ -444,6 +769,39 @@ impl<'de> Deserialize<'de> for DataType {
     }
 }
 
+/// Splits a comma-separated list of examples into its values.
+///
+/// Values may be wrapped in double quotes to include commas, the quotes
+/// are removed from the resulting values.
+///
+/// # Arguments
+///
+/// * `value` - The comma-separated list, e.g. `"a", "b", "c"`.
+///
+/// # Returns
+///
+/// The individual example values.
+fn split_examples(value: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for c in value.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => examples.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    examples.push(current);
+
+    examples
+        .into_iter()
+        .map(|example| example.trim().to_string())
+        .filter(|example| !example.is_empty())
+        .collect()
+}
+
 /// Represents the different keys that can be used for attribute options.
 enum OptionKey {
     /// Represents the data type of the attribute.
@@ -458,6 +816,18 @@ enum OptionKey {
     Default,
     /// Indicates if the attribute can have multiple values.
     Multiple,
+    /// Indicates if the attribute is deprecated.
+    Deprecated,
+    /// Indicates if the attribute is read-only.
+    Readonly,
+    /// Represents the serialization name of the attribute.
+    Alias,
+    /// Represents example values of the attribute.
+    Examples,
+    /// Represents the constant value of the attribute.
+    Const,
+    /// Represents options that are kept in `options` and used by the exporters, such as constraints.
+    Known,
     /// Represents any other option not covered by the predefined keys.
     Other,
 }
@@ -480,11 +850,28 @@ impl OptionKey {
             "xml" => OptionKey::Xml,
             "default" => OptionKey::Default,
             "multiple" => OptionKey::Multiple,
+            "deprecated" => OptionKey::Deprecated,
+            "readonly" => OptionKey::Readonly,
+            "alias" => OptionKey::Alias,
+            "examples" => OptionKey::Examples,
+            "const" => OptionKey::Const,
+            "minimum" | "maximum" | "exclusiveminimum" | "exclusivemaximum" | "multipleof"
+            | "minlength" | "maxlength" | "pattern" | "minitems" | "maxitems" | "uniqueitems"
+            | "base64" | "key" | "semact" | "exclude" => OptionKey::Known,
             _ => OptionKey::Other,
         }
     }
 }
 
+/// Checks whether an attribute option is recognized by the parser or the exporters.
+///
+/// # Arguments
+///
+/// * `key` - The key of the option.
+pub(crate) fn is_known_option(key: &str) -> bool {
+    !matches!(OptionKey::from_str(key), OptionKey::Other)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::xmltype::XMLType;
@@ -529,6 +916,77 @@ mod tests {
         assert_eq!(attr.term, Some("string".to_string()));
     }
 
+    #[test]
+    fn test_attribute_add_alias_option() {
+        let mut attr = Attribute::new("gene_name".to_string(), false);
+        assert_eq!(attr.get_alias(), None);
+
+        let option = AttrOption::new("Alias".to_string(), "geneName".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(attr.get_alias(), Some("geneName"));
+        assert!(attr.options.is_empty());
+    }
+
+    #[test]
+    fn test_attribute_add_readonly_option() {
+        let mut attr = Attribute::new("id".to_string(), true);
+        assert!(!attr.is_readonly());
+
+        let option = AttrOption::new("ReadOnly".to_string(), "true".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert!(attr.is_readonly());
+        assert!(attr.options.is_empty());
+    }
+
+    #[test]
+    fn test_attribute_add_const_option() {
+        let mut attr = Attribute::new("kind".to_string(), true);
+        assert_eq!(attr.get_const(), None);
+
+        let option = AttrOption::new("Const".to_string(), "\"circle\"".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(
+            attr.get_const(),
+            Some(&DataType::String("\"circle\"".to_string()))
+        );
+
+        let option = AttrOption::new("const".to_string(), "2".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(attr.get_const(), Some(&DataType::Integer(2)));
+        assert!(attr.options.is_empty());
+    }
+
+    #[test]
+    fn test_attribute_add_examples_option() {
+        let mut attr = Attribute::new("name".to_string(), false);
+        assert!(attr.examples.is_empty());
+
+        let option = AttrOption::new(
+            "Examples".to_string(),
+            r#""Sample A", "Sample B, diluted",plain"#.to_string(),
+        );
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(
+            attr.examples,
+            vec!["Sample A", "Sample B, diluted", "plain"]
+        );
+        assert!(attr.options.is_empty());
+    }
+
+    #[test]
+    fn test_attribute_add_pattern_option() {
+        let mut attr = Attribute::new("name".to_string(), false);
+        let option = AttrOption::new("Pattern".to_string(), r"^\d+$".to_string());
+        attr.add_option(option).expect("Failed to add option");
+
+        let option = AttrOption::new("regex".to_string(), "[a-z".to_string());
+        let result = attr.add_option(option);
+
+        assert!(result.is_err());
+        assert_eq!(attr.options.len(), 1);
+        assert_eq!(attr.options[0].key, "pattern");
+    }
+
     #[test]
     fn test_attribute_add_option() {
         let mut attr = Attribute::new("name".to_string(), false);
@@ -605,6 +1063,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_attribute_is_primitive() {
+        let mut attr = Attribute::new("name".to_string(), false);
+        assert!(!attr.is_primitive());
+        assert!(!attr.has_complex_type());
+
+        attr.dtypes = vec!["string".to_string(), "integer".to_string()];
+        assert!(attr.is_primitive());
+        assert!(!attr.has_complex_type());
+
+        attr.dtypes.push("Person".to_string());
+        assert!(!attr.is_primitive());
+        assert!(attr.has_complex_type());
+    }
+
+    #[test]
+    fn test_attribute_is_excluded_from() {
+        let mut attr = Attribute::new("name".to_string(), false);
+        assert!(!attr.is_excluded_from(&Templates::Typescript));
+
+        attr.add_option(AttrOption::new(
+            "Exclude".to_string(),
+            "Typescript, python-dataclass".to_string(),
+        ))
+        .unwrap();
+
+        assert!(attr.is_excluded_from(&Templates::Typescript));
+        assert!(attr.is_excluded_from(&Templates::PythonDataclass));
+        assert!(!attr.is_excluded_from(&Templates::TypescriptInterface));
+    }
+
+    #[test]
+    fn test_attribute_get_option() {
+        let mut attr = Attribute::new("name".to_string(), false);
+        assert!(attr.get_option("minimum").is_none());
+        assert!(!attr.has_option("minimum"));
+        assert!(attr.options_map().is_empty());
+
+        attr.add_option(AttrOption::new("Minimum".to_string(), "0".to_string()))
+            .unwrap();
+        attr.add_option(AttrOption::new("Maximum".to_string(), "10".to_string()))
+            .unwrap();
+
+        assert_eq!(attr.get_option("minimum").unwrap().value(), "0");
+        assert_eq!(attr.get_option("Maximum").unwrap().value(), "10");
+        assert!(attr.has_option("MINIMUM"));
+        assert!(!attr.has_option("pattern"));
+
+        let options = attr.options_map();
+        assert_eq!(options.len(), 2);
+        assert_eq!(options["maximum"].value(), "10");
+    }
+
     #[test]
     fn test_serialize_data_type() {
         // Test string
@@ -776,4 +1287,12 @@ mod tests {
         let dt = DataType::from_str("");
         assert!(dt.is_err());
     }
+
+    #[test]
+    fn test_is_known_option() {
+        assert!(is_known_option("minimum"));
+        assert!(is_known_option("MaxItems"));
+        assert!(is_known_option("type"));
+        assert!(!is_known_option("mycustomoption"));
+    }
 }
@@ -0,0 +1,415 @@
+/*
+ * Copyright (c) 2024 Jan Range
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ *
+ */
+
+use std::error::Error;
+
+use serde_json::Value;
+
+use crate::attribute::{Attribute, DataType};
+use crate::datamodel::DataModel;
+use crate::object::{Enumeration, Object};
+
+/// Creates a data model from an Avro schema string.
+///
+/// Avro `record` types are mapped to objects and `enum` types to enumerations.
+/// Arrays set `is_array`, unions containing `null` mark an attribute as optional
+/// and the remaining union members become the attribute's data types.
+///
+/// The schema may be a single named type or a list of named types. Named types
+/// nested within fields are extracted as objects or enumerations of their own.
+///
+/// # Arguments
+///
+/// * `content` - The Avro schema as JSON string.
+///
+/// # Returns
+///
+/// A data model or an error if the schema is not valid or uses unsupported types.
+pub fn from_avro_str(content: &str) -> Result<DataModel, Box<dyn Error>> {
+    let schema: Value = serde_json::from_str(content)?;
+    let mut model = DataModel::new(None, None);
+
+    match &schema {
+        Value::Array(schemas) => {
+            for schema in schemas {
+                register_named_type(schema, &mut model)?;
+            }
+        }
+        _ => {
+            register_named_type(&schema, &mut model)?;
+        }
+    }
+
+    let enum_names: Vec<String> = model.enums.iter().map(|e| e.name.clone()).collect();
+    for object in model.objects.iter_mut() {
+        for attr in object.attributes.iter_mut() {
+            attr.is_enum = attr.dtypes.iter().any(|dtype| enum_names.contains(dtype));
+        }
+    }
+
+    Ok(model)
+}
+
+/// Represents the md-models view of an Avro field type.
+#[derive(Debug, Default)]
+struct FieldType {
+    dtypes: Vec<String>,
+    is_array: bool,
+    nullable: bool,
+}
+
+/// Registers a named Avro type (`record` or `enum`) within the data model.
+///
+/// # Arguments
+///
+/// * `schema` - The Avro schema of the named type.
+/// * `model` - The data model to add the type to.
+///
+/// # Returns
+///
+/// The name of the registered type or an error if the schema is not a named type.
+fn register_named_type(schema: &Value, model: &mut DataModel) -> Result<String, Box<dyn Error>> {
+    let kind = schema
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let name = schema
+        .get("name")
+        .and_then(Value::as_str)
+        .map(short_name)
+        .ok_or_else(|| format!("Avro {} type has no name", kind))?;
+
+    match kind {
+        "record" | "error" => {
+            if model.get_object(&name).is_some() {
+                return Err(format!("Avro record '{}' is defined more than once", name).into());
+            }
+
+            // Reserve the position before nested records are registered
+            let index = model.objects.len();
+            model.objects.push(Object::new(name.clone(), None));
+
+            let mut attributes = Vec::new();
+            let fields = schema
+                .get("fields")
+                .and_then(Value::as_array)
+                .ok_or_else(|| format!("Avro record '{}' has no fields", name))?;
+
+            for field in fields {
+                attributes.push(field_to_attribute(field, &name, model)?);
+            }
+
+            let object = &mut model.objects[index];
+            object.attributes = attributes;
+            object.docstring = doc(schema);
+        }
+        "enum" => {
+            if model.enums.iter().any(|e| e.name == name) {
+                return Err(format!("Avro enum '{}' is defined more than once", name).into());
+            }
+
+            let symbols = schema
+                .get("symbols")
+                .and_then(Value::as_array)
+                .ok_or_else(|| format!("Avro enum '{}' has no symbols", name))?;
+
+            model.enums.push(Enumeration {
+                name: name.clone(),
+                mappings: symbols
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|symbol| (symbol.to_string(), symbol.to_string()))
+                    .collect(),
+                docstring: doc(schema),
+                position: None,
+            });
+        }
+        _ => {
+            let kind = if kind.is_empty() { "unknown" } else { kind };
+            return Err(format!("Expected an Avro record or enum, found '{}'", kind).into());
+        }
+    }
+
+    Ok(name)
+}
+
+/// Converts an Avro record field to an attribute.
+///
+/// # Arguments
+///
+/// * `field` - The Avro field definition.
+/// * `record` - The name of the record the field belongs to, used for error messages.
+/// * `model` - The data model to register nested named types in.
+///
+/// # Returns
+///
+/// The converted attribute or an error if the field type is not supported.
+fn field_to_attribute(
+    field: &Value,
+    record: &str,
+    model: &mut DataModel,
+) -> Result<Attribute, Box<dyn Error>> {
+    let name = field
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("Field of Avro record '{}' has no name", record))?;
+    let schema = field
+        .get("type")
+        .ok_or_else(|| format!("Field '{}.{}' has no type", record, name))?;
+
+    let field_type =
+        resolve_type(schema, model).map_err(|e| format!("Field '{}.{}': {}", record, name, e))?;
+
+    let mut attribute = Attribute::new(name.to_string(), !field_type.nullable);
+    attribute.dtypes = field_type.dtypes;
+    attribute.is_array = field_type.is_array;
+    attribute.docstring = doc(field);
+    attribute.default = field.get("default").and_then(to_default);
+
+    Ok(attribute)
+}
+
+/// Resolves an Avro type to md-models data types.
+///
+/// # Arguments
+///
+/// * `schema` - The Avro type, which may be a type name, a union or a complex type.
+/// * `model` - The data model to register nested named types in.
+///
+/// # Returns
+///
+/// The resolved field type or an error if the type is not supported.
+fn resolve_type(schema: &Value, model: &mut DataModel) -> Result<FieldType, Box<dyn Error>> {
+    match schema {
+        Value::String(name) => Ok(FieldType {
+            dtypes: vec![map_primitive(name).unwrap_or_else(|| short_name(name))],
+            ..Default::default()
+        }),
+        Value::Array(members) => resolve_union(members, model),
+        Value::Object(complex) => {
+            let kind = complex
+                .get("type")
+                .ok_or("Complex Avro type has no 'type' key")?;
+
+            match kind.as_str() {
+                Some("record") | Some("error") | Some("enum") => Ok(FieldType {
+                    dtypes: vec![register_named_type(schema, model)?],
+                    ..Default::default()
+                }),
+                Some("array") => {
+                    let items = complex.get("items").ok_or("Avro array has no 'items'")?;
+                    let items = resolve_type(items, model)?;
+
+                    if items.is_array {
+                        return Err("Nested Avro arrays are not supported".into());
+                    }
+
+                    Ok(FieldType {
+                        is_array: true,
+                        ..items
+                    })
+                }
+                Some("fixed") => Ok(FieldType {
+                    dtypes: vec!["bytes".to_string()],
+                    ..Default::default()
+                }),
+                Some("map") => Err("Avro map types are not supported".into()),
+                // Logical types are represented by their underlying primitive type
+                Some(primitive) => Ok(FieldType {
+                    dtypes: vec![map_primitive(primitive).unwrap_or_else(|| short_name(primitive))],
+                    ..Default::default()
+                }),
+                None => resolve_type(kind, model),
+            }
+        }
+        _ => Err(format!("Invalid Avro type '{}'", schema).into()),
+    }
+}
+
+/// Resolves an Avro union to md-models data types.
+///
+/// A `null` member marks the attribute as optional. All other members become
+/// data types of the attribute, where a single array member sets `is_array`.
+fn resolve_union(members: &[Value], model: &mut DataModel) -> Result<FieldType, Box<dyn Error>> {
+    let mut field_type = FieldType::default();
+    let mut resolved = Vec::new();
+
+    for member in members {
+        if member.as_str() == Some("null") {
+            field_type.nullable = true;
+        } else {
+            resolved.push(resolve_type(member, model)?);
+        }
+    }
+
+    if resolved.len() > 1 && resolved.iter().any(|member| member.is_array) {
+        return Err("Avro unions with array members and other types are not supported".into());
+    }
+
+    for member in resolved {
+        field_type.is_array |= member.is_array;
+        field_type.dtypes.extend(member.dtypes);
+    }
+
+    if field_type.dtypes.is_empty() {
+        return Err("Avro union must contain at least one non-null type".into());
+    }
+
+    Ok(field_type)
+}
+
+/// Maps an Avro primitive type to the corresponding md-models type.
+fn map_primitive(name: &str) -> Option<String> {
+    let dtype = match name {
+        "string" => "string",
+        "int" | "long" => "integer",
+        "float" | "double" => "float",
+        "boolean" => "boolean",
+        "bytes" => "bytes",
+        _ => return None,
+    };
+
+    Some(dtype.to_string())
+}
+
+/// Strips the namespace from a full Avro name.
+fn short_name(name: &str) -> String {
+    name.rsplit('.').next().unwrap_or(name).to_string()
+}
+
+/// Returns the documentation of an Avro type or field.
+fn doc(schema: &Value) -> String {
+    schema
+        .get("doc")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Converts an Avro field default to an attribute default.
+fn to_default(value: &Value) -> Option<DataType> {
+    match value {
+        Value::Bool(b) => Some(DataType::Boolean(*b)),
+        Value::Number(n) if n.is_i64() => n.as_i64().map(DataType::Integer),
+        Value::Number(n) => n.as_f64().map(DataType::Float),
+        Value::String(s) => Some(DataType::String(format!("\"{}\"", s))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_avro_str() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model.avsc").unwrap();
+
+        // Act
+        let model = from_avro_str(&content).expect("Failed to import Avro schema");
+
+        // Assert
+        let names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, vec!["Test", "Test2"]);
+        assert_eq!(model.enums.len(), 1);
+        assert_eq!(model.enums[0].name, "Ontology");
+        assert_eq!(model.enums[0].mappings.len(), 3);
+
+        let test = &model.objects[0];
+        assert_eq!(test.docstring, "A test object.");
+
+        let name = &test.attributes[0];
+        assert_eq!(name.name, "name");
+        assert_eq!(name.dtypes, vec!["string"]);
+        assert!(name.required);
+        assert_eq!(name.docstring, "The name of the test.");
+
+        let number = &test.attributes[1];
+        assert_eq!(number.dtypes, vec!["float"]);
+        assert!(!number.required);
+        assert_eq!(number.default.as_ref().unwrap().as_float(), Some(1.0));
+
+        let test2 = &test.attributes[2];
+        assert_eq!(test2.dtypes, vec!["Test2"]);
+        assert!(test2.is_array);
+        assert!(!test2.is_enum);
+
+        let ontology = &test.attributes[3];
+        assert_eq!(ontology.dtypes, vec!["Ontology"]);
+        assert!(ontology.is_enum);
+
+        let value = &test.attributes[4];
+        assert_eq!(value.dtypes, vec!["integer", "string"]);
+        assert!(value.required);
+
+        let created = &test.attributes[5];
+        assert_eq!(created.dtypes, vec!["integer"]);
+    }
+
+    #[test]
+    fn test_from_avro_str_list_of_types() {
+        // Arrange
+        let content = r#"[
+            {"type": "enum", "name": "org.example.Color", "symbols": ["RED", "GREEN"]},
+            {
+                "type": "record",
+                "name": "org.example.Shape",
+                "fields": [
+                    {"name": "color", "type": "org.example.Color"},
+                    {"name": "tags", "type": ["null", {"type": "array", "items": "string"}]}
+                ]
+            }
+        ]"#;
+
+        // Act
+        let model = from_avro_str(content).expect("Failed to import Avro schema");
+
+        // Assert
+        assert_eq!(model.enums[0].name, "Color");
+        let shape = &model.objects[0];
+        assert_eq!(shape.name, "Shape");
+        assert_eq!(shape.attributes[0].dtypes, vec!["Color"]);
+        assert!(shape.attributes[0].is_enum);
+        assert_eq!(shape.attributes[1].dtypes, vec!["string"]);
+        assert!(shape.attributes[1].is_array);
+        assert!(!shape.attributes[1].required);
+    }
+
+    #[test]
+    fn test_from_avro_str_unsupported() {
+        // Arrange
+        let cases = [
+            r#"{"type": "record", "name": "A", "fields": [{"name": "m", "type": {"type": "map", "values": "string"}}]}"#,
+            r#"{"type": "record", "name": "A", "fields": [{"name": "n", "type": ["null"]}]}"#,
+            r#"{"type": "record", "name": "A", "fields": [{"name": "u", "type": ["string", {"type": "array", "items": "int"}]}]}"#,
+            r#""string""#,
+        ];
+
+        // Act & Assert
+        for case in cases {
+            assert!(from_avro_str(case).is_err(), "Expected '{}' to fail", case);
+        }
+    }
+}
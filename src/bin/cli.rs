@@ -29,7 +29,10 @@ use mdmodels::{
     exporters::{render_jinja_template, Templates},
     json::validation::validate_json,
     llm::extraction::query_openai,
-    pipeline::process_pipeline,
+    openapi::OpenApiFormat,
+    pipeline::{dry_run_pipeline, process_pipeline},
+    prelude::FrontMatter,
+    validation::{Validator, WarningType},
 };
 use serde::{Deserialize, Serialize};
 use std::{error::Error, fmt::Display, fs, io::Write, path::PathBuf, str::FromStr};
@@ -57,6 +60,8 @@ enum Commands {
     Extract(ExtractArgs),
     /// Validate a dataset against a markdown model.
     Dataset(DatasetArgs),
+    /// Compare two versions of a markdown model.
+    Diff(DiffArgs),
 }
 
 /// Arguments for the validate subcommand.
@@ -65,6 +70,14 @@ struct ValidateArgs {
     /// Path or URL to the markdown file.
     #[arg(short, long, help = "Path or URL to the markdown file")]
     input: InputType,
+
+    /// Whether to warn about attributes without a description.
+    #[arg(long, help = "Warn about attributes without a description")]
+    require_docstrings: bool,
+
+    /// Whether to print statistics about the model.
+    #[arg(long, help = "Print statistics about the model")]
+    stats: bool,
 }
 
 /// Arguments for the convert subcommand.
@@ -91,12 +104,40 @@ struct ConvertArgs {
     root: Option<String>,
 }
 
+/// Arguments for the diff subcommand.
+#[derive(Parser, Debug)]
+struct DiffArgs {
+    /// Path or URL to the old markdown model.
+    #[arg(help = "Path or URL to the old markdown model")]
+    old: InputType,
+
+    /// Path or URL to the new markdown model.
+    #[arg(help = "Path or URL to the new markdown model")]
+    new: InputType,
+
+    /// Whether to output the differences as Markdown changelog.
+    #[arg(long, help = "Output the differences as Markdown changelog")]
+    changelog: bool,
+
+    /// Whether to output the differences as JSON.
+    #[arg(
+        long,
+        conflicts_with = "changelog",
+        help = "Output the differences as JSON"
+    )]
+    json: bool,
+}
+
 /// Arguments for the pipeline subcommand.
 #[derive(Parser, Debug)]
 struct PipelineArgs {
     /// Path to the pipeline configuration file.
     #[arg(short, long, help = "Path to the pipeline configuration YAML file")]
     input: PathBuf,
+
+    /// Whether to only list the actions of the pipeline.
+    #[arg(long, help = "List the actions of the pipeline without writing files")]
+    dry_run: bool,
 }
 
 /// Arguments for the extract subcommand.
@@ -215,11 +256,12 @@ fn main() -> Result<(), Box<dyn Error>> {
     match args.cmd {
         Commands::Validate(args) => validate(args),
         Commands::Convert(args) => convert(args),
-        Commands::Pipeline(args) => process_pipeline(&args.input),
+        Commands::Pipeline(args) => pipeline(args),
         Commands::Extract(args) => query_llm(args),
         Commands::Dataset(args) => match args.command {
             DatasetCommands::Validate(args) => validate_ds(args),
         },
+        Commands::Diff(args) => diff(args),
     }
 }
 
@@ -234,7 +276,15 @@ fn validate(args: ValidateArgs) -> Result<(), Box<dyn Error>> {
     let path = resolve_input_path(&args.input);
 
     match DataModel::from_markdown(&path) {
-        Ok(_) => {
+        Ok(model) => {
+            if args.stats {
+                println!("{}", model.stats());
+            }
+
+            if args.require_docstrings {
+                log_missing_docstrings(model);
+            }
+
             print_validation_result(true);
             Ok(())
         }
@@ -246,6 +296,27 @@ fn validate(args: ValidateArgs) -> Result<(), Box<dyn Error>> {
     }
 }
 
+/// Logs warnings for attributes without a description.
+///
+/// Models that already set `require-docstrings` have been checked while parsing.
+///
+/// # Arguments
+/// * `model` - The parsed model to check.
+fn log_missing_docstrings(mut model: DataModel) {
+    let config = model.config.get_or_insert_with(FrontMatter::default);
+    if config.require_docstrings {
+        return;
+    }
+    config.require_docstrings = true;
+
+    let mut validator = Validator::new();
+    validator.validate(&model);
+    validator.warnings.retain(|warning| {
+        warning.warning_type == WarningType::DocstringWarning && warning.attribute.is_some()
+    });
+    validator.log_warnings();
+}
+
 /// Prints the result of the validation.
 ///
 /// # Arguments
@@ -319,6 +390,14 @@ fn convert(args: ConvertArgs) -> Result<(), Box<dyn Error>> {
     // Render the template.
     let rendered = match args.template {
         Templates::JsonSchema => model.json_schema(args.root, false)?,
+        Templates::OpenApi => {
+            let format = match &args.output {
+                Some(output) => OpenApiFormat::from_path(output),
+                None => OpenApiFormat::Yaml,
+            };
+            model.to_openapi(args.root, format)?
+        }
+        Templates::JsonLdContext => model.to_jsonld_context()?,
         _ => render_jinja_template(&args.template, &mut model, None)?,
     };
 
@@ -389,6 +468,76 @@ fn render_all_json_schemes(
     Ok(())
 }
 
+/// Runs a pipeline or lists its actions, if `--dry-run` is set.
+///
+/// # Arguments
+///
+/// * `args` - Arguments for the pipeline subcommand.
+fn pipeline(args: PipelineArgs) -> Result<(), Box<dyn Error>> {
+    if !args.dry_run {
+        return process_pipeline(&args.input);
+    }
+
+    let actions = dry_run_pipeline(&args.input)?;
+    let header = (
+        "Template".to_string(),
+        "Merge",
+        "Output".to_string(),
+        "Inputs".to_string(),
+    );
+    let rows: Vec<(String, &str, String, String)> = std::iter::once(header)
+        .chain(actions.iter().map(|action| {
+            let inputs = action
+                .input_paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            (
+                action.template.to_string(),
+                if action.merge { "yes" } else { "no" },
+                action.output_path.display().to_string(),
+                inputs,
+            )
+        }))
+        .collect();
+
+    let template_width = rows.iter().map(|r| r.0.len()).max().unwrap_or(0);
+    let output_width = rows.iter().map(|r| r.2.len()).max().unwrap_or(0);
+
+    for (template, merge, output, inputs) in rows {
+        println!(
+            "{:<template_width$}  {:<5}  {:<output_width$}  {}",
+            template, merge, output, inputs
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints the differences between two markdown models.
+///
+/// # Arguments
+///
+/// * `args` - Arguments for the diff subcommand.
+fn diff(args: DiffArgs) -> Result<(), Box<dyn Error>> {
+    // Remote inputs share a temporary file, hence parse one model at a time
+    let old = DataModel::from_markdown(&resolve_input_path(&args.old))?;
+    let new = DataModel::from_markdown(&resolve_input_path(&args.new))?;
+    let diff = old.diff(&new);
+
+    if args.changelog {
+        print!("{}", diff.to_changelog());
+    } else if args.json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+    } else {
+        print!("{}", diff.summary());
+    }
+
+    Ok(())
+}
+
 /// Validates a dataset against a markdown model.
 fn validate_ds(args: ValidateDatasetArgs) -> Result<(), Box<dyn Error>> {
     let model_path = resolve_input_path(&args.model);
@@ -436,6 +585,45 @@ mod tests {
         assert.success();
     }
 
+    #[test]
+    fn test_validation_require_docstrings() {
+        let run = |require_docstrings: bool| {
+            let mut cmd = Command::cargo_bin("md-models").unwrap();
+            cmd.env("RUST_LOG", "warn")
+                .arg("validate")
+                .arg("-i")
+                .arg("tests/data/model.md");
+
+            if require_docstrings {
+                cmd.arg("--require-docstrings");
+            }
+
+            let output = cmd.output().unwrap();
+            assert!(output.status.success());
+            String::from_utf8_lossy(&output.stderr).to_string()
+        };
+
+        let warning = "Property 'number' has no description.";
+        assert!(run(true).contains(warning));
+        assert!(!run(false).contains(warning));
+        assert!(!run(true).contains("Property 'name' has no description."));
+    }
+
+    #[test]
+    fn test_validation_stats() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("validate")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("--stats")
+            .assert();
+        let output = assert.success().get_output().stdout.clone();
+        let stdout = String::from_utf8(output).unwrap();
+        assert!(stdout.contains("Objects"));
+        assert!(stdout.contains("Term coverage"));
+    }
+
     #[test]
     fn test_failed_validation_result() {
         let mut cmd = Command::cargo_bin("md-models").unwrap();
@@ -460,6 +648,19 @@ mod tests {
         assert.success();
     }
 
+    #[test]
+    fn test_plantuml_conversion() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("plantuml")
+            .assert();
+        assert.success();
+    }
+
     #[test]
     fn test_json_schema_no_root() {
         let mut cmd = Command::cargo_bin("md-models").unwrap();
@@ -505,4 +706,46 @@ mod tests {
             .assert();
         assert.failure();
     }
+
+    #[test]
+    fn test_pipeline_dry_run() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("pipeline")
+            .arg("-i")
+            .arg("tests/data/pipeline/monolithic.toml")
+            .arg("--dry-run")
+            .assert();
+
+        let output = assert.success().get_output().stdout.clone();
+        let stdout = String::from_utf8(output).unwrap();
+        assert!(stdout.starts_with("Template"));
+        assert!(!std::path::Path::new("tests/data/pipeline/gen").exists());
+    }
+
+    #[test]
+    fn test_diff_changelog() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let expected = fs::read_to_string("tests/data/expected_changelog.md").unwrap();
+        let assert = cmd
+            .arg("diff")
+            .arg("tests/data/model_diff_v1.md")
+            .arg("tests/data/model_diff_v2.md")
+            .arg("--changelog")
+            .assert();
+        assert.success().stdout(expected);
+    }
+
+    #[test]
+    fn test_diff_summary() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("diff")
+            .arg("tests/data/model_diff_v1.md")
+            .arg("tests/data/model_diff_v2.md")
+            .assert();
+        let output = assert.success().get_output().stdout.clone();
+        let stdout = String::from_utf8(output).unwrap();
+        assert!(stdout.contains("~ Object Person -> Author"));
+    }
 }
@@ -26,9 +26,12 @@ use std::path::Path;
 
 use crate::attribute::Attribute;
 use crate::datamodel;
-use crate::exporters::Templates;
+use crate::exporters::{TemplateConfig, Templates};
 use crate::markdown::frontmatter::FrontMatter;
+use crate::markdown::position::Position;
 use crate::object::{Enumeration, Object};
+use crate::validation::{validate_markdown_str, Validator};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 
@@ -101,11 +104,108 @@ impl DataModel {
         template: Templates,
         config: Option<HashMap<String, String>>,
     ) -> String {
-        let config = config.unwrap_or_default();
+        let config = TemplateConfig::from_hashmap(&config.unwrap_or_default());
         self.model
             .convert_to(&template, Some(&config))
             .expect("Failed to convert to template")
     }
+
+    /// Validates the `DataModel` instance.
+    ///
+    /// # Returns
+    ///
+    /// The number of validation warnings or a `ValueError` listing the
+    /// validation errors.
+    fn validate(&self) -> PyResult<usize> {
+        let mut validator = Validator::new();
+        validator.validate(&self.model);
+
+        if !validator.is_valid {
+            return Err(PyValueError::new_err(validator.to_string()));
+        }
+
+        Ok(validator.warnings.len())
+    }
+
+    /// Returns the semantic version of the `DataModel` instance.
+    ///
+    /// # Returns
+    ///
+    /// The version string or `None` if no valid version is set.
+    fn schema_version(&self) -> Option<String> {
+        self.model
+            .schema_version()
+            .map(|version| version.to_string())
+    }
+
+    /// Checks whether another `DataModel` satisfies the version requirement of this instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other `DataModel` to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the version of `other` satisfies the requirement.
+    fn is_compatible_with(&self, other: &DataModel) -> bool {
+        self.model.is_compatible_with(&other.model)
+    }
+
+    /// Returns all objects with at least one attribute of the given type.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The name of the referenced object or enumeration.
+    ///
+    /// # Returns
+    ///
+    /// A list of the referencing objects.
+    fn objects_referencing(&self, type_name: String) -> Vec<Object> {
+        self.model
+            .objects_referencing(&type_name)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns all attributes of the given type along with their object names.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The name of the referenced object or enumeration.
+    ///
+    /// # Returns
+    ///
+    /// A list of tuples of object names and the referencing attributes.
+    fn attributes_referencing(&self, type_name: String) -> Vec<(String, Attribute)> {
+        self.model
+            .attributes_referencing(&type_name)
+            .into_iter()
+            .map(|(object, attr)| (object.name.clone(), attr.clone()))
+            .collect()
+    }
+
+    /// Returns all enumerations that are not used by any attribute.
+    ///
+    /// # Returns
+    ///
+    /// A list of the unreferenced enumerations.
+    fn unreferenced_enums(&self) -> Vec<Enumeration> {
+        self.model
+            .unreferenced_enums()
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Removes all enumerations that are not used by any attribute.
+    ///
+    /// # Returns
+    ///
+    /// A list of the names of the removed enumerations.
+    fn prune_unused_enums(&mut self) -> Vec<String> {
+        self.model.prune_unused_enums()
+    }
 }
 
 #[pymethods]
@@ -118,6 +218,82 @@ impl Object {
     pub fn __repr__(&self) -> String {
         serde_json::to_string_pretty(&self).unwrap()
     }
+
+    /// Returns the name of the `Object`.
+    #[getter]
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Returns the attributes of the `Object`.
+    #[getter]
+    fn attributes(&self) -> Vec<Attribute> {
+        self.attributes.clone()
+    }
+
+    /// Returns the docstring of the `Object`.
+    #[getter]
+    fn docstring(&self) -> String {
+        self.docstring.clone()
+    }
+
+    /// Returns the term of the `Object`, if any.
+    #[getter]
+    fn term(&self) -> Option<String> {
+        self.term.clone()
+    }
+
+    /// Returns the parent of the `Object`, if any.
+    #[getter]
+    fn parent(&self) -> Option<String> {
+        self.parent.clone()
+    }
+
+    /// Returns the position of the `Object` in the markdown file, if any.
+    #[getter]
+    fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Returns the example instances of the `Object` as JSON strings.
+    ///
+    /// # Returns
+    ///
+    /// A vector of JSON strings, one per example.
+    #[getter]
+    fn examples(&self) -> Vec<String> {
+        self.examples
+            .iter()
+            .map(|example| example.to_string())
+            .collect()
+    }
+}
+
+#[pymethods]
+impl Validator {
+    /// Returns a string representation of the `Validator` instance.
+    ///
+    /// # Returns
+    ///
+    /// A string that represents the `Validator` instance.
+    fn __repr__(&self) -> String {
+        serde_json::to_string_pretty(&self).unwrap()
+    }
+}
+
+/// Validates markdown model content without building a `DataModel`.
+///
+/// # Arguments
+///
+/// * `content` - A string slice that holds the markdown content.
+///
+/// # Returns
+///
+/// A `Validator` holding the validation errors, which is empty for valid models.
+#[pyfunction]
+#[pyo3(signature = (content))]
+pub fn validate(content: String) -> Validator {
+    validate_markdown_str(&content)
 }
 
 #[pymethods]
@@ -21,10 +21,15 @@
  *
  */
 
+use std::collections::HashMap;
+use std::str::FromStr;
+
 use crate::datamodel::DataModel;
-use crate::exporters::Templates;
-use crate::json::export::to_json_schema;
-use crate::validation::Validator;
+use crate::exporters::{TemplateConfig, Templates};
+use crate::json::export::{to_json_schema, JsonSchemaDraft, JsonSchemaInheritance};
+use crate::openapi::OpenApiFormat;
+use crate::validation::{validate_markdown_str, ValidationError, ValidationWarning, Validator};
+use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
 // Add console.log support for debugging
@@ -55,6 +60,7 @@ pub fn parse_model(markdown_content: &str) -> Result<DataModel, JsError> {
 ///
 /// * `markdown_content` - A string slice that holds the markdown content to be converted.
 /// * `template` - The template format to convert the markdown content into.
+/// * `config_json` - An optional JSON object of template options, e.g. `{"nav": "false"}`.
 ///
 /// # Returns
 ///
@@ -62,15 +68,102 @@ pub fn parse_model(markdown_content: &str) -> Result<DataModel, JsError> {
 /// - `Ok(String)` if the conversion is successful.
 /// - `Err(JsValue)` if there is an error during parsing or conversion.
 #[wasm_bindgen]
-pub fn convert_to(markdown_content: &str, template: Templates) -> Result<String, JsValue> {
+pub fn convert_to(
+    markdown_content: &str,
+    template: Templates,
+    config_json: Option<String>,
+) -> Result<String, JsValue> {
+    let options = parse_template_options(config_json.as_deref().unwrap_or_default())
+        .map_err(|e| JsValue::from_str(&e))?;
+    let config = TemplateConfig::from_hashmap(&options);
+
     let mut model = DataModel::from_markdown_string(markdown_content)
         .map_err(|e| JsValue::from_str(&format!("Error parsing markdown content: {}", e)))?;
 
     model
-        .convert_to(&template, None)
+        .convert_to(&template, Some(&config))
         .map_err(|e| JsValue::from_str(&format!("Error converting markdown content: {}", e)))
 }
 
+/// Renders the given markdown content with a template given by its name.
+///
+/// Unlike `convert_to`, the template is selected by the same names as in the
+/// CLI and pipelines, and template options can be passed as JSON object.
+///
+/// # Arguments
+///
+/// * `markdown` - A string slice that holds the markdown content to be rendered.
+/// * `template` - The name of the template, e.g. `python-pydantic` or `json-schema`.
+/// * `config_json` - A JSON object of template options, e.g. `{"draft07": "true"}`. May be empty.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(String)` if the rendering is successful.
+/// - `Err(JsValue)` if the template is unknown, the options are invalid or rendering fails.
+#[wasm_bindgen]
+pub fn render_template(
+    markdown: &str,
+    template: &str,
+    config_json: &str,
+) -> Result<String, JsValue> {
+    render(markdown, template, config_json).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Renders markdown content with a named template, see `render_template`.
+fn render(markdown: &str, template: &str, config_json: &str) -> Result<String, String> {
+    let template = Templates::from_str(template).map_err(|e| e.to_string())?;
+    let options = parse_template_options(config_json)?;
+    let config = TemplateConfig::from_hashmap(&options);
+
+    let mut model = DataModel::from_markdown_string(markdown)
+        .map_err(|e| format!("Error parsing markdown content: {}", e))?;
+
+    let rendered = match template {
+        Templates::JsonSchema => {
+            let root = match options.get("root") {
+                Some(root) => root.clone(),
+                None => model
+                    .objects
+                    .first()
+                    .ok_or("No objects found in model")?
+                    .name
+                    .clone(),
+            };
+
+            let schema = to_json_schema(&model, &root, false, config.draft(), config.inheritance())
+                .map_err(|e| format!("Error serializing schema: {}", e))?;
+            serde_json::to_string_pretty(&schema).map_err(|e| e.to_string())?
+        }
+        Templates::OpenApi => model
+            .to_openapi(options.get("root").cloned(), OpenApiFormat::Yaml)
+            .map_err(|e| format!("Error rendering OpenAPI specification: {}", e))?,
+        Templates::Internal => model.internal_schema(),
+        Templates::JsonLdContext => model
+            .to_jsonld_context()
+            .map_err(|e| format!("Error rendering JSON-LD context: {}", e))?,
+        Templates::JsonSchemaAll => {
+            return Err(
+                "Template 'json-schema-all' renders multiple files and is not supported".into(),
+            )
+        }
+        _ => model
+            .convert_to(&template, Some(&config))
+            .map_err(|e| format!("Error converting markdown content: {}", e))?,
+    };
+
+    Ok(rendered)
+}
+
+/// Parses template options given as JSON object, an empty string yields no options.
+fn parse_template_options(config_json: &str) -> Result<HashMap<String, String>, String> {
+    if config_json.trim().is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    serde_json::from_str(config_json).map_err(|e| format!("Invalid template options: {}", e))
+}
+
 /// Returns the JSON schema for the given markdown content.
 ///
 /// # Arguments
@@ -103,8 +196,14 @@ pub fn json_schema(
             .clone(),
     };
 
-    let json_schema = to_json_schema(&model, &root, openai)
-        .map_err(|e| JsValue::from_str(&format!("Error serializing schema: {}", e)))?;
+    let json_schema = to_json_schema(
+        &model,
+        &root,
+        openai,
+        JsonSchemaDraft::Draft202012,
+        JsonSchemaInheritance::Inline,
+    )
+    .map_err(|e| JsValue::from_str(&format!("Error serializing schema: {}", e)))?;
 
     // Directly return the JSON schema object instead of converting it to a JsValue
     Ok(serde_json::to_string(&json_schema).unwrap())
@@ -121,9 +220,91 @@ pub fn json_schema(
 /// Either an empty `Validator` or an error `Validator`.
 #[wasm_bindgen]
 pub fn validate(markdown_content: &str) -> Validator {
-    let model = DataModel::from_markdown_string(markdown_content);
-    match model {
-        Ok(_) => Validator::new(),
+    validate_markdown_str(markdown_content)
+}
+
+/// The result of a validation, serialized as
+/// `{ isValid: boolean, errors: ValidationError[], warnings: ValidationWarning[] }`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ValidationResult<'a> {
+    is_valid: bool,
+    errors: &'a [ValidationError],
+    warnings: &'a [ValidationWarning],
+}
+
+/// Validates the given markdown content and returns the result as plain JavaScript object.
+///
+/// # Arguments
+///
+/// * `markdown_content` - A string slice that holds the markdown content to be validated.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(JsValue)` with an object of the form `{ isValid, errors, warnings }`.
+/// - `Err(JsValue)` if the result cannot be serialized.
+#[wasm_bindgen]
+pub fn validate_model(markdown_content: &str) -> Result<JsValue, JsValue> {
+    let validator = match DataModel::from_markdown_string(markdown_content) {
+        Ok(model) => {
+            // Warnings of valid models are not returned by the parser
+            let mut validator = Validator::new();
+            validator.validate(&model);
+            validator
+        }
         Err(res) => res,
-    }
+    };
+
+    let result = ValidationResult {
+        is_valid: validator.is_valid,
+        errors: &validator.errors,
+        warnings: &validator.warnings,
+    };
+
+    result
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsValue::from_str(&format!("Error serializing validation result: {}", e)))
+}
+
+/// Returns the semantic version of the given markdown content.
+///
+/// # Arguments
+///
+/// * `markdown_content` - A string slice that holds the markdown content.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(Some(String))` if a valid version is set in the frontmatter.
+/// - `Ok(None)` if no valid version is set.
+/// - `Err(JsValue)` if there is an error during parsing.
+#[wasm_bindgen]
+pub fn schema_version(markdown_content: &str) -> Result<Option<String>, JsValue> {
+    let model = DataModel::from_markdown_string(markdown_content)
+        .map_err(|e| JsValue::from_str(&format!("Error parsing markdown content: {}", e)))?;
+
+    Ok(model.schema_version().map(|version| version.to_string()))
+}
+
+/// Checks whether the version of another model satisfies the version requirement of a model.
+///
+/// # Arguments
+///
+/// * `markdown_content` - A string slice that holds the markdown content with the requirement.
+/// * `other_content` - A string slice that holds the markdown content of the other model.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(bool)` indicating whether the models are compatible.
+/// - `Err(JsValue)` if there is an error during parsing.
+#[wasm_bindgen]
+pub fn is_compatible_with(markdown_content: &str, other_content: &str) -> Result<bool, JsValue> {
+    let model = DataModel::from_markdown_string(markdown_content)
+        .map_err(|e| JsValue::from_str(&format!("Error parsing markdown content: {}", e)))?;
+    let other = DataModel::from_markdown_string(other_content)
+        .map_err(|e| JsValue::from_str(&format!("Error parsing markdown content: {}", e)))?;
+
+    Ok(model.is_compatible_with(&other))
 }
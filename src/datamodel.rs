@@ -25,17 +25,29 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::{error::Error, fs, path::Path};
 
-use log::error;
+use log::warn;
 use serde::{Deserialize, Serialize};
 
-use crate::exporters::{render_jinja_template, Templates};
-use crate::json::export::to_json_schema;
+use crate::attribute::Attribute;
+use crate::avro::import::from_avro_str;
+use crate::diff::ModelDiff;
+use crate::exporters::{render_jinja_template, TemplateConfig, Templates};
+use crate::json::export::{to_json_schema, JsonSchemaDraft, JsonSchemaInheritance};
+use crate::json::import::from_json_schema_str;
 use crate::json::validation::{validate_json, ValidationError};
+use crate::jsonld::to_jsonld_context;
 use crate::markdown::frontmatter::FrontMatter;
-use crate::markdown::parser::parse_markdown;
+use crate::markdown::parser::{
+    finalize_model, parse_markdown, parse_markdown_unvalidated, set_enum_attributes,
+};
 use crate::object::{Enumeration, Object};
-use crate::validation::Validator;
+use crate::openapi::{to_openapi, OpenApiFormat};
+use crate::proto::import::from_proto;
+use crate::tree::{dependency_graph, find_cycles, has_cycles, reachable_from, topological_order};
+use crate::validation::{check_object_name, ErrorType, Validator};
 use colored::Colorize;
+use lazy_static::lazy_static;
+use semver::{Version, VersionReq};
 
 #[cfg(feature = "python")]
 use pyo3::pyclass;
@@ -78,6 +90,11 @@ pub struct DataModel {
     pub config: Option<FrontMatter>,
 }
 
+lazy_static! {
+    /// Configuration used for models that do not declare a frontmatter.
+    static ref DEFAULT_CONFIG: FrontMatter = FrontMatter::default();
+}
+
 impl DataModel {
     pub fn new(name: Option<String>, config: Option<FrontMatter>) -> Self {
         DataModel {
@@ -88,6 +105,311 @@ impl DataModel {
         }
     }
 
+    /// Returns the configuration of the model.
+    ///
+    /// Falls back to the default frontmatter if the model does not declare one.
+    pub fn config(&self) -> &FrontMatter {
+        self.config.as_ref().unwrap_or(&DEFAULT_CONFIG)
+    }
+
+    /// Creates a builder to construct a data model programmatically
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdmodels::prelude::*;
+    ///
+    /// let mut attribute = Attribute::new("name".to_string(), true);
+    /// attribute.dtypes.push("string".to_string());
+    ///
+    /// let mut object = Object::new("Sample".to_string(), None);
+    /// object.add_attribute(attribute);
+    ///
+    /// let model = DataModel::builder()
+    ///     .set_name("Samples".to_string())
+    ///     .add_object(object)
+    ///     .add_enum(Enumeration::from_values("Status", &["active", "inactive"]))
+    ///     .build()
+    ///     .expect("Model should be valid");
+    ///
+    /// assert_eq!(model.objects.len(), 1);
+    /// assert_eq!(model.enums.len(), 1);
+    /// ```
+    /// # Returns
+    /// An empty `DataModelBuilder`
+    pub fn builder() -> DataModelBuilder {
+        DataModelBuilder::default()
+    }
+
+    /// Returns the object with the given name
+    ///
+    /// * `name` - Name of the object
+    ///
+    /// # Returns
+    /// A reference to the object or `None` if the model has no such object
+    pub fn get_object(&self, name: &str) -> Option<&Object> {
+        self.objects.iter().find(|o| o.name == name)
+    }
+
+    /// Returns the object with the given name for modification
+    ///
+    /// * `name` - Name of the object
+    ///
+    /// # Returns
+    /// A mutable reference to the object or `None` if the model has no such object
+    pub fn get_object_mut(&mut self, name: &str) -> Option<&mut Object> {
+        self.objects.iter_mut().find(|o| o.name == name)
+    }
+
+    /// Returns the enumeration with the given name
+    ///
+    /// * `name` - Name of the enumeration
+    ///
+    /// # Returns
+    /// A reference to the enumeration or `None` if the model has no such enumeration
+    pub fn get_enum(&self, name: &str) -> Option<&Enumeration> {
+        self.enums.iter().find(|e| e.name == name)
+    }
+
+    /// Returns the enumeration with the given name for modification
+    ///
+    /// * `name` - Name of the enumeration
+    ///
+    /// # Returns
+    /// A mutable reference to the enumeration or `None` if the model has no such enumeration
+    pub fn get_enum_mut(&mut self, name: &str) -> Option<&mut Enumeration> {
+        self.enums.iter_mut().find(|e| e.name == name)
+    }
+
+    /// Adds an object to the data model
+    ///
+    /// * `object` - The object to add, which must not share its name with another type
+    ///
+    /// # Errors
+    /// `DuplicateError` if an object or enumeration with the same name exists
+    pub fn add_object(&mut self, object: Object) -> Result<(), DuplicateError> {
+        if self.is_defined(&object.name) {
+            return Err(DuplicateError(object.name));
+        }
+
+        self.add_object_unchecked(object);
+        Ok(())
+    }
+
+    /// Adds an object to the data model without checking for duplicates
+    ///
+    /// * `object` - The object to add, whose name has already been checked
+    pub fn add_object_unchecked(&mut self, object: Object) {
+        self.objects.push(object);
+    }
+
+    /// Adds an enumeration to the data model
+    ///
+    /// * `enumeration` - The enumeration to add, which must not share its name with another type
+    ///
+    /// # Errors
+    /// `DuplicateError` if an object or enumeration with the same name exists
+    pub fn add_enum(&mut self, enumeration: Enumeration) -> Result<(), DuplicateError> {
+        if self.is_defined(&enumeration.name) {
+            return Err(DuplicateError(enumeration.name));
+        }
+
+        self.add_enum_unchecked(enumeration);
+        Ok(())
+    }
+
+    /// Adds an enumeration to the data model without checking for duplicates
+    ///
+    /// * `enumeration` - The enumeration to add, whose name has already been checked
+    pub fn add_enum_unchecked(&mut self, enumeration: Enumeration) {
+        self.enums.push(enumeration);
+    }
+
+    /// Checks whether an object or enumeration with the given name exists
+    fn is_defined(&self, name: &str) -> bool {
+        self.get_object(name).is_some() || self.get_enum(name).is_some()
+    }
+
+    /// Looks up an attribute by the name of its object and its own name
+    ///
+    /// # Arguments
+    /// * `object_name` - The name of the object
+    /// * `attr_name` - The name of the attribute
+    ///
+    /// # Returns
+    /// The object and its attribute or `None` if either does not exist
+    pub fn find_attribute(
+        &self,
+        object_name: &str,
+        attr_name: &str,
+    ) -> Option<(&Object, &Attribute)> {
+        let object = self.get_object(object_name)?;
+        let attribute = object.attributes.iter().find(|a| a.name == attr_name)?;
+
+        Some((object, attribute))
+    }
+
+    /// Looks up an attribute for modification by the name of its object and its own name
+    ///
+    /// The object itself cannot be borrowed while its attribute is modified,
+    /// hence only the attribute is returned.
+    ///
+    /// # Arguments
+    /// * `object_name` - The name of the object
+    /// * `attr_name` - The name of the attribute
+    ///
+    /// # Returns
+    /// A mutable reference to the attribute or `None` if either does not exist
+    pub fn find_attribute_mut(
+        &mut self,
+        object_name: &str,
+        attr_name: &str,
+    ) -> Option<&mut Attribute> {
+        self.get_object_mut(object_name)?
+            .attributes
+            .iter_mut()
+            .find(|a| a.name == attr_name)
+    }
+
+    /// Returns all objects having an attribute of the given name
+    ///
+    /// # Arguments
+    /// * `attr_name` - The name of the attribute
+    ///
+    /// # Returns
+    /// Pairs of objects and their attribute in the order of the model
+    pub fn find_object_by_attribute(&self, attr_name: &str) -> Vec<(&Object, &Attribute)> {
+        self.all_attributes()
+            .filter(|(_, attr)| attr.name == attr_name)
+            .collect()
+    }
+
+    /// Iterates over the attributes of all objects
+    ///
+    /// Attributes are yielded object by object, in the order of the model.
+    ///
+    /// # Returns
+    /// An iterator over pairs of objects and their attributes
+    pub fn all_attributes(&self) -> impl Iterator<Item = (&Object, &Attribute)> {
+        self.objects
+            .iter()
+            .flat_map(|object| object.attributes.iter().map(move |attr| (object, attr)))
+    }
+
+    /// Computes aggregate statistics about the model for quality audits
+    ///
+    /// # Returns
+    /// A `ModelStats` report, which can be displayed as a table
+    pub fn stats(&self) -> ModelStats {
+        let total_attributes = self.objects.iter().map(|o| o.attribute_count()).sum();
+        let documented_attributes = self
+            .all_attributes()
+            .filter(|(_, attr)| !attr.docstring.is_empty())
+            .count();
+        let term_annotated_attributes = self
+            .all_attributes()
+            .filter(|(_, attr)| attr.has_term())
+            .count();
+
+        let ratio = |count: usize| {
+            if total_attributes == 0 {
+                0.0
+            } else {
+                count as f64 / total_attributes as f64
+            }
+        };
+
+        ModelStats {
+            object_count: self.objects.len(),
+            enum_count: self.enums.len(),
+            total_attributes,
+            required_attributes: self.objects.iter().map(|o| o.required_count()).sum(),
+            array_attributes: self.objects.iter().map(|o| o.array_count()).sum(),
+            documented_objects: self
+                .objects
+                .iter()
+                .filter(|o| !o.docstring.is_empty())
+                .count(),
+            documented_attributes,
+            term_annotated_attributes,
+            documentation_coverage: ratio(documented_attributes),
+            term_coverage: ratio(term_annotated_attributes),
+        }
+    }
+
+    /// Iterates over the attributes of all objects for modification
+    ///
+    /// The object itself cannot be borrowed while its attributes are modified,
+    /// hence only the name of the object is yielded.
+    ///
+    /// # Returns
+    /// An iterator over pairs of object names and their attributes
+    pub fn all_attributes_mut(&mut self) -> impl Iterator<Item = (&str, &mut Attribute)> {
+        self.objects.iter_mut().flat_map(|object| {
+            let name = object.name.as_str();
+            object.attributes.iter_mut().map(move |attr| (name, attr))
+        })
+    }
+
+    /// Returns all objects with at least one attribute of the given type
+    ///
+    /// Array and union types are considered as well, since each of their
+    /// types is listed in the `dtypes` of the attribute.
+    ///
+    /// # Arguments
+    /// * `type_name` - The name of the referenced object or enumeration
+    ///
+    /// # Returns
+    /// The referencing objects in the order of the model
+    pub fn objects_referencing(&self, type_name: &str) -> Vec<&Object> {
+        self.objects
+            .iter()
+            .filter(|object| object.attributes_by_dtype(type_name).next().is_some())
+            .collect()
+    }
+
+    /// Returns all attributes of the given type along with their objects
+    ///
+    /// # Arguments
+    /// * `type_name` - The name of the referenced object or enumeration
+    ///
+    /// # Returns
+    /// Pairs of objects and their referencing attributes in the order of the model
+    pub fn attributes_referencing(&self, type_name: &str) -> Vec<(&Object, &Attribute)> {
+        self.all_attributes()
+            .filter(|(_, attr)| attr.dtypes.iter().any(|dtype| dtype == type_name))
+            .collect()
+    }
+
+    /// Returns all enumerations that are not used by any attribute
+    ///
+    /// # Returns
+    /// The unreferenced enumerations in the order of the model
+    pub fn unreferenced_enums(&self) -> Vec<&Enumeration> {
+        self.enums
+            .iter()
+            .filter(|e| self.objects_referencing(&e.name).is_empty())
+            .collect()
+    }
+
+    /// Removes all enumerations that are not used by any attribute
+    ///
+    /// This is useful after merging models or removing objects.
+    ///
+    /// # Returns
+    /// The names of the removed enumerations in the order of the model
+    pub fn prune_unused_enums(&mut self) -> Vec<String> {
+        let unused: Vec<String> = self
+            .unreferenced_enums()
+            .iter()
+            .map(|e| e.name.clone())
+            .collect();
+
+        self.enums.retain(|e| !unused.contains(&e.name));
+
+        unused
+    }
+
     /// Validates a dataset against the data model.
     ///
     /// This function takes the path to a dataset and validates it against the
@@ -110,6 +432,48 @@ impl DataModel {
         validate_json(path.to_path_buf(), self, root)
     }
 
+    /// Validates a JSON file against the JSON schema of the data model.
+    ///
+    /// The schema of `schema_root` is generated in memory, hence it does not
+    /// need to be written to disk. This is equivalent to `validate_json`.
+    ///
+    /// # Arguments
+    ///
+    /// * `json_path` - The path of the JSON dataset to validate.
+    /// * `schema_root` - An optional root object of the schema. Will use the first object if not provided.
+    ///
+    /// # Returns
+    /// A Result containing a vector of `ValidationError` if validation fails,
+    /// or an empty vector if successful.
+    pub fn validate_json_against_schema(
+        &self,
+        json_path: &Path,
+        schema_root: Option<String>,
+    ) -> Result<Vec<ValidationError>, Box<dyn Error>> {
+        self.validate_json(json_path, schema_root)
+    }
+
+    /// Validates a JSON string against the data model.
+    ///
+    /// The JSON schema of the root object is generated in memory, hence neither
+    /// the dataset nor the schema need to be written to disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The JSON content of the dataset to validate.
+    /// * `root` - An optional root path for the schema. Will use the first object if not provided.
+    ///
+    /// # Returns
+    /// A Result containing a vector of `ValidationError` if validation fails,
+    /// or an empty vector if successful.
+    pub fn validate_json_str_against_schema(
+        &self,
+        content: &str,
+        root: Option<String>,
+    ) -> Result<Vec<ValidationError>, Box<dyn Error>> {
+        validate_json(content.to_string(), self, root)
+    }
+
     // Get the JSON schema for an object
     //
     // * `obj_name` - Name of the object
@@ -134,6 +498,50 @@ impl DataModel {
         &self,
         obj_name: Option<String>,
         openai: bool,
+    ) -> Result<String, Box<dyn Error>> {
+        self.json_schema_with_draft(
+            obj_name,
+            openai,
+            JsonSchemaDraft::Draft202012,
+            JsonSchemaInheritance::Inline,
+        )
+    }
+
+    // Get the draft-07 JSON schema for an object
+    //
+    // Draft-07 schemas use `definitions` and `#/definitions/` references
+    // instead of `$defs`, which is required by some older tools.
+    //
+    // * `root` - Name of the object, defaults to the first object
+    //
+    // # Panics
+    // If no objects are found in the markdown file
+    // If the object is not found in the markdown file
+    //
+    // # Returns
+    //
+    // A JSON schema string
+    pub fn json_schema_draft07(&self, root: Option<String>) -> Result<String, Box<dyn Error>> {
+        self.json_schema_with_draft(
+            root,
+            false,
+            JsonSchemaDraft::Draft07,
+            JsonSchemaInheritance::Inline,
+        )
+    }
+
+    // Get the JSON schema for an object using the given draft
+    //
+    // * `obj_name` - Name of the object, defaults to the first object
+    // * `openai` - Whether to remove options from the schema properties
+    // * `draft` - The JSON schema draft
+    // * `inheritance` - The encoding of inherited attributes
+    pub(crate) fn json_schema_with_draft(
+        &self,
+        obj_name: Option<String>,
+        openai: bool,
+        draft: JsonSchemaDraft,
+        inheritance: JsonSchemaInheritance,
     ) -> Result<String, Box<dyn Error>> {
         if self.objects.is_empty() {
             panic!("No objects found in the markdown file");
@@ -145,17 +553,57 @@ impl DataModel {
                     panic!("Object '{}' not found in the markdown file", name);
                 }
                 Ok(serde_json::to_string_pretty(&to_json_schema(
-                    self, &name, openai,
+                    self,
+                    &name,
+                    openai,
+                    draft,
+                    inheritance,
                 )?)?)
             }
             None => Ok(serde_json::to_string_pretty(&to_json_schema(
                 self,
                 &self.objects[0].name,
                 openai,
+                draft,
+                inheritance,
             )?)?),
         }
     }
 
+    /// Get the OpenAPI 3.1 specification of the model
+    ///
+    /// * `root` - Object for which CRUD paths are generated, defaults to the first object
+    /// * `format` - Whether to render JSON or YAML
+    ///
+    /// # Returns
+    /// The OpenAPI specification as string
+    pub fn to_openapi(
+        &self,
+        root: Option<String>,
+        format: OpenApiFormat,
+    ) -> Result<String, Box<dyn Error>> {
+        let root = match root {
+            Some(root) => root,
+            None => self
+                .objects
+                .first()
+                .map(|object| object.name.clone())
+                .ok_or("No objects found in the markdown file")?,
+        };
+
+        to_openapi(self, &root, format)
+    }
+
+    /// Get a standalone JSON-LD context document of the model
+    ///
+    /// Objects and attributes without a term are omitted.
+    ///
+    /// # Returns
+    /// The JSON-LD context as string
+    pub fn to_jsonld_context(&self) -> Result<String, Box<dyn Error>> {
+        to_jsonld_context(self)
+    }
+
     // Get the JSON schema for all objects in the markdown file
     // and write them to a file
     //
@@ -174,6 +622,27 @@ impl DataModel {
     // model.json_schema_all("path/to/directory".to_string());
     // ```
     pub fn json_schema_all(&self, path: PathBuf, openai: bool) -> Result<(), Box<dyn Error>> {
+        self.json_schema_all_with_draft(
+            path,
+            openai,
+            JsonSchemaDraft::Draft202012,
+            JsonSchemaInheritance::Inline,
+        )
+    }
+
+    // Write the JSON schema for all objects using the given draft
+    //
+    // * `path` - Path to the directory where the JSON schema files will be written
+    // * `openai` - Whether to remove options from the schema properties
+    // * `draft` - The JSON schema draft
+    // * `inheritance` - The encoding of inherited attributes
+    pub(crate) fn json_schema_all_with_draft(
+        &self,
+        path: PathBuf,
+        openai: bool,
+        draft: JsonSchemaDraft,
+        inheritance: JsonSchemaInheritance,
+    ) -> Result<(), Box<dyn Error>> {
         if self.objects.is_empty() {
             panic!("No objects found in the markdown file");
         }
@@ -185,7 +654,7 @@ impl DataModel {
 
         let base_path = path.to_str().ok_or("Failed to convert path to string")?;
         for object in &self.objects {
-            let schema = to_json_schema(self, &object.name, openai)?;
+            let schema = to_json_schema(self, &object.name, openai, draft, inheritance)?;
             let file_name = format!("{}/{}.json", base_path, object.name);
             fs::write(file_name, serde_json::to_string_pretty(&schema)?)
                 .expect("Could not write file");
@@ -219,32 +688,113 @@ impl DataModel {
         serde_json::to_string_pretty(&self).expect("Could not serialize to internal schema")
     }
 
-    // Parse a markdown file and create a data model
+    // Parse an internal schema file and create a data model
     //
-    // * `path` - Path to the markdown file
+    // * `path` - Path to the internal schema file
     //
     // # Examples
     //
     // ```
-    // let path = Path::new("path/to/file.md");
-    // let model = DataModel::from_internal_schema(path);
+    // let path = Path::new("path/to/file.json");
+    // let model = DataModel::from_internal_schema_file(path);
     // ```
     //
     // # Returns
     //
     // A data model
     //
-    pub fn from_internal_schema(path: &Path) -> Result<Self, Box<dyn Error>> {
+    pub fn from_internal_schema_file(path: &Path) -> Result<Self, Box<dyn Error>> {
         if !path.exists() {
             return Err("File does not exist".into());
         }
 
         let contents = fs::read_to_string(path)?;
-        let model: DataModel = serde_json::from_str(&contents)?;
+        Self::from_internal_schema(&contents)
+    }
 
+    /// Create a data model from an internal schema string
+    ///
+    /// This is the counterpart of [`DataModel::internal_schema`].
+    ///
+    /// * `json` - The internal schema as JSON string
+    ///
+    /// # Returns
+    /// A data model
+    pub fn from_internal_schema(json: &str) -> Result<Self, Box<dyn Error>> {
+        let model: DataModel = serde_json::from_str(json)?;
         Ok(model)
     }
 
+    /// Create a data model from an Avro schema file
+    ///
+    /// * `path` - Path to the Avro schema file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use mdmodels::datamodel::DataModel;
+    ///
+    /// let path = Path::new("tests/data/model.avsc");
+    /// let model = DataModel::from_avro(path).unwrap();
+    /// ```
+    /// # Returns
+    /// A data model
+    pub fn from_avro(path: &Path) -> Result<Self, Box<dyn Error>> {
+        if !path.exists() {
+            return Err("File does not exist".into());
+        }
+
+        let contents = fs::read_to_string(path)?;
+        from_avro_str(&contents)
+    }
+
+    /// Create a data model from an Avro schema string
+    ///
+    /// * `content` - The Avro schema as JSON string
+    ///
+    /// # Returns
+    /// A data model
+    pub fn from_avro_str(content: &str) -> Result<Self, Box<dyn Error>> {
+        from_avro_str(content)
+    }
+
+    /// Parse a Protocol Buffers file and create a data model
+    ///
+    /// * `path` - Path to the `.proto` file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use mdmodels::datamodel::DataModel;
+    ///
+    /// let path = Path::new("tests/data/model.proto");
+    /// let model = DataModel::from_proto_file(path).unwrap();
+    /// ```
+    /// # Returns
+    /// A data model
+    pub fn from_proto_file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        if !path.exists() {
+            return Err("File does not exist".into());
+        }
+
+        let contents = fs::read_to_string(path)?;
+        from_proto(&contents)
+    }
+
+    /// Create a data model from a JSON schema string
+    ///
+    /// Definitions are read from `$defs` as well as from draft-07 `definitions`.
+    ///
+    /// * `content` - The JSON schema as JSON string
+    ///
+    /// # Returns
+    /// A data model
+    pub fn from_json_schema_str(content: &str) -> Result<Self, Box<dyn Error>> {
+        from_json_schema_str(content)
+    }
+
     /// Sort the attributes of all objects by required
     pub fn sort_attrs(&mut self) {
         for obj in &mut self.objects {
@@ -267,261 +817,2234 @@ impl DataModel {
     pub fn convert_to(
         &mut self,
         template: &Templates,
-        config: Option<&HashMap<String, String>>,
+        config: Option<&TemplateConfig>,
     ) -> Result<String, minijinja::Error> {
         self.sort_attrs();
         render_jinja_template(template, self, config)
     }
 
-    // Merge two data models
+    // Render the data model as a PlantUML class diagram
     //
-    // * `other` - The other data model to merge
-    pub fn merge(&mut self, other: &Self) {
-        // Initialize a variable to check if the merge is valid
-        let mut valid = true;
-
-        // Check if there are any duplicate objects or enums
-        for obj in &other.objects {
-            if self.objects.iter().any(|o| o.name == obj.name) {
-                error!(
-                    "[{}] {}: Object {} is defined more than once.",
-                    "Merge".bold(),
-                    "DuplicateError".bold(),
-                    obj.name.red().bold(),
-                );
+    // Objects become classes, inheritance and object-typed attributes
+    // become relations between them.
+    //
+    // # Returns
+    //
+    // A string containing the diagram, from `@startuml` to `@enduml`
+    pub fn to_plantuml(&self) -> String {
+        render_jinja_template(&Templates::PlantUml, &mut self.clone(), None)
+            .expect("Could not render PlantUML diagram")
+    }
 
-                valid = false;
+    // Render the data model as a Mermaid entity-relationship diagram
+    //
+    // Objects become entities listing their primitive attributes, while
+    // object-typed attributes become relationships whose cardinality
+    // follows from the attribute being an array and being required.
+    //
+    // # Returns
+    //
+    // A string containing the `erDiagram` definition
+    pub fn to_mermaid_er(&self) -> String {
+        render_jinja_template(&Templates::MermaidErd, &mut self.clone(), None)
+            .expect("Could not render Mermaid ER diagram")
+    }
+
+    // Extract a sub-model containing the given objects and their dependencies
+    //
+    // * `roots` - The names of the objects to keep
+    //
+    // The resulting model contains the root objects, all objects they
+    // transitively reference and all enumerations used by these objects.
+    // The frontmatter is copied from this model.
+    //
+    // # Errors
+    //
+    // If any of the roots is not an object of this model
+    pub fn subset(&self, roots: &[&str]) -> Result<DataModel, Box<dyn Error>> {
+        let missing: Vec<&str> = roots
+            .iter()
+            .filter(|root| self.get_object(root).is_none())
+            .copied()
+            .collect();
+
+        if !missing.is_empty() {
+            return Err(format!("Objects not found in model: {}", missing.join(", ")).into());
+        }
+
+        let graph = dependency_graph(self);
+        let names = reachable_from(&graph, roots);
+
+        let objects: Vec<Object> = self
+            .objects
+            .iter()
+            .filter(|o| names.contains(&o.name))
+            .cloned()
+            .collect();
+
+        let enums = self
+            .enums
+            .iter()
+            .filter(|e| {
+                objects
+                    .iter()
+                    .any(|o| o.attributes_by_dtype(&e.name).next().is_some())
+            })
+            .cloned()
+            .collect();
+
+        Ok(DataModel {
+            name: self.name.clone(),
+            objects,
+            enums,
+            config: self.config.clone(),
+        })
+    }
+
+    // Extract a sub-model containing a single object and its dependencies
+    //
+    // * `root` - The name of the object to keep
+    //
+    // # Errors
+    //
+    // If the root is not an object of this model
+    pub fn subset_objects_reachable_from(&self, root: &str) -> Result<DataModel, Box<dyn Error>> {
+        self.subset(&[root])
+    }
+
+    // Rename an object and update all references to it
+    //
+    // * `old_name` - The current name of the object
+    // * `new_name` - The new name of the object
+    //
+    // Attribute types and parents referring to the object are updated as well.
+    // The model is left unchanged if the rename fails.
+    //
+    // # Errors
+    //
+    // If the object does not exist, the new name is invalid or already taken
+    pub fn rename_object(&mut self, old_name: &str, new_name: &str) -> Result<(), RenameError> {
+        if self.get_object(old_name).is_none() {
+            return Err(RenameError::ObjectNotFound(old_name.to_string()));
+        }
+
+        check_object_name(new_name).map_err(|messages| RenameError::InvalidName {
+            name: new_name.to_string(),
+            messages,
+        })?;
+
+        let taken = self.get_object(new_name).is_some() || self.get_enum(new_name).is_some();
+
+        if taken {
+            return Err(RenameError::NameTaken(new_name.to_string()));
+        }
+
+        let referencing: Vec<String> = self
+            .objects_referencing(old_name)
+            .iter()
+            .map(|object| object.name.clone())
+            .collect();
+
+        for object in self.objects.iter_mut() {
+            if referencing.contains(&object.name) {
+                for dtype in object
+                    .attributes
+                    .iter_mut()
+                    .flat_map(|attr| attr.dtypes.iter_mut())
+                    .filter(|dtype| dtype.as_str() == old_name)
+                {
+                    *dtype = new_name.to_string();
+                }
+            }
+
+            if object.name == old_name {
+                object.name = new_name.to_string();
+            }
+
+            if object.parent.as_deref() == Some(old_name) {
+                object.parent = Some(new_name.to_string());
             }
         }
 
-        for enm in &other.enums {
-            if self.enums.iter().any(|e| e.name == enm.name) {
-                error!(
-                    "[{}] {}: Enumeration {} is defined more than once.",
+        Ok(())
+    }
+
+    // Rename an enumeration and update all references to it
+    //
+    // * `old_name` - The current name of the enumeration
+    // * `new_name` - The new name of the enumeration
+    //
+    // Attribute types referring to the enumeration are updated as well.
+    // The model is left unchanged if the rename fails.
+    //
+    // # Errors
+    //
+    // If the enumeration does not exist, the new name is invalid or already taken
+    pub fn rename_enum(&mut self, old_name: &str, new_name: &str) -> Result<(), RenameError> {
+        if self.get_enum(old_name).is_none() {
+            return Err(RenameError::EnumNotFound(old_name.to_string()));
+        }
+
+        check_object_name(new_name).map_err(|messages| RenameError::InvalidName {
+            name: new_name.to_string(),
+            messages,
+        })?;
+
+        let taken = self.get_object(new_name).is_some() || self.get_enum(new_name).is_some();
+
+        if taken {
+            return Err(RenameError::NameTaken(new_name.to_string()));
+        }
+
+        for (_, attr) in self.all_attributes_mut() {
+            for dtype in attr.dtypes.iter_mut().filter(|dtype| *dtype == old_name) {
+                *dtype = new_name.to_string();
+            }
+        }
+
+        for enumeration in self.enums.iter_mut() {
+            if enumeration.name == old_name {
+                enumeration.name = new_name.to_string();
+            }
+        }
+
+        set_enum_attributes(self);
+
+        Ok(())
+    }
+
+    // Remove an object and handle all references to it
+    //
+    // * `name` - The name of the object
+    // * `strategy` - How attributes and children referencing the object are handled
+    //
+    // References of the object to itself are removed along with it. Objects
+    // inheriting from it lose their parent unless the strategy rejects references.
+    // The model is left unchanged if the removal fails.
+    //
+    // # Errors
+    //
+    // If the strategy is `ErrorOnReferences` and other objects reference the object
+    pub fn remove_object(
+        &mut self,
+        name: &str,
+        strategy: RemoveStrategy,
+    ) -> Result<Option<Object>, RemoveError> {
+        let Some(index) = self.objects.iter().position(|o| o.name == name) else {
+            return Ok(None);
+        };
+
+        self.remove_references(name, strategy)?;
+
+        Ok(Some(self.objects.remove(index)))
+    }
+
+    // Remove an enumeration and handle all references to it
+    //
+    // * `name` - The name of the enumeration
+    // * `strategy` - How attributes referencing the enumeration are handled
+    //
+    // The model is left unchanged if the removal fails.
+    //
+    // # Errors
+    //
+    // If the strategy is `ErrorOnReferences` and attributes reference the enumeration
+    pub fn remove_enum(
+        &mut self,
+        name: &str,
+        strategy: RemoveStrategy,
+    ) -> Result<Option<Enumeration>, RemoveError> {
+        let Some(index) = self.enums.iter().position(|e| e.name == name) else {
+            return Ok(None);
+        };
+
+        self.remove_references(name, strategy)?;
+
+        Ok(Some(self.enums.remove(index)))
+    }
+
+    // Apply a `RemoveStrategy` to all references of other objects to a type
+    fn remove_references(
+        &mut self,
+        name: &str,
+        strategy: RemoveStrategy,
+    ) -> Result<(), RemoveError> {
+        let mut references: Vec<String> = self
+            .all_attributes()
+            .filter(|(object, attr)| object.name != name && attr.dtypes.iter().any(|d| d == name))
+            .map(|(object, attr)| format!("{}.{}", object.name, attr.name))
+            .collect();
+
+        references.extend(
+            self.objects
+                .iter()
+                .filter(|object| object.name != name && object.parent.as_deref() == Some(name))
+                .map(|object| object.name.clone()),
+        );
+
+        if references.is_empty() {
+            return Ok(());
+        }
+
+        let objects = self.objects.iter_mut().filter(|object| object.name != name);
+
+        match strategy {
+            RemoveStrategy::ErrorOnReferences => {
+                return Err(RemoveError {
+                    name: name.to_string(),
+                    references,
+                });
+            }
+            RemoveStrategy::RemoveReferencingAttributes => {
+                for object in objects {
+                    object
+                        .attributes
+                        .retain(|attr| !attr.dtypes.iter().any(|d| d == name));
+                    if object.parent.as_deref() == Some(name) {
+                        object.parent = None;
+                    }
+                }
+            }
+            RemoveStrategy::ReplaceWithString => {
+                for object in objects {
+                    for attr in object
+                        .attributes
+                        .iter_mut()
+                        .filter(|attr| attr.dtypes.iter().any(|d| d == name))
+                    {
+                        let mut dtypes = Vec::new();
+                        for dtype in attr.dtypes.drain(..) {
+                            let dtype = if dtype == name {
+                                "string".to_string()
+                            } else {
+                                dtype
+                            };
+                            if !dtypes.contains(&dtype) {
+                                dtypes.push(dtype);
+                            }
+                        }
+                        attr.dtypes = dtypes;
+                        attr.is_enum = false;
+                    }
+
+                    if object.parent.as_deref() == Some(name) {
+                        object.parent = None;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Merge two data models
+    //
+    // Objects and enums defined in both models are skipped and reported
+    // as warnings. Use `merge_strict` to reject such conflicts instead.
+    //
+    // * `other` - The other data model to merge
+    pub fn merge(&mut self, other: &Self) {
+        if let Err(e) = self.merge_strict(other) {
+            for name in &e.conflicting_objects {
+                warn!(
+                    "[{}] {}: Object {} is defined more than once and is skipped.",
+                    "Merge".bold(),
+                    "DuplicateWarning".bold(),
+                    name.yellow().bold(),
+                );
+            }
+
+            for name in &e.conflicting_enums {
+                warn!(
+                    "[{}] {}: Enumeration {} is defined more than once and is skipped.",
                     "Merge".bold(),
-                    "DuplicateError".bold(),
-                    enm.name.red().bold(),
+                    "DuplicateWarning".bold(),
+                    name.yellow().bold(),
                 );
+            }
+
+            let strategy = MergeStrategy {
+                on_conflict: ConflictBehavior::Skip,
+            };
+            self.merge_with_strategy(other, strategy)
+                .expect("Skipping conflicts never fails");
+        }
+    }
+
+    // Merge two data models, rejecting objects and enums defined in both
+    //
+    // * `other` - The other data model to merge
+    //
+    // # Errors
+    //
+    // If any object or enum is defined in both models. The model is left
+    // unchanged in this case.
+    pub fn merge_strict(&mut self, other: &Self) -> Result<(), MergeError> {
+        let strategy = MergeStrategy {
+            on_conflict: ConflictBehavior::Error,
+        };
+
+        self.merge_with_strategy(other, strategy)
+    }
+
+    // Merge two data models using the given strategy for conflicts
+    //
+    // * `other` - The other data model to merge
+    // * `strategy` - How objects and enums defined in both models are handled
+    //
+    // # Errors
+    //
+    // If the strategy is `ConflictBehavior::Error` and any object or enum is
+    // defined in both models. The model is left unchanged in this case.
+    pub fn merge_with_strategy(
+        &mut self,
+        other: &Self,
+        strategy: MergeStrategy,
+    ) -> Result<(), MergeError> {
+        let conflicts = MergeError {
+            conflicting_objects: other
+                .objects
+                .iter()
+                .filter(|obj| self.get_object(&obj.name).is_some())
+                .map(|obj| obj.name.clone())
+                .collect(),
+            conflicting_enums: other
+                .enums
+                .iter()
+                .filter(|enm| self.get_enum(&enm.name).is_some())
+                .map(|enm| enm.name.clone())
+                .collect(),
+        };
+
+        if strategy.on_conflict == ConflictBehavior::Error && !conflicts.is_empty() {
+            return Err(conflicts);
+        }
+
+        for obj in &other.objects {
+            match self.get_object_mut(&obj.name) {
+                Some(existing) if strategy.on_conflict == ConflictBehavior::Override => {
+                    *existing = obj.clone();
+                }
+                Some(_) => {}
+                None => self.objects.push(obj.clone()),
+            }
+        }
+
+        for enm in &other.enums {
+            match self.get_enum_mut(&enm.name) {
+                Some(existing) if strategy.on_conflict == ConflictBehavior::Override => {
+                    *existing = enm.clone();
+                }
+                Some(_) => {}
+                None => self.enums.push(enm.clone()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compute the semantic differences to another data model
+    ///
+    /// * `other` - The newer version of the data model
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use mdmodels::datamodel::DataModel;
+    ///
+    /// let old = DataModel::from_markdown(Path::new("tests/data/model_diff_v1.md")).unwrap();
+    /// let new = DataModel::from_markdown(Path::new("tests/data/model_diff_v2.md")).unwrap();
+    /// let changelog = old.diff(&new).to_changelog();
+    /// ```
+    /// # Returns
+    /// A `ModelDiff` describing the changes from this model to `other`
+    pub fn diff(&self, other: &DataModel) -> ModelDiff {
+        ModelDiff::new(self, other)
+    }
+
+    /// Returns the objects of the data model in topological order
+    ///
+    /// Each object follows all objects it references, while independent objects
+    /// keep their order within the model. References closing a cycle are ignored.
+    ///
+    /// # Returns
+    /// References to all objects, dependencies first
+    pub fn topological_objects(&self) -> Vec<&Object> {
+        self.topological_indices()
+            .into_iter()
+            .map(|index| &self.objects[index])
+            .collect()
+    }
+
+    /// Returns the objects of the data model in topological order
+    ///
+    /// Alias of `DataModel::topological_objects`.
+    pub fn objects_in_topological_order(&self) -> Vec<&Object> {
+        self.topological_objects()
+    }
+
+    /// Returns mutable references to the objects of the data model in topological order
+    ///
+    /// See `DataModel::topological_objects` for the ordering.
+    ///
+    /// # Returns
+    /// Mutable references to all objects, dependencies first
+    pub fn topological_objects_mut(&mut self) -> Vec<&mut Object> {
+        let indices = self.topological_indices();
+        let mut objects: Vec<Option<&mut Object>> = self.objects.iter_mut().map(Some).collect();
+
+        indices
+            .into_iter()
+            .filter_map(|index| objects[index].take())
+            .collect()
+    }
+
+    /// Sorts the objects of the data model in topological order
+    ///
+    /// See `DataModel::topological_objects` for the ordering.
+    pub fn sort_objects_topologically(&mut self) {
+        let indices = self.topological_indices();
+        let mut objects: Vec<Option<Object>> = std::mem::take(&mut self.objects)
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        self.objects = indices
+            .into_iter()
+            .filter_map(|index| objects[index].take())
+            .collect();
+    }
+
+    /// Computes the indices of the objects in topological order
+    fn topological_indices(&self) -> Vec<usize> {
+        let names: Vec<&str> = self.objects.iter().map(|o| o.name.as_str()).collect();
+
+        topological_order(&dependency_graph(self), &names)
+            .iter()
+            .filter_map(|name| names.iter().position(|n| n == name))
+            .collect()
+    }
+
+    /// Checks whether objects of the data model reference each other in a cycle
+    ///
+    /// Recursive types, such as an object referencing itself, count as cycles.
+    ///
+    /// # Returns
+    /// `true` if at least one cycle of object references exists
+    pub fn has_cycles(&self) -> bool {
+        has_cycles(self)
+    }
+
+    /// Finds all cycles of object references in the data model
+    ///
+    /// # Returns
+    /// The object names of each cycle, starting with the object the cycle is entered at
+    pub fn find_cycles(&self) -> Vec<Vec<String>> {
+        find_cycles(self)
+    }
+
+    /// Returns the semantic version of the data model
+    ///
+    /// The version is read from the `version` key of the frontmatter.
+    ///
+    /// # Returns
+    /// The parsed version or `None` if no version is set or it is not a valid semantic version
+    pub fn schema_version(&self) -> Option<Version> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.version.as_deref())
+            .and_then(|version| Version::parse(version).ok())
+    }
+
+    /// Check whether another data model satisfies the version requirement of this model
+    ///
+    /// The requirement is read from the `requires-version` key of the frontmatter.
+    ///
+    /// * `other` - The data model whose version is checked
+    ///
+    /// # Returns
+    /// `true` if this model has no requirement or the version of `other` satisfies it,
+    /// `false` if the requirement is invalid or `other` has no valid version
+    pub fn is_compatible_with(&self, other: &DataModel) -> bool {
+        let requirement = match self
+            .config
+            .as_ref()
+            .and_then(|config| config.requires_version.as_deref())
+        {
+            Some(requirement) => requirement,
+            None => return true,
+        };
+
+        match (VersionReq::parse(requirement), other.schema_version()) {
+            (Ok(requirement), Some(version)) => requirement.matches(&version),
+            _ => false,
+        }
+    }
+
+    /// Parse a markdown file and create a data model
+    ///
+    /// * `path` - Path to the markdown file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use mdmodels::datamodel::DataModel;
+    ///
+    /// let path = Path::new("tests/data/model.md");
+    /// let model = DataModel::from_markdown(path);
+    /// ```
+    /// # Returns
+    /// A data model
+    #[allow(clippy::result_large_err)]
+    pub fn from_markdown(path: &Path) -> Result<Self, Validator> {
+        let content = fs::read_to_string(path).expect("Could not read file");
+        parse_markdown(&content)
+    }
+
+    /// Parse all markdown files of a directory and merge them into a single data model
+    ///
+    /// Files are parsed in alphabetical order of their paths and merged into the first
+    /// model. The merged model is validated as a whole, hence types may reference
+    /// definitions of other files. The name of the merged model is set to the name
+    /// of the directory. Metadata such as prefixes is taken from the first file's
+    /// front matter, while the validation options of all files are merged, see
+    /// `FrontMatter::merge_validation_options`.
+    ///
+    /// * `dir` - Path to the directory containing the markdown files
+    /// * `recursive` - Whether to include markdown files of subdirectories
+    ///
+    /// # Returns
+    /// The merged data model, a `MarkdownDirError` if the merged model is invalid, or a
+    /// `MergeError` if files define the same objects or enumerations
+    pub fn from_markdown_dir(dir: &Path, recursive: bool) -> Result<Self, Box<dyn Error>> {
+        let mut paths: Vec<PathBuf> = if recursive {
+            walkdir::WalkDir::new(dir)
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .filter(|entry| entry.file_type().is_file())
+                .map(|entry| entry.into_path())
+                .collect()
+        } else {
+            fs::read_dir(dir)?
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file())
+                .collect()
+        };
+
+        paths.retain(|path| path.extension().is_some_and(|ext| ext == "md"));
+        paths.sort();
+
+        if paths.is_empty() {
+            return Err(format!("No markdown files found in {}", dir.display()).into());
+        }
+
+        // Files are validated after merging, so that references across files resolve
+        let mut models = Vec::new();
+        let mut sources: HashMap<String, PathBuf> = HashMap::new();
+
+        for path in &paths {
+            let model = parse_markdown_unvalidated(&fs::read_to_string(path)?);
+            let names = model
+                .objects
+                .iter()
+                .map(|o| &o.name)
+                .chain(model.enums.iter().map(|e| &e.name));
+
+            for name in names {
+                sources.entry(name.clone()).or_insert_with(|| path.clone());
+            }
+
+            models.push(model);
+        }
+
+        let mut models = models.into_iter();
+        let mut merged = models.next().expect("At least one model has been parsed");
+        let strategy = MergeStrategy {
+            on_conflict: ConflictBehavior::Error,
+        };
+
+        for model in models {
+            if let Some(config) = &model.config {
+                merged
+                    .config
+                    .get_or_insert_with(FrontMatter::new)
+                    .merge_validation_options(config);
+            }
+
+            merged.merge_with_strategy(&model, strategy)?;
+        }
+
+        let mut merged = finalize_model(merged)
+            .map_err(|validator| MarkdownDirError::from_validator(validator, &sources, dir))?;
+
+        merged.name = dir
+            .canonicalize()?
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string());
+
+        Ok(merged)
+    }
+
+    /// Fetch a markdown model from a URL and create a data model
+    ///
+    /// The downloaded content is cached in `.mdmodels_cache/` within the current
+    /// directory, keyed by the SHA-256 hash of the URL. Subsequent calls read the
+    /// cached content instead of fetching it again, unless the cached entry is older
+    /// than one day. Use `clear_url_cache` to force a refresh.
+    ///
+    /// * `url` - URL of the markdown file
+    ///
+    /// # Returns
+    /// A data model or an error if fetching or parsing fails
+    #[cfg(feature = "fetch")]
+    pub fn from_url(url: &str) -> Result<Self, Box<dyn Error>> {
+        let content = fetch_url_cached(url, Path::new(URL_CACHE_DIR), URL_CACHE_MAX_AGE)?;
+        Ok(parse_markdown(&content)?)
+    }
+
+    /// Removes all models cached by `from_url`
+    ///
+    /// # Returns
+    /// An error if the cache directory exists but could not be removed
+    #[cfg(feature = "fetch")]
+    pub fn clear_url_cache() -> Result<(), Box<dyn Error>> {
+        match fs::remove_dir_all(URL_CACHE_DIR) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fetch a markdown model from a URL without using the cache
+    ///
+    /// * `url` - URL of the markdown file
+    ///
+    /// # Returns
+    /// A data model or an error if fetching or parsing fails
+    #[cfg(feature = "fetch")]
+    pub fn from_url_no_cache(url: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(parse_markdown(&fetch_url(url)?)?)
+    }
+
+    /// Parse a markdown file and create a data model
+    ///
+    /// * `path` - Path to the markdown file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use std::fs;
+    /// use mdmodels::datamodel::DataModel;
+    ///
+    /// let path = Path::new("tests/data/model.md");
+    /// let content = fs::read_to_string(path).unwrap();
+    /// let model = DataModel::from_markdown_string(content.as_str());
+    /// ```
+    /// # Returns
+    /// A data model
+    #[allow(clippy::result_large_err)]
+    pub fn from_markdown_string(content: &str) -> Result<Self, Validator> {
+        parse_markdown(content)
+    }
+}
+
+/// Builder for constructing a `DataModel` programmatically
+///
+/// Objects and enumerations are checked for unique names as they are added. Duplicates
+/// are not added, but reported alongside all other validation errors by `build`.
+#[derive(Debug, Clone, Default)]
+pub struct DataModelBuilder {
+    name: Option<String>,
+    objects: Vec<Object>,
+    enums: Vec<Enumeration>,
+    config: Option<FrontMatter>,
+    validator: Validator,
+}
+
+impl DataModelBuilder {
+    /// Adds an object to the data model
+    ///
+    /// * `object` - The object to add, which must not share its name with another type
+    pub fn add_object(&mut self, object: Object) -> &mut Self {
+        if self.is_defined(&object.name) {
+            self.add_duplicate_error("Object", &object.name);
+        } else {
+            self.objects.push(object);
+        }
+
+        self
+    }
+
+    /// Adds an enumeration to the data model
+    ///
+    /// * `enumeration` - The enumeration to add, which must not share its name with another type
+    pub fn add_enum(&mut self, enumeration: Enumeration) -> &mut Self {
+        if self.is_defined(&enumeration.name) {
+            self.add_duplicate_error("Enumeration", &enumeration.name);
+        } else {
+            self.enums.push(enumeration);
+        }
+
+        self
+    }
+
+    /// Sets the name of the data model
+    ///
+    /// * `name` - The name of the data model
+    pub fn set_name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the configuration of the data model
+    ///
+    /// * `config` - The configuration, as it would be given in the frontmatter
+    pub fn set_config(&mut self, config: FrontMatter) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Validates and builds the data model
+    ///
+    /// # Returns
+    /// The data model or a `Validator` holding all errors, including rejected duplicates
+    #[allow(clippy::result_large_err)]
+    pub fn build(&self) -> Result<DataModel, Validator> {
+        // Duplicates have been rejected when adding objects and enumerations
+        let mut model = DataModel::new(self.name.clone(), self.config.clone());
+        for object in &self.objects {
+            model.add_object_unchecked(object.clone());
+        }
+        for enumeration in &self.enums {
+            model.add_enum_unchecked(enumeration.clone());
+        }
+
+        let mut validator = Validator::new();
+        validator.validate(&model);
+
+        for error in &self.validator.errors {
+            validator.add_error(error.clone());
+        }
+
+        if validator.is_valid {
+            Ok(model)
+        } else {
+            Err(validator)
+        }
+    }
+
+    /// Checks whether an object or enumeration with the given name has been added
+    fn is_defined(&self, name: &str) -> bool {
+        self.objects.iter().any(|o| o.name == name) || self.enums.iter().any(|e| e.name == name)
+    }
+
+    /// Records an error for a type that has been added more than once
+    fn add_duplicate_error(&mut self, kind: &str, name: &str) {
+        self.validator
+            .add_error(crate::validation::ValidationError {
+                message: format!("{} '{}' is defined more than once.", kind, name),
+                object: Some(name.to_string()),
+                attribute: None,
+                location: "Global".into(),
+                error_type: ErrorType::DuplicateError,
+                positions: vec![],
+            });
+    }
+}
+
+/// Creates a Markdown changelog section describing the changes between two data models
+///
+/// The section is headed with the given version and date, or `[Unreleased]` if no
+/// version is given, followed by `Added`, `Changed` and `Removed` subsections.
+///
+/// * `old` - The previous version of the data model
+/// * `new` - The current version of the data model
+/// * `version` - The version the changes are released in
+/// * `date` - The release date, e.g. `2024-01-31`, only used with a version
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use mdmodels::datamodel::{diff_to_changelog, DataModel};
+///
+/// let old = DataModel::from_markdown(Path::new("tests/data/model_diff_v1.md")).unwrap();
+/// let new = DataModel::from_markdown(Path::new("tests/data/model_diff_v2.md")).unwrap();
+/// let changelog = diff_to_changelog(&old, &new, Some("2.0.0"), Some("2024-01-31"));
+/// assert!(changelog.starts_with("## [2.0.0] - 2024-01-31\n"));
+/// ```
+/// # Returns
+/// The changelog section as Markdown string
+pub fn diff_to_changelog(
+    old: &DataModel,
+    new: &DataModel,
+    version: Option<&str>,
+    date: Option<&str>,
+) -> String {
+    let heading = match (version, date) {
+        (Some(version), Some(date)) => format!("[{}] - {}", version, date),
+        (Some(version), None) => format!("[{}]", version),
+        (None, _) => "[Unreleased]".to_string(),
+    };
+
+    old.diff(new).to_changelog_with_heading(&heading)
+}
+
+// Directory in which models fetched by `DataModel::from_url` are cached
+#[cfg(feature = "fetch")]
+const URL_CACHE_DIR: &str = ".mdmodels_cache";
+
+// Age after which a cached model is fetched again
+#[cfg(feature = "fetch")]
+const URL_CACHE_MAX_AGE: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+/// Downloads the content of a URL, failing on non-success status codes
+#[cfg(feature = "fetch")]
+fn fetch_url(url: &str) -> Result<String, Box<dyn Error>> {
+    Ok(reqwest::blocking::get(url)?.error_for_status()?.text()?)
+}
+
+/// Downloads the content of a URL or reads it from the cache
+///
+/// Cached entries older than `max_age` are fetched again and replaced. The cache
+/// is best-effort: if it cannot be read or written, the content is fetched and
+/// returned anyway.
+#[cfg(feature = "fetch")]
+fn fetch_url_cached(
+    url: &str,
+    cache_dir: &Path,
+    max_age: std::time::Duration,
+) -> Result<String, Box<dyn Error>> {
+    let cache_path = cache_dir.join(format!("{}.md", url_hash(url)));
+
+    let is_fresh = fs::metadata(&cache_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < max_age);
+
+    if is_fresh {
+        if let Ok(content) = fs::read_to_string(&cache_path) {
+            return Ok(content);
+        }
+    }
+
+    let content = fetch_url(url)?;
+
+    if let Err(e) = fs::create_dir_all(cache_dir).and_then(|_| fs::write(&cache_path, &content)) {
+        warn!("Could not cache {} in {}: {}", url, cache_dir.display(), e);
+    }
+
+    Ok(content)
+}
+
+/// Hashes a URL to a file name for the cache
+#[cfg(feature = "fetch")]
+fn url_hash(url: &str) -> String {
+    use sha2::{Digest, Sha256};
+
+    format!("{:x}", Sha256::digest(url.as_bytes()))
+}
+
+/// How `DataModel::merge_with_strategy` handles definitions present in both models
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictBehavior {
+    /// Keep the definition of the model that is merged into
+    Skip,
+    /// Replace the definition with the one of the other model
+    Override,
+    /// Abort the merge and report all conflicting names
+    Error,
+}
+
+/// How `DataModel::remove_object` and `DataModel::remove_enum` handle references to the removed type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoveStrategy {
+    /// Abort the removal if any other object references the type
+    ErrorOnReferences,
+    /// Delete all attributes that reference the type
+    RemoveReferencingAttributes,
+    /// Change the referencing attribute types to `string`
+    ReplaceWithString,
+}
+
+/// Strategy used by `DataModel::merge_with_strategy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergeStrategy {
+    /// Behavior for objects and enums defined in both models
+    pub on_conflict: ConflictBehavior,
+}
+
+/// Error returned by `DataModel::merge_with_strategy` listing all conflicting names
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MergeError {
+    /// Names of objects defined in both models
+    pub conflicting_objects: Vec<String>,
+    /// Names of enumerations defined in both models
+    pub conflicting_enums: Vec<String>,
+}
+
+impl MergeError {
+    /// Returns whether no conflicts were found
+    pub fn is_empty(&self) -> bool {
+        self.conflicting_objects.is_empty() && self.conflicting_enums.is_empty()
+    }
+}
+
+impl std::fmt::Display for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> = self
+            .conflicting_objects
+            .iter()
+            .chain(self.conflicting_enums.iter())
+            .map(|name| name.as_str())
+            .collect();
+
+        write!(f, "Conflicting definitions: {}", names.join(", "))
+    }
+}
+
+impl Error for MergeError {}
+
+/// Error returned by `DataModel::from_markdown_dir` listing all invalid files
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MarkdownDirError {
+    /// Paths of the invalid files along with their validation results
+    pub files: Vec<(PathBuf, Validator)>,
+}
+
+impl MarkdownDirError {
+    /// Splits the errors of a merged model by the files defining the affected types
+    ///
+    /// Errors that cannot be attributed to a file are reported for the directory.
+    fn from_validator(
+        validator: Validator,
+        sources: &HashMap<String, PathBuf>,
+        dir: &Path,
+    ) -> Self {
+        let mut files: Vec<(PathBuf, Validator)> = Vec::new();
+
+        for error in validator.errors {
+            let path = error
+                .object
+                .as_ref()
+                .and_then(|name| sources.get(name))
+                .cloned()
+                .unwrap_or_else(|| dir.to_path_buf());
+
+            match files.iter_mut().find(|(file, _)| *file == path) {
+                Some((_, file_validator)) => file_validator.add_error(error),
+                None => {
+                    let mut file_validator = Validator::new();
+                    file_validator.add_error(error);
+                    files.push((path, file_validator));
+                }
+            }
+        }
+
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Self { files }
+    }
+}
+
+impl std::fmt::Display for MarkdownDirError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (path, validator) in &self.files {
+            writeln!(f, "{}:", path.display())?;
+            for error in &validator.errors {
+                writeln!(f, "  {}", error.message)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for MarkdownDirError {}
+
+/// Error returned by `DataModel::rename_object` and `DataModel::rename_enum`
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenameError {
+    /// No object with the given name exists
+    ObjectNotFound(String),
+    /// No enumeration with the given name exists
+    EnumNotFound(String),
+    /// The new name does not pass the name checks of the validator
+    InvalidName { name: String, messages: Vec<String> },
+    /// An object or enumeration with the new name already exists
+    NameTaken(String),
+}
+
+impl std::fmt::Display for RenameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenameError::ObjectNotFound(name) => write!(f, "Object '{}' not found", name),
+            RenameError::EnumNotFound(name) => write!(f, "Enumeration '{}' not found", name),
+            RenameError::InvalidName { messages, .. } => write!(f, "{}", messages.join(" ")),
+            RenameError::NameTaken(name) => write!(f, "Name '{}' is already taken", name),
+        }
+    }
+}
+
+impl Error for RenameError {}
+
+/// Error returned by `DataModel::add_object` and `DataModel::add_enum` holding the conflicting name
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateError(pub String);
+
+impl std::fmt::Display for DuplicateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Name '{}' is already taken", self.0)
+    }
+}
+
+impl Error for DuplicateError {}
+
+/// Error returned by `DataModel::remove_object` and `DataModel::remove_enum` listing all references
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveError {
+    /// Name of the type that should have been removed
+    pub name: String,
+    /// Referencing attributes as `Object.attribute` and inheriting objects by name
+    pub references: Vec<String>,
+}
+
+impl std::fmt::Display for RemoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}' is still referenced by {}",
+            self.name,
+            self.references.join(", ")
+        )
+    }
+}
+
+impl Error for RemoveError {}
+
+/// Aggregate statistics of a model returned by `DataModel::stats`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelStats {
+    /// Number of objects
+    pub object_count: usize,
+    /// Number of enumerations
+    pub enum_count: usize,
+    /// Number of attributes across all objects
+    pub total_attributes: usize,
+    /// Number of attributes marked as required
+    pub required_attributes: usize,
+    /// Number of array attributes
+    pub array_attributes: usize,
+    /// Number of objects with a docstring
+    pub documented_objects: usize,
+    /// Number of attributes with a description
+    pub documented_attributes: usize,
+    /// Number of attributes with a term
+    pub term_annotated_attributes: usize,
+    /// Fraction of attributes with a description
+    pub documentation_coverage: f64,
+    /// Fraction of attributes with a term
+    pub term_coverage: f64,
+}
+
+impl std::fmt::Display for ModelStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = [
+            ("Objects", self.object_count.to_string()),
+            ("Enumerations", self.enum_count.to_string()),
+            ("Attributes", self.total_attributes.to_string()),
+            ("Required attributes", self.required_attributes.to_string()),
+            ("Array attributes", self.array_attributes.to_string()),
+            ("Documented objects", self.documented_objects.to_string()),
+            (
+                "Documented attributes",
+                self.documented_attributes.to_string(),
+            ),
+            (
+                "Term annotated attributes",
+                self.term_annotated_attributes.to_string(),
+            ),
+            (
+                "Documentation coverage",
+                format!("{:.1}%", self.documentation_coverage * 100.0),
+            ),
+            (
+                "Term coverage",
+                format!("{:.1}%", self.term_coverage * 100.0),
+            ),
+        ];
+
+        for (label, value) in rows {
+            writeln!(f, "{:<28}{:>8}", label, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::attribute::DataType;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_merge() {
+        // Arrange
+        let mut model1 = DataModel::new(None, None);
+        let mut model2 = DataModel::new(None, None);
+
+        let mut obj1 = Object::new("Object1".to_string(), None);
+        obj1.add_attribute(crate::attribute::Attribute {
+            name: "test1".to_string(),
+            is_array: false,
+            is_id: false,
+            dtypes: vec!["string".to_string()],
+            docstring: "".to_string(),
+            options: vec![],
+            custom_options: vec![],
+            term: None,
+            required: false,
+            deprecated: false,
+            readonly: false,
+            alias: None,
+            examples: Vec::new(),
+            xml: None,
+            default: None,
+            const_value: None,
+            is_enum: false,
+            position: None,
+        });
+
+        let mut obj2 = Object::new("Object2".to_string(), None);
+        obj2.add_attribute(crate::attribute::Attribute {
+            name: "test2".to_string(),
+            is_array: false,
+            is_id: false,
+            dtypes: vec!["string".to_string()],
+            docstring: "".to_string(),
+            options: vec![],
+            custom_options: vec![],
+            term: None,
+            required: false,
+            deprecated: false,
+            readonly: false,
+            alias: None,
+            examples: Vec::new(),
+            xml: None,
+            default: None,
+            const_value: None,
+            is_enum: false,
+            position: None,
+        });
+
+        let enm1 = Enumeration {
+            name: "Enum1".to_string(),
+            mappings: BTreeMap::from([("key1".to_string(), "value1".to_string())]),
+            docstring: "".to_string(),
+            position: None,
+        };
+
+        let enm2 = Enumeration {
+            name: "Enum2".to_string(),
+            mappings: BTreeMap::from([("key2".to_string(), "value2".to_string())]),
+            docstring: "".to_string(),
+            position: None,
+        };
+
+        model1.objects.push(obj1);
+        model1.enums.push(enm1);
+        model2.objects.push(obj2);
+        model2.enums.push(enm2);
+
+        // Act
+        model1.merge(&model2);
+
+        // Assert
+        assert_eq!(model1.objects.len(), 2);
+        assert_eq!(model1.enums.len(), 2);
+        assert_eq!(model1.objects[0].name, "Object1");
+        assert_eq!(model1.objects[1].name, "Object2");
+        assert_eq!(model1.enums[0].name, "Enum1");
+        assert_eq!(model1.enums[1].name, "Enum2");
+    }
+
+    #[test]
+    fn test_sort_attrs() {
+        // Arrange
+        let mut model = DataModel::new(None, None);
+        let mut obj = Object::new("Object1".to_string(), None);
+        obj.add_attribute(crate::attribute::Attribute {
+            name: "not_required".to_string(),
+            is_array: false,
+            is_id: false,
+            dtypes: vec!["string".to_string()],
+            docstring: "".to_string(),
+            options: vec![],
+            custom_options: vec![],
+            term: None,
+            required: false,
+            deprecated: false,
+            readonly: false,
+            alias: None,
+            examples: Vec::new(),
+            xml: None,
+            default: Some(DataType::String("".to_string())),
+            const_value: None,
+            is_enum: false,
+            position: None,
+        });
+
+        obj.add_attribute(crate::attribute::Attribute {
+            name: "required".to_string(),
+            is_array: false,
+            is_id: false,
+            dtypes: vec!["string".to_string()],
+            docstring: "".to_string(),
+            options: vec![],
+            custom_options: vec![],
+            term: None,
+            required: true,
+            deprecated: false,
+            readonly: false,
+            alias: None,
+            examples: Vec::new(),
+            xml: None,
+            default: None,
+            const_value: None,
+            is_enum: false,
+            position: None,
+        });
+
+        model.objects.push(obj);
+
+        // Act
+        model.sort_attrs();
+
+        // Assert
+        assert_eq!(model.objects[0].attributes[0].name, "required");
+        assert_eq!(model.objects[0].attributes[1].name, "not_required");
+    }
+
+    #[test]
+    fn test_from_internal_schema() {
+        // Arrange
+        let path = Path::new("tests/data/expected_internal_schema.json");
+
+        // Act
+        let model =
+            DataModel::from_internal_schema_file(path).expect("Failed to parse internal schema");
+
+        // Assert
+        assert_eq!(model.objects.len(), 2);
+        assert_eq!(model.enums.len(), 1);
+    }
+
+    #[test]
+    fn test_internal_schema_round_trip() {
+        for path in [
+            "tests/data/model.md",
+            "tests/data/model_full_documentation.md",
+        ] {
+            // Arrange
+            let model =
+                DataModel::from_markdown(Path::new(path)).expect("Failed to parse markdown");
+
+            // Act
+            let schema = model.internal_schema();
+            let restored =
+                DataModel::from_internal_schema(&schema).expect("Failed to parse internal schema");
+
+            // Assert
+            assert_eq!(model, restored, "Round trip failed for {}", path);
+        }
+    }
+
+    #[test]
+    fn test_from_markdown_w_html() {
+        // Arrange
+        let path = Path::new("tests/data/model_w_html.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Failed to parse markdown");
+
+        // Assert
+        assert_eq!(model.objects.len(), 2);
+        assert_eq!(model.enums.len(), 1);
+    }
+
+    #[test]
+    fn test_from_markdown_string() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let content = fs::read_to_string(path).unwrap();
+
+        // Act
+        let model =
+            DataModel::from_markdown_string(content.as_str()).expect("Failed to parse markdown");
+
+        // Assert
+        assert_eq!(model.objects.len(), 2);
+        assert_eq!(model.enums.len(), 1);
+    }
+
+    #[test]
+    fn test_json_schema_all_ids() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let model = DataModel::from_markdown(path).expect("Failed to parse markdown");
+        let out = std::env::temp_dir().join("mdmodels_json_schema_all_ids");
+
+        // Act
+        model
+            .json_schema_all(out.clone(), false)
+            .expect("Failed to write JSON schemas");
+
+        // Assert
+        for object in &model.objects {
+            let file = out.join(format!("{}.json", object.name));
+            let content = fs::read_to_string(&file).expect("Schema file not found");
+            let schema: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+            assert_eq!(
+                schema["$id"],
+                format!("https://www.github.com/my/repo/{}", object.name)
+            );
+
+            let definitions = schema["$defs"].as_object().cloned().unwrap_or_default();
+            for (name, definition) in definitions {
+                if !definition["enum"].is_array() {
+                    assert!(out.join(format!("{}.json", name)).exists());
+                }
+                assert_eq!(
+                    definition["$id"],
+                    format!("https://www.github.com/my/repo/{}", name)
+                );
+            }
+        }
+    }
+
+    /// Creates an object with a single required attribute of the given type
+    fn builder_object(name: &str, dtype: &str) -> Object {
+        let mut attribute = crate::attribute::Attribute::new("value".to_string(), true);
+        attribute.dtypes = vec![dtype.to_string()];
+
+        let mut object = Object::new(name.to_string(), None);
+        object.add_attribute(attribute);
+        object
+    }
+
+    /// Creates an enumeration with a single value
+    fn builder_enum(name: &str) -> Enumeration {
+        Enumeration::from_mapping(name, &[("KEY", "key")])
+    }
+
+    #[test]
+    fn test_builder() {
+        // Act
+        let model = DataModel::builder()
+            .set_name("Test".to_string())
+            .set_config(FrontMatter::default())
+            .add_object(builder_object("Sample", "Unit"))
+            .add_object(builder_object("Dataset", "Sample"))
+            .add_enum(builder_enum("Unit"))
+            .build()
+            .expect("Failed to build model");
+
+        // Assert
+        assert_eq!(model.name, Some("Test".to_string()));
+        assert_eq!(model.config, Some(FrontMatter::default()));
+        let names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, vec!["Sample", "Dataset"]);
+        assert_eq!(model.enums[0].name, "Unit");
+    }
+
+    #[test]
+    fn test_builder_rejects_duplicates() {
+        // Arrange
+        let mut builder = DataModel::builder();
+
+        // Act
+        builder
+            .add_object(builder_object("Sample", "string"))
+            .add_object(builder_object("Sample", "integer"))
+            .add_enum(builder_enum("Sample"));
+
+        // Assert
+        assert_eq!(builder.objects.len(), 1);
+        assert_eq!(builder.objects[0].attributes[0].dtypes, vec!["string"]);
+        assert!(builder.enums.is_empty());
+
+        let validator = builder.build().expect_err("Duplicates should be rejected");
+        let messages: Vec<&str> = validator
+            .errors
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Object 'Sample' is defined more than once.",
+                "Enumeration 'Sample' is defined more than once.",
+            ]
+        );
+        assert!(validator
+            .errors
+            .iter()
+            .all(|e| e.error_type == ErrorType::DuplicateError));
+    }
+
+    #[test]
+    fn test_builder_validates_model() {
+        // Act
+        let result = DataModel::builder()
+            .add_object(builder_object("Sample", "Unknown"))
+            .build();
+
+        // Assert
+        let validator = result.expect_err("Unknown types should be rejected");
+        assert!(!validator.is_valid);
+        assert!(validator
+            .errors
+            .iter()
+            .any(|e| e.error_type == ErrorType::TypeError));
+    }
+
+    #[test]
+    fn test_builder_empty() {
+        // Act
+        let result = DataModel::builder().build();
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_add_object_and_enum() {
+        // Arrange
+        let mut model = DataModel::new(None, None);
+        let enumeration = Enumeration {
+            name: "Status".to_string(),
+            mappings: BTreeMap::from([("OK".to_string(), "ok".to_string())]),
+            docstring: "".to_string(),
+            position: None,
+        };
+
+        // Act
+        let object = model.add_object(Object::new("Sample".to_string(), None));
+        let enumeration = model.add_enum(enumeration);
+
+        // Assert
+        assert_eq!(object, Ok(()));
+        assert_eq!(enumeration, Ok(()));
+        assert_eq!(model.objects[0].name, "Sample");
+        assert_eq!(model.enums[0].name, "Status");
+    }
+
+    #[test]
+    fn test_stats() {
+        // Arrange
+        let model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse model");
+
+        // Act
+        let stats = model.stats();
+
+        // Assert
+        assert_eq!(
+            stats,
+            ModelStats {
+                object_count: 2,
+                enum_count: 1,
+                total_attributes: 6,
+                required_attributes: 1,
+                array_attributes: 2,
+                documented_objects: 0,
+                documented_attributes: 1,
+                term_annotated_attributes: 5,
+                documentation_coverage: 1.0 / 6.0,
+                term_coverage: 5.0 / 6.0,
+            }
+        );
+
+        let table = stats.to_string();
+        assert!(table.contains("Attributes                         6"));
+        assert!(table.contains("Term coverage                  83.3%"));
+    }
+
+    #[test]
+    fn test_stats_empty_model() {
+        let stats = DataModel::new(None, None).stats();
+
+        assert_eq!(stats.total_attributes, 0);
+        assert_eq!(stats.documentation_coverage, 0.0);
+        assert_eq!(stats.term_coverage, 0.0);
+    }
+
+    #[test]
+    fn test_config_falls_back_to_default() {
+        // Arrange
+        let mut model = DataModel::new(None, None);
+
+        // Act & Assert
+        assert_eq!(model.config(), &FrontMatter::default());
+
+        model.config = Some(FrontMatter {
+            prefix: "custom".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(model.config().prefix, "custom");
+    }
+
+    #[test]
+    fn test_add_object_and_enum_duplicates() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse markdown");
+        let original = model.clone();
+        let enumeration = Enumeration {
+            name: "Test".to_string(),
+            mappings: BTreeMap::new(),
+            docstring: "".to_string(),
+            position: None,
+        };
+
+        // Act
+        let object = model.add_object(Object::new("Ontology".to_string(), None));
+        let enumeration = model.add_enum(enumeration);
+
+        // Assert
+        assert_eq!(object, Err(DuplicateError("Ontology".to_string())));
+        assert_eq!(enumeration, Err(DuplicateError("Test".to_string())));
+        assert_eq!(model, original);
+    }
+
+    #[test]
+    fn test_get_object_and_enum() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse markdown");
+
+        // Act
+        model.get_object_mut("Test2").unwrap().docstring = "Changed".to_string();
+        model
+            .get_enum_mut("Ontology")
+            .unwrap()
+            .docstring
+            .push_str("Changed");
+
+        // Assert
+        assert_eq!(model.get_object("Test2").unwrap().docstring, "Changed");
+        assert!(model
+            .get_enum("Ontology")
+            .unwrap()
+            .docstring
+            .ends_with("Changed"));
+        assert!(model.get_object("Ontology").is_none());
+        assert!(model.get_enum("Test").is_none());
+    }
+
+    #[test]
+    fn test_find_attribute() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse markdown");
+
+        // Act
+        let (object, attribute) = model
+            .find_attribute("Test2", "names")
+            .expect("Attribute not found");
+        let (object, attribute) = (object.name.clone(), attribute.name.clone());
+
+        model
+            .find_attribute_mut("Test", "name")
+            .expect("Attribute not found")
+            .docstring = "Changed".to_string();
+
+        // Assert
+        assert_eq!((object.as_str(), attribute.as_str()), ("Test2", "names"));
+        assert!(model.find_attribute("Test", "names").is_none());
+        assert!(model.find_attribute("Missing", "name").is_none());
+        assert!(model.find_attribute_mut("Test2", "missing").is_none());
+
+        let (_, attribute) = model.find_attribute("Test", "name").unwrap();
+        assert_eq!(attribute.docstring, "Changed");
+
+        let owners: Vec<&str> = model
+            .find_object_by_attribute("number")
+            .iter()
+            .map(|(object, _)| object.name.as_str())
+            .collect();
+        assert_eq!(owners, vec!["Test", "Test2"]);
+        assert!(model.find_object_by_attribute("missing").is_empty());
+    }
+
+    #[test]
+    fn test_all_attributes() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse markdown");
+
+        let mut expected = Vec::new();
+        for object in &model.objects {
+            for attribute in &object.attributes {
+                expected.push((object.name.clone(), attribute.name.clone()));
+            }
+        }
+
+        // Act
+        let flat: Vec<(String, String)> = model
+            .all_attributes()
+            .map(|(object, attribute)| (object.name.clone(), attribute.name.clone()))
+            .collect();
+
+        for (object, attribute) in model.all_attributes_mut() {
+            attribute.docstring = format!("{}.{}", object, attribute.name);
+        }
+
+        // Assert
+        assert_eq!(flat, expected);
+        assert_eq!(flat.len(), 6);
+
+        let docstrings: Vec<String> = model
+            .all_attributes()
+            .map(|(_, attribute)| attribute.docstring.clone())
+            .collect();
+        let expected: Vec<String> = expected
+            .iter()
+            .map(|(object, attribute)| format!("{}.{}", object, attribute))
+            .collect();
+        assert_eq!(docstrings, expected);
+    }
+
+    #[test]
+    fn test_topological_objects() {
+        // Arrange
+        let mut model = DataModel::from_markdown_string(
+            "# Test\n\n### Dataset\n\n- samples\n  - Type: Sample[]\n- author\n  - Type: Author\n\n### Sample\n\n- buffer\n  - Type: Buffer\n- author\n  - Type: Author\n\n### Author\n\n- name\n  - Type: string\n\n### Buffer\n\n- ph\n  - Type: float\n",
+        )
+        .expect("Failed to parse markdown");
+
+        // Act
+        let names: Vec<String> = model
+            .topological_objects()
+            .iter()
+            .map(|o| o.name.clone())
+            .collect();
+
+        // Assert
+        assert_eq!(names, vec!["Author", "Buffer", "Sample", "Dataset"]);
+        assert_eq!(
+            model.objects_in_topological_order(),
+            model.topological_objects()
+        );
 
-                valid = false;
+        let graph = dependency_graph(&model);
+        for (object, dependencies) in &graph {
+            let index = names.iter().position(|n| n == object).unwrap();
+            for dependency in dependencies {
+                assert!(names.iter().position(|n| n == dependency).unwrap() < index);
             }
         }
 
-        // If the merge is not valid, panic
-        if !valid {
-            panic!("Merge is not valid");
+        for object in model.topological_objects_mut() {
+            object.docstring = object.name.to_lowercase();
         }
-
-        // Merge the objects and enums
-        self.objects.extend(other.objects.clone());
-        self.enums.extend(other.enums.clone());
+        assert_eq!(model.objects[0].docstring, "dataset");
+        assert_eq!(model.topological_objects()[0].docstring, "author");
     }
 
-    /// Parse a markdown file and create a data model
-    ///
-    /// * `path` - Path to the markdown file
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use std::path::Path;
-    /// use mdmodels::datamodel::DataModel;
-    ///
-    /// let path = Path::new("tests/data/model.md");
-    /// let model = DataModel::from_markdown(path);
-    /// ```
-    /// # Returns
-    /// A data model
-    #[allow(clippy::result_large_err)]
-    pub fn from_markdown(path: &Path) -> Result<Self, Validator> {
-        let content = fs::read_to_string(path).expect("Could not read file");
-        parse_markdown(&content)
+    #[test]
+    fn test_sort_objects_topologically() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse markdown");
+        let expected: Vec<String> = model
+            .topological_objects()
+            .iter()
+            .map(|o| o.name.clone())
+            .collect();
+
+        // Act
+        model.sort_objects_topologically();
+
+        // Assert
+        let names: Vec<String> = model.objects.iter().map(|o| o.name.clone()).collect();
+        assert_eq!(names, vec!["Test2", "Test"]);
+        assert_eq!(names, expected);
+
+        model.sort_objects_topologically();
+        let resorted: Vec<String> = model.objects.iter().map(|o| o.name.clone()).collect();
+        assert_eq!(resorted, names);
     }
 
-    /// Parse a markdown file and create a data model
-    ///
-    /// * `path` - Path to the markdown file
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use std::path::Path;
-    /// use std::fs;
-    /// use mdmodels::datamodel::DataModel;
-    ///
-    /// let path = Path::new("tests/data/model.md");
-    /// let content = fs::read_to_string(path).unwrap();
-    /// let model = DataModel::from_markdown_string(content.as_str());
-    /// ```
-    /// # Returns
-    /// A data model
-    #[allow(clippy::result_large_err)]
-    pub fn from_markdown_string(content: &str) -> Result<Self, Validator> {
-        parse_markdown(content)
+    #[test]
+    fn test_diff_to_changelog() {
+        // Arrange
+        let old = DataModel::from_markdown_string(
+            "# Test\n\n### Sample\n\n- __name__\n  - Type: string\n- weight\n  - Type: integer\n\n### Legacy\n\n- value\n  - Type: string\n",
+        )
+        .expect("Failed to parse old model");
+        let new = DataModel::from_markdown_string(
+            "# Test\n\n### Sample\n\n- __name__\n  - Type: string\n- weight\n  - Type: float\n- __batch__\n  - Type: string\n\n### Buffer\n\n- ph\n  - Type: float\n\n### Archive\n\n- path\n  - Type: string\n",
+        )
+        .expect("Failed to parse new model");
+
+        // Act
+        let changelog = diff_to_changelog(&old, &new, None, None);
+        let released = diff_to_changelog(&old, &new, Some("1.1.0"), Some("2024-01-31"));
+        let undated = diff_to_changelog(&old, &new, Some("1.1.0"), None);
+
+        // Assert
+        let expected = "\
+## [Unreleased]
+
+### Added
+
+- Object `Buffer`
+- Object `Archive`
+- **BREAKING** Attribute `Sample.batch` (required)
+
+### Changed
+
+- **BREAKING** Attribute `Sample.weight` type changed from `integer` to `float`
+
+### Removed
+
+- **BREAKING** Object `Legacy`
+";
+        assert_eq!(changelog, expected);
+
+        let body = expected.split_once('\n').unwrap().1;
+        assert_eq!(released, format!("## [1.1.0] - 2024-01-31\n{}", body));
+        assert_eq!(undated, format!("## [1.1.0]\n{}", body));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::collections::BTreeMap;
+    #[test]
+    fn test_objects_referencing() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model_subset.md"))
+            .expect("Failed to parse markdown");
+        let mut union = Attribute::new("specimen".to_string(), false);
+        union.dtypes = vec!["string".to_string(), "Sample".to_string()];
+        model
+            .get_object_mut("Author")
+            .expect("Author not found")
+            .attributes
+            .push(union);
 
-    use crate::attribute::DataType;
+        // Act
+        let objects: Vec<&str> = model
+            .objects_referencing("Sample")
+            .iter()
+            .map(|object| object.name.as_str())
+            .collect();
+        let attributes: Vec<(&str, &str)> = model
+            .attributes_referencing("Sample")
+            .iter()
+            .map(|(object, attr)| (object.name.as_str(), attr.name.as_str()))
+            .collect();
+        let arrays: Vec<(&str, &str)> = model
+            .attributes_referencing("Measurement")
+            .iter()
+            .map(|(object, attr)| (object.name.as_str(), attr.name.as_str()))
+            .collect();
 
-    use super::*;
-    use pretty_assertions::assert_eq;
+        // Assert
+        assert_eq!(objects, vec!["Measurement", "Sample", "Author"]);
+        assert_eq!(
+            attributes,
+            vec![
+                ("Measurement", "sample"),
+                ("Sample", "parent"),
+                ("Author", "specimen")
+            ]
+        );
+        assert_eq!(arrays, vec![("Dataset", "measurements")]);
+        assert!(model.objects_referencing("Missing").is_empty());
+    }
 
     #[test]
-    fn test_merge() {
+    fn test_rename_object() {
         // Arrange
-        let mut model1 = DataModel::new(None, None);
-        let mut model2 = DataModel::new(None, None);
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model_subset.md"))
+            .expect("Failed to parse markdown");
+        model.objects[2].parent = Some("Measurement".to_string());
 
-        let mut obj1 = Object::new("Object1".to_string(), None);
-        obj1.add_attribute(crate::attribute::Attribute {
-            name: "test1".to_string(),
-            is_array: false,
-            is_id: false,
-            dtypes: vec!["string".to_string()],
-            docstring: "".to_string(),
-            options: vec![],
-            term: None,
-            required: false,
-            xml: None,
-            default: None,
-            is_enum: false,
-            position: None,
-        });
+        // Act
+        model
+            .rename_object("Sample", "Specimen")
+            .expect("Failed to rename object");
+        model
+            .rename_object("Measurement", "Run")
+            .expect("Failed to rename object");
 
-        let mut obj2 = Object::new("Object2".to_string(), None);
-        obj2.add_attribute(crate::attribute::Attribute {
-            name: "test2".to_string(),
-            is_array: false,
-            is_id: false,
-            dtypes: vec!["string".to_string()],
-            docstring: "".to_string(),
-            options: vec![],
-            term: None,
-            required: false,
-            xml: None,
-            default: None,
-            is_enum: false,
-            position: None,
-        });
+        // Assert
+        let names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, vec!["Dataset", "Run", "Specimen", "Author"]);
 
-        let enm1 = Enumeration {
-            name: "Enum1".to_string(),
-            mappings: BTreeMap::from([("key1".to_string(), "value1".to_string())]),
-            docstring: "".to_string(),
-            position: None,
+        let dtypes = |object: usize, attribute: usize| {
+            model.objects[object].attributes[attribute].dtypes.clone()
         };
+        assert_eq!(dtypes(0, 1), vec!["Run"]);
+        assert_eq!(dtypes(1, 2), vec!["Specimen"]);
+        assert_eq!(dtypes(2, 1), vec!["Specimen"]);
+        assert_eq!(model.objects[2].parent, Some("Run".to_string()));
+    }
 
-        let enm2 = Enumeration {
-            name: "Enum2".to_string(),
-            mappings: BTreeMap::from([("key2".to_string(), "value2".to_string())]),
-            docstring: "".to_string(),
-            position: None,
-        };
+    #[test]
+    fn test_remove_object_error_on_references() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model_subset.md"))
+            .expect("Failed to parse markdown");
+        let original = model.clone();
 
-        model1.objects.push(obj1);
-        model1.enums.push(enm1);
-        model2.objects.push(obj2);
-        model2.enums.push(enm2);
+        // Act
+        let result = model.remove_object("Sample", RemoveStrategy::ErrorOnReferences);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(RemoveError {
+                name: "Sample".to_string(),
+                references: vec!["Measurement.sample".to_string()],
+            })
+        );
+        assert_eq!(model, original);
+        assert_eq!(
+            model.remove_object("Missing", RemoveStrategy::ErrorOnReferences),
+            Ok(None)
+        );
+        assert_eq!(
+            model.remove_enum("Missing", RemoveStrategy::ErrorOnReferences),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_remove_object_referencing_attributes() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model_subset.md"))
+            .expect("Failed to parse markdown");
+        model.objects[3].parent = Some("Sample".to_string());
 
         // Act
-        model1.merge(&model2);
+        let removed = model
+            .remove_object("Sample", RemoveStrategy::RemoveReferencingAttributes)
+            .expect("Failed to remove object")
+            .expect("Object not found");
 
         // Assert
-        assert_eq!(model1.objects.len(), 2);
-        assert_eq!(model1.enums.len(), 2);
-        assert_eq!(model1.objects[0].name, "Object1");
-        assert_eq!(model1.objects[1].name, "Object2");
-        assert_eq!(model1.enums[0].name, "Enum1");
-        assert_eq!(model1.enums[1].name, "Enum2");
+        assert_eq!(removed.name, "Sample");
+        assert_eq!(removed.attributes.len(), 2);
+        assert!(model.get_object("Sample").is_none());
+
+        let measurement = model.get_object("Measurement").unwrap();
+        let names: Vec<&str> = measurement
+            .attributes
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["value", "unit"]);
+        assert_eq!(model.get_object("Author").unwrap().parent, None);
+
+        let mut validator = Validator::new();
+        validator.validate(&model);
+        assert!(validator.is_valid, "{}", validator);
     }
 
     #[test]
-    fn test_sort_attrs() {
+    fn test_remove_object_replace_with_string() {
         // Arrange
-        let mut model = DataModel::new(None, None);
-        let mut obj = Object::new("Object1".to_string(), None);
-        obj.add_attribute(crate::attribute::Attribute {
-            name: "not_required".to_string(),
-            is_array: false,
-            is_id: false,
-            dtypes: vec!["string".to_string()],
-            docstring: "".to_string(),
-            options: vec![],
-            term: None,
-            required: false,
-            xml: None,
-            default: Some(DataType::String("".to_string())),
-            is_enum: false,
-            position: None,
-        });
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model_subset.md"))
+            .expect("Failed to parse markdown");
 
-        obj.add_attribute(crate::attribute::Attribute {
-            name: "required".to_string(),
-            is_array: false,
-            is_id: false,
-            dtypes: vec!["string".to_string()],
-            docstring: "".to_string(),
-            options: vec![],
-            term: None,
-            required: true,
-            xml: None,
-            default: None,
-            is_enum: false,
-            position: None,
-        });
+        // Act
+        model
+            .remove_object("Measurement", RemoveStrategy::ReplaceWithString)
+            .expect("Failed to remove object");
 
-        model.objects.push(obj);
+        // Assert
+        let dataset = model.get_object("Dataset").unwrap();
+        assert_eq!(dataset.attributes[1].dtypes, vec!["string"]);
+        assert!(dataset.attributes[1].is_array);
+
+        let mut validator = Validator::new();
+        validator.validate(&model);
+        assert!(validator.is_valid, "{}", validator);
+    }
+
+    #[test]
+    fn test_remove_enum() {
+        // Arrange
+        let model = DataModel::from_markdown(Path::new("tests/data/model_subset.md"))
+            .expect("Failed to parse markdown");
 
         // Act
-        model.sort_attrs();
+        let mut rejected = model.clone();
+        let rejected_result = rejected.remove_enum("Unit", RemoveStrategy::ErrorOnReferences);
+
+        let mut removed = model.clone();
+        removed
+            .remove_enum("Unit", RemoveStrategy::RemoveReferencingAttributes)
+            .expect("Failed to remove enumeration");
+
+        let mut replaced = model.clone();
+        let enumeration = replaced
+            .remove_enum("Unit", RemoveStrategy::ReplaceWithString)
+            .expect("Failed to remove enumeration");
 
         // Assert
-        assert_eq!(model.objects[0].attributes[0].name, "required");
-        assert_eq!(model.objects[0].attributes[1].name, "not_required");
+        assert_eq!(
+            rejected_result,
+            Err(RemoveError {
+                name: "Unit".to_string(),
+                references: vec!["Measurement.unit".to_string()],
+            })
+        );
+        assert_eq!(rejected, model);
+
+        let measurement = removed.get_object("Measurement").unwrap();
+        assert!(measurement.attributes.iter().all(|a| a.name != "unit"));
+        assert!(removed.get_enum("Unit").is_none());
+
+        assert_eq!(enumeration.map(|e| e.name), Some("Unit".to_string()));
+        let unit = &replaced.get_object("Measurement").unwrap().attributes[1];
+        assert_eq!(unit.dtypes, vec!["string"]);
+        assert!(!unit.is_enum);
+
+        for model in [removed, replaced] {
+            let mut validator = Validator::new();
+            validator.validate(&model);
+            assert!(validator.is_valid, "{}", validator);
+        }
     }
 
     #[test]
-    fn test_from_internal_schema() {
+    fn test_rename_object_errors() {
         // Arrange
-        let path = Path::new("tests/data/expected_internal_schema.json");
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model_subset.md"))
+            .expect("Failed to parse markdown");
+        let original = model.clone();
+
+        // Act & Assert
+        assert_eq!(
+            model.rename_object("Missing", "Other"),
+            Err(RenameError::ObjectNotFound("Missing".to_string()))
+        );
+        assert_eq!(
+            model.rename_object("Sample", "Author"),
+            Err(RenameError::NameTaken("Author".to_string()))
+        );
+        assert_eq!(
+            model.rename_object("Sample", "Unit"),
+            Err(RenameError::NameTaken("Unit".to_string()))
+        );
+        assert!(matches!(
+            model.rename_object("Sample", "1 sample"),
+            Err(RenameError::InvalidName { messages, .. }) if messages.len() == 2
+        ));
+        assert_eq!(model, original);
+    }
+
+    #[test]
+    fn test_rename_enum() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model_subset.md"))
+            .expect("Failed to parse markdown");
 
         // Act
-        let model = DataModel::from_internal_schema(path).expect("Failed to parse internal schema");
+        model
+            .rename_enum("Unit", "UnitOfMeasure")
+            .expect("Failed to rename enumeration");
 
         // Assert
-        assert_eq!(model.objects.len(), 2);
-        assert_eq!(model.enums.len(), 1);
+        assert!(model.get_enum("Unit").is_none());
+        assert!(model.get_enum("UnitOfMeasure").is_some());
+
+        let (_, unit) = model
+            .find_attribute("Measurement", "unit")
+            .expect("Attribute not found");
+        assert_eq!(unit.dtypes, vec!["UnitOfMeasure"]);
+        assert!(unit.is_enum);
+        assert!(model.attributes_referencing("Unit").is_empty());
     }
 
     #[test]
-    fn test_from_markdown_w_html() {
+    fn test_rename_enum_errors() {
         // Arrange
-        let path = Path::new("tests/data/model_w_html.md");
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model_subset.md"))
+            .expect("Failed to parse markdown");
+        let original = model.clone();
+
+        // Act & Assert
+        assert_eq!(
+            model.rename_enum("Missing", "Other"),
+            Err(RenameError::EnumNotFound("Missing".to_string()))
+        );
+        assert_eq!(
+            model.rename_enum("Unit", "Role"),
+            Err(RenameError::NameTaken("Role".to_string()))
+        );
+        assert_eq!(
+            model.rename_enum("Unit", "Sample"),
+            Err(RenameError::NameTaken("Sample".to_string()))
+        );
+        assert!(matches!(
+            model.rename_enum("Unit", "unit of"),
+            Err(RenameError::InvalidName { .. })
+        ));
+        assert_eq!(model, original);
+    }
+
+    fn model_with_config(config: &str) -> DataModel {
+        let content = format!(
+            "---\n{}\n---\n\n### Test\n\n- name\n  - Type: string\n",
+            config
+        );
+        DataModel::from_markdown_string(&content).expect("Failed to parse markdown")
+    }
+
+    #[test]
+    fn test_schema_version() {
+        // Arrange
+        let model = model_with_config("version: 1.2.3-rc.1");
+        let invalid = model_with_config("version: latest");
+        let missing = model_with_config("prefix: tst");
 
         // Act
-        let model = DataModel::from_markdown(path).expect("Failed to parse markdown");
+        let version = model.schema_version().expect("Expected a version");
 
         // Assert
-        assert_eq!(model.objects.len(), 2);
-        assert_eq!(model.enums.len(), 1);
+        assert_eq!(version.to_string(), "1.2.3-rc.1");
+        assert!(invalid.schema_version().is_none());
+        assert!(missing.schema_version().is_none());
     }
 
     #[test]
-    fn test_from_markdown_string() {
+    fn test_is_compatible_with() {
         // Arrange
-        let path = Path::new("tests/data/model.md");
-        let content = fs::read_to_string(path).unwrap();
+        let model = model_with_config("version: 2.0.0\nrequires-version: \">=1.2.0, <2.0.0\"");
+        let compatible = model_with_config("version: 1.4.0");
+        let incompatible = model_with_config("version: 2.1.0");
+        let unversioned = model_with_config("prefix: tst");
+
+        // Act & Assert
+        assert!(model.is_compatible_with(&compatible));
+        assert!(!model.is_compatible_with(&incompatible));
+        assert!(!model.is_compatible_with(&unversioned));
+        assert!(compatible.is_compatible_with(&incompatible));
+    }
+}
+
+#[cfg(all(test, feature = "fetch"))]
+mod fetch_tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    /// Serves the given markdown content for a single request.
+    fn serve_once(content: &'static str) -> (String, std::thread::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind listener");
+        let address = listener.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                content.len(),
+                content
+            )
+            .unwrap();
+        });
+
+        (format!("http://{}/model.md", address), handle)
+    }
+
+    const MODEL: &str = "### Remote\n\n- name\n  - Type: string\n";
+    const MAX_AGE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_fetch_url_cached() {
+        // Arrange
+        let cache_dir = tempfile::tempdir().expect("Could not create temporary directory");
+        let (url, handle) = serve_once(MODEL);
 
         // Act
-        let model =
-            DataModel::from_markdown_string(content.as_str()).expect("Failed to parse markdown");
+        let fetched = fetch_url_cached(&url, cache_dir.path(), MAX_AGE).unwrap();
+        handle.join().unwrap();
+
+        // The server is gone, hence the second call has to use the cache
+        let cached = fetch_url_cached(&url, cache_dir.path(), MAX_AGE).unwrap();
 
         // Assert
-        assert_eq!(model.objects.len(), 2);
-        assert_eq!(model.enums.len(), 1);
+        assert_eq!(fetched, MODEL);
+        assert_eq!(cached, MODEL);
+        assert!(cache_dir
+            .path()
+            .join(format!("{}.md", url_hash(&url)))
+            .is_file());
+    }
+
+    #[test]
+    fn test_fetch_url_cached_unwritable_cache() {
+        // Arrange
+        let dir = tempfile::tempdir().expect("Could not create temporary directory");
+        let blocker = dir.path().join("blocker");
+        fs::write(&blocker, "").unwrap();
+        let (url, handle) = serve_once(MODEL);
+
+        // Act
+        let fetched = fetch_url_cached(&url, &blocker.join("cache"), MAX_AGE);
+        handle.join().unwrap();
+
+        // Assert
+        assert_eq!(fetched.unwrap(), MODEL);
+    }
+
+    #[test]
+    fn test_fetch_url_cached_expired() {
+        // Arrange
+        let cache_dir = tempfile::tempdir().expect("Could not create temporary directory");
+        let (url, handle) = serve_once(MODEL);
+        fetch_url_cached(&url, cache_dir.path(), MAX_AGE).unwrap();
+        handle.join().unwrap();
+
+        // Act
+        let result = fetch_url_cached(&url, cache_dir.path(), Duration::ZERO);
+
+        // Assert
+        assert!(result.is_err(), "Expired entries should be fetched again");
+    }
+
+    #[test]
+    fn test_url_hash() {
+        assert_eq!(
+            url_hash("https://example.com/model.md"),
+            url_hash("https://example.com/model.md")
+        );
+        assert_eq!(url_hash("https://example.com/model.md").len(), 64);
+        assert_ne!(
+            url_hash("https://example.com/model.md"),
+            url_hash("https://example.com/other.md")
+        );
     }
 }
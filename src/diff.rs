@@ -0,0 +1,721 @@
+/*
+ * Copyright (c) 2024 Jan Range
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ *
+ */
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::attribute::Attribute;
+use crate::datamodel::DataModel;
+use crate::object::{Enumeration, Object};
+
+/// Represents the semantic differences between two data models.
+///
+/// Positions are ignored, hence moving definitions within a file does not
+/// result in a difference.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ModelDiff {
+    /// Names of objects that only exist in the new model.
+    pub added_objects: Vec<String>,
+    /// Names of objects that only exist in the old model.
+    pub removed_objects: Vec<String>,
+    /// Objects that were renamed, as pairs of old and new names.
+    pub renamed_objects: Vec<(String, String)>,
+    /// Objects that exist in both models but differ.
+    pub modified_objects: Vec<ObjectDiff>,
+    /// Names of enumerations that only exist in the new model.
+    pub added_enums: Vec<String>,
+    /// Names of enumerations that only exist in the old model.
+    pub removed_enums: Vec<String>,
+    /// Enumerations that exist in both models but differ.
+    pub modified_enums: Vec<EnumDiff>,
+}
+
+/// Represents the differences of an object between two data models.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ObjectDiff {
+    /// Name of the object.
+    pub name: String,
+    /// Attributes that only exist in the new object.
+    pub added_attributes: Vec<AttributeRef>,
+    /// Attributes that only exist in the old object.
+    pub removed_attributes: Vec<AttributeRef>,
+    /// Attributes that exist in both objects but differ.
+    pub modified_attributes: Vec<AttributeDiff>,
+    /// Whether the docstring of the object has changed.
+    pub docstring_changed: bool,
+    /// The term of the object, if changed, as pair of old and new term.
+    pub term_changed: Option<(Option<String>, Option<String>)>,
+}
+
+/// Identifies an added or removed attribute.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AttributeRef {
+    /// Name of the attribute.
+    pub name: String,
+    /// Whether the attribute is required.
+    pub required: bool,
+}
+
+/// Represents the differences of an attribute between two data models.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AttributeDiff {
+    /// Name of the attribute.
+    pub name: String,
+    /// The individual changes of the attribute.
+    pub changes: Vec<AttributeChange>,
+}
+
+/// Represents a single change of an attribute.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub enum AttributeChange {
+    /// The data types have changed.
+    Dtypes { old: Vec<String>, new: Vec<String> },
+    /// The attribute has become required or optional.
+    Required { old: bool, new: bool },
+    /// The attribute has become an array or a single value.
+    Multiple { old: bool, new: bool },
+    /// The term has changed.
+    Term {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    /// The docstring has changed.
+    Docstring,
+}
+
+/// Represents the differences of an enumeration between two data models.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct EnumDiff {
+    /// Name of the enumeration.
+    pub name: String,
+    /// Keys that only exist in the new enumeration.
+    pub added_values: Vec<String>,
+    /// Keys that only exist in the old enumeration.
+    pub removed_values: Vec<String>,
+    /// Keys whose value has changed.
+    pub modified_values: Vec<String>,
+}
+
+impl ModelDiff {
+    /// Computes the differences between an old and a new data model.
+    ///
+    /// Objects that were removed and added with the same set of attribute
+    /// names are reported as renamed objects.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The old data model.
+    /// * `new` - The new data model.
+    ///
+    /// # Returns
+    ///
+    /// A `ModelDiff` describing the changes from `old` to `new`.
+    pub fn new(old: &DataModel, new: &DataModel) -> Self {
+        let mut diff = ModelDiff::default();
+
+        let mut removed: Vec<&Object> = old
+            .objects
+            .iter()
+            .filter(|o| !new.objects.iter().any(|n| n.name == o.name))
+            .collect();
+        let mut added: Vec<&Object> = new
+            .objects
+            .iter()
+            .filter(|o| !old.objects.iter().any(|n| n.name == o.name))
+            .collect();
+
+        // Detect renamed objects by comparing their attribute names
+        removed.retain(|old_object| {
+            let renamed = added
+                .iter()
+                .position(|new_object| same_attribute_names(old_object, new_object));
+
+            match renamed {
+                Some(index) => {
+                    let new_object = added.remove(index);
+                    diff.renamed_objects
+                        .push((old_object.name.clone(), new_object.name.clone()));
+                    false
+                }
+                None => true,
+            }
+        });
+
+        diff.removed_objects = removed.iter().map(|o| o.name.clone()).collect();
+        diff.added_objects = added.iter().map(|o| o.name.clone()).collect();
+
+        for old_object in &old.objects {
+            let new_object = new.get_object(&old_object.name);
+            if let Some(object_diff) = new_object.and_then(|n| ObjectDiff::new(old_object, n)) {
+                diff.modified_objects.push(object_diff);
+            }
+        }
+
+        diff.removed_enums = old
+            .enums
+            .iter()
+            .filter(|e| !new.enums.iter().any(|n| n.name == e.name))
+            .map(|e| e.name.clone())
+            .collect();
+        diff.added_enums = new
+            .enums
+            .iter()
+            .filter(|e| !old.enums.iter().any(|n| n.name == e.name))
+            .map(|e| e.name.clone())
+            .collect();
+
+        for old_enum in &old.enums {
+            let new_enum = new.get_enum(&old_enum.name);
+            if let Some(enum_diff) = new_enum.and_then(|n| EnumDiff::new(old_enum, n)) {
+                diff.modified_enums.push(enum_diff);
+            }
+        }
+
+        diff
+    }
+
+    /// Checks whether there are no differences.
+    pub fn is_empty(&self) -> bool {
+        *self == ModelDiff::default()
+    }
+
+    /// Renders the differences as a human-readable summary.
+    ///
+    /// Each line is prefixed with `+` for additions, `-` for removals and `~`
+    /// for modifications. Changes of attributes and enumeration values are
+    /// indented below their object or enumeration.
+    ///
+    /// # Returns
+    ///
+    /// A string listing all differences, or `No changes.` if there are none.
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "No changes.\n".to_string();
+        }
+
+        let mut summary = String::new();
+
+        for name in &self.added_objects {
+            let _ = writeln!(summary, "+ Object {}", name);
+        }
+        for name in &self.removed_objects {
+            let _ = writeln!(summary, "- Object {}", name);
+        }
+        for (old, new) in &self.renamed_objects {
+            let _ = writeln!(summary, "~ Object {} -> {}", old, new);
+        }
+
+        for object in &self.modified_objects {
+            let _ = writeln!(summary, "~ Object {}", object.name);
+
+            if object.term_changed.is_some() {
+                let _ = writeln!(summary, "    ~ term");
+            }
+            if object.docstring_changed {
+                let _ = writeln!(summary, "    ~ description");
+            }
+            for attribute in &object.added_attributes {
+                let _ = writeln!(summary, "    + {}", attribute.name);
+            }
+            for attribute in &object.removed_attributes {
+                let _ = writeln!(summary, "    - {}", attribute.name);
+            }
+            for attribute in &object.modified_attributes {
+                let changes: Vec<&str> = attribute.changes.iter().map(|c| c.label()).collect();
+                let _ = writeln!(summary, "    ~ {} ({})", attribute.name, changes.join(", "));
+            }
+        }
+
+        for name in &self.added_enums {
+            let _ = writeln!(summary, "+ Enumeration {}", name);
+        }
+        for name in &self.removed_enums {
+            let _ = writeln!(summary, "- Enumeration {}", name);
+        }
+
+        for enumeration in &self.modified_enums {
+            let _ = writeln!(summary, "~ Enumeration {}", enumeration.name);
+
+            for key in &enumeration.added_values {
+                let _ = writeln!(summary, "    + {}", key);
+            }
+            for key in &enumeration.removed_values {
+                let _ = writeln!(summary, "    - {}", key);
+            }
+            for key in &enumeration.modified_values {
+                let _ = writeln!(summary, "    ~ {}", key);
+            }
+        }
+
+        summary
+    }
+
+    /// Renders the differences as a Markdown changelog entry.
+    ///
+    /// The output follows the Keep a Changelog conventions with an `[Unreleased]`
+    /// section and `Added`, `Changed` and `Removed` subsections. Changes that break
+    /// existing data or consumers are marked as `**BREAKING**`.
+    ///
+    /// # Returns
+    ///
+    /// A Markdown string containing the changelog entry.
+    pub fn to_changelog(&self) -> String {
+        self.to_changelog_with_heading("[Unreleased]")
+    }
+
+    /// Renders the differences as a Markdown changelog entry with a custom heading.
+    ///
+    /// See `ModelDiff::to_changelog` for the layout of the entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `heading` - The heading of the section, e.g. `[1.0.0] - 2024-01-31`.
+    ///
+    /// # Returns
+    ///
+    /// A Markdown string containing the changelog entry.
+    pub fn to_changelog_with_heading(&self, heading: &str) -> String {
+        let mut added = Vec::new();
+        let mut changed = Vec::new();
+        let mut removed = Vec::new();
+
+        for name in &self.added_objects {
+            added.push(format!("Object `{}`", name));
+        }
+        for name in &self.added_enums {
+            added.push(format!("Enumeration `{}`", name));
+        }
+
+        for (old, new) in &self.renamed_objects {
+            changed.push(breaking(format!("Object `{}` renamed to `{}`", old, new)));
+        }
+
+        for object in &self.modified_objects {
+            for attribute in &object.added_attributes {
+                let entry = format!("Attribute `{}.{}`", object.name, attribute.name);
+                if attribute.required {
+                    added.push(breaking(format!("{} (required)", entry)));
+                } else {
+                    added.push(entry);
+                }
+            }
+
+            if let Some((old, new)) = &object.term_changed {
+                changed.push(format!(
+                    "Object `{}` term changed from {} to {}",
+                    object.name,
+                    display_term(old),
+                    display_term(new)
+                ));
+            }
+            if object.docstring_changed {
+                changed.push(format!("Object `{}` description changed", object.name));
+            }
+
+            for attribute in &object.modified_attributes {
+                let name = format!("`{}.{}`", object.name, attribute.name);
+                for change in &attribute.changes {
+                    changed.push(change.to_changelog_entry(&name));
+                }
+            }
+
+            for attribute in &object.removed_attributes {
+                let entry = format!("Attribute `{}.{}`", object.name, attribute.name);
+                if attribute.required {
+                    removed.push(breaking(format!("{} (required)", entry)));
+                } else {
+                    removed.push(entry);
+                }
+            }
+        }
+
+        for enumeration in &self.modified_enums {
+            for key in &enumeration.added_values {
+                added.push(format!(
+                    "Value `{}` of enumeration `{}`",
+                    key, enumeration.name
+                ));
+            }
+            for key in &enumeration.modified_values {
+                changed.push(breaking(format!(
+                    "Value `{}` of enumeration `{}` changed",
+                    key, enumeration.name
+                )));
+            }
+            for key in &enumeration.removed_values {
+                removed.push(breaking(format!(
+                    "Value `{}` of enumeration `{}`",
+                    key, enumeration.name
+                )));
+            }
+        }
+
+        for name in &self.removed_objects {
+            removed.push(breaking(format!("Object `{}`", name)));
+        }
+        for name in &self.removed_enums {
+            removed.push(breaking(format!("Enumeration `{}`", name)));
+        }
+
+        let mut changelog = format!("## {}\n", heading);
+
+        if self.is_empty() {
+            changelog.push_str("\nNo changes.\n");
+            return changelog;
+        }
+
+        for (title, entries) in [("Added", added), ("Changed", changed), ("Removed", removed)] {
+            if entries.is_empty() {
+                continue;
+            }
+
+            let _ = write!(changelog, "\n### {}\n\n", title);
+            for entry in entries {
+                let _ = writeln!(changelog, "- {}", entry);
+            }
+        }
+
+        changelog
+    }
+}
+
+impl ObjectDiff {
+    /// Computes the differences between two versions of an object.
+    ///
+    /// # Returns
+    ///
+    /// An `ObjectDiff` or `None` if both objects are semantically equal.
+    fn new(old: &Object, new: &Object) -> Option<Self> {
+        let diff = ObjectDiff {
+            name: new.name.clone(),
+            added_attributes: new
+                .attributes
+                .iter()
+                .filter(|a| !old.attributes.iter().any(|o| o.name == a.name))
+                .map(AttributeRef::from)
+                .collect(),
+            removed_attributes: old
+                .attributes
+                .iter()
+                .filter(|a| !new.attributes.iter().any(|n| n.name == a.name))
+                .map(AttributeRef::from)
+                .collect(),
+            modified_attributes: old
+                .attributes
+                .iter()
+                .filter_map(|o| {
+                    let n = new.attributes.iter().find(|n| n.name == o.name)?;
+                    AttributeDiff::new(o, n)
+                })
+                .collect(),
+            docstring_changed: old.docstring != new.docstring,
+            term_changed: (old.term != new.term).then(|| (old.term.clone(), new.term.clone())),
+        };
+
+        let unchanged = diff.added_attributes.is_empty()
+            && diff.removed_attributes.is_empty()
+            && diff.modified_attributes.is_empty()
+            && !diff.docstring_changed
+            && diff.term_changed.is_none();
+
+        (!unchanged).then_some(diff)
+    }
+}
+
+impl From<&Attribute> for AttributeRef {
+    fn from(attribute: &Attribute) -> Self {
+        AttributeRef {
+            name: attribute.name.clone(),
+            required: attribute.required,
+        }
+    }
+}
+
+impl AttributeDiff {
+    /// Computes the differences between two versions of an attribute.
+    ///
+    /// # Returns
+    ///
+    /// An `AttributeDiff` or `None` if both attributes are semantically equal.
+    fn new(old: &Attribute, new: &Attribute) -> Option<Self> {
+        let mut changes = Vec::new();
+
+        if old.dtypes != new.dtypes {
+            changes.push(AttributeChange::Dtypes {
+                old: old.dtypes.clone(),
+                new: new.dtypes.clone(),
+            });
+        }
+        if old.required != new.required {
+            changes.push(AttributeChange::Required {
+                old: old.required,
+                new: new.required,
+            });
+        }
+        if old.is_array != new.is_array {
+            changes.push(AttributeChange::Multiple {
+                old: old.is_array,
+                new: new.is_array,
+            });
+        }
+        if old.term != new.term {
+            changes.push(AttributeChange::Term {
+                old: old.term.clone(),
+                new: new.term.clone(),
+            });
+        }
+        if old.docstring != new.docstring {
+            changes.push(AttributeChange::Docstring);
+        }
+
+        (!changes.is_empty()).then(|| AttributeDiff {
+            name: new.name.clone(),
+            changes,
+        })
+    }
+}
+
+impl AttributeChange {
+    /// Checks whether the change breaks existing data or consumers.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            AttributeChange::Dtypes { .. } | AttributeChange::Multiple { .. } => true,
+            AttributeChange::Required { new, .. } => *new,
+            AttributeChange::Term { .. } | AttributeChange::Docstring => false,
+        }
+    }
+
+    /// Returns a short label naming the changed property.
+    fn label(&self) -> &'static str {
+        match self {
+            AttributeChange::Dtypes { .. } => "type",
+            AttributeChange::Required { .. } => "required",
+            AttributeChange::Multiple { .. } => "multiple",
+            AttributeChange::Term { .. } => "term",
+            AttributeChange::Docstring => "description",
+        }
+    }
+
+    /// Renders the change as a changelog entry for the given attribute.
+    fn to_changelog_entry(&self, attribute: &str) -> String {
+        let entry = match self {
+            AttributeChange::Dtypes { old, new } => format!(
+                "Attribute {} type changed from `{}` to `{}`",
+                attribute,
+                old.join(", "),
+                new.join(", ")
+            ),
+            AttributeChange::Required { new: true, .. } => {
+                format!("Attribute {} is now required", attribute)
+            }
+            AttributeChange::Required { new: false, .. } => {
+                format!("Attribute {} is no longer required", attribute)
+            }
+            AttributeChange::Multiple { new: true, .. } => {
+                format!("Attribute {} now holds multiple values", attribute)
+            }
+            AttributeChange::Multiple { new: false, .. } => {
+                format!("Attribute {} no longer holds multiple values", attribute)
+            }
+            AttributeChange::Term { old, new } => format!(
+                "Attribute {} term changed from {} to {}",
+                attribute,
+                display_term(old),
+                display_term(new)
+            ),
+            AttributeChange::Docstring => format!("Attribute {} description changed", attribute),
+        };
+
+        if self.is_breaking() {
+            breaking(entry)
+        } else {
+            entry
+        }
+    }
+}
+
+impl EnumDiff {
+    /// Computes the differences between two versions of an enumeration.
+    ///
+    /// # Returns
+    ///
+    /// An `EnumDiff` or `None` if both enumerations have the same mappings.
+    fn new(old: &Enumeration, new: &Enumeration) -> Option<Self> {
+        let diff = EnumDiff {
+            name: new.name.clone(),
+            added_values: new
+                .mappings
+                .keys()
+                .filter(|k| !old.mappings.contains_key(*k))
+                .cloned()
+                .collect(),
+            removed_values: old
+                .mappings
+                .keys()
+                .filter(|k| !new.mappings.contains_key(*k))
+                .cloned()
+                .collect(),
+            modified_values: old
+                .mappings
+                .iter()
+                .filter(|(k, v)| new.mappings.get(*k).is_some_and(|n| n != *v))
+                .map(|(k, _)| k.clone())
+                .collect(),
+        };
+
+        let unchanged = diff.added_values.is_empty()
+            && diff.removed_values.is_empty()
+            && diff.modified_values.is_empty();
+
+        (!unchanged).then_some(diff)
+    }
+}
+
+/// Checks whether two objects share the same non-empty set of attribute names.
+fn same_attribute_names(a: &Object, b: &Object) -> bool {
+    let names = |o: &Object| {
+        o.attributes
+            .iter()
+            .map(|a| a.name.clone())
+            .collect::<BTreeSet<_>>()
+    };
+
+    !a.attributes.is_empty() && names(a) == names(b)
+}
+
+/// Marks a changelog entry as breaking.
+fn breaking(entry: String) -> String {
+    format!("**BREAKING** {}", entry)
+}
+
+/// Formats an optional term for a changelog entry.
+fn display_term(term: &Option<String>) -> String {
+    match term {
+        Some(term) => format!("`{}`", term),
+        None => "none".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+    use std::path::Path;
+
+    fn evolution() -> (DataModel, DataModel) {
+        let old = DataModel::from_markdown(Path::new("tests/data/model_diff_v1.md"))
+            .expect("Failed to parse old model");
+        let new = DataModel::from_markdown(Path::new("tests/data/model_diff_v2.md"))
+            .expect("Failed to parse new model");
+        (old, new)
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        // Arrange
+        let (old, _) = evolution();
+
+        // Act
+        let diff = ModelDiff::new(&old, &old.clone());
+
+        // Assert
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_changelog(), "## [Unreleased]\n\nNo changes.\n");
+        assert_eq!(diff.summary(), "No changes.\n");
+    }
+
+    #[test]
+    fn test_diff() {
+        // Arrange
+        let (old, new) = evolution();
+
+        // Act
+        let diff = ModelDiff::new(&old, &new);
+
+        // Assert
+        assert_eq!(diff.added_objects, vec!["Publication"]);
+        assert_eq!(diff.removed_objects, vec!["Legacy"]);
+        assert_eq!(
+            diff.renamed_objects,
+            vec![("Person".to_string(), "Author".to_string())]
+        );
+        assert_eq!(diff.added_enums, vec!["License"]);
+        assert!(diff.removed_enums.is_empty());
+
+        let dataset = &diff.modified_objects[0];
+        assert_eq!(dataset.name, "Dataset");
+        assert_eq!(dataset.added_attributes[0].name, "license");
+        assert_eq!(dataset.removed_attributes[0].name, "identifier");
+        assert!(dataset.removed_attributes[0].required);
+        assert_eq!(dataset.modified_attributes.len(), 4);
+
+        let status = &diff.modified_enums[0];
+        assert_eq!(status.added_values, vec!["ARCHIVED"]);
+        assert_eq!(status.removed_values, vec!["DRAFT"]);
+    }
+
+    #[test]
+    fn test_diff_changelog() {
+        // Arrange
+        let (old, new) = evolution();
+        let expected = fs::read_to_string("tests/data/expected_changelog.md")
+            .expect("Could not read expected changelog");
+
+        // Act
+        let changelog = ModelDiff::new(&old, &new).to_changelog();
+
+        // Assert
+        assert_eq!(changelog, expected);
+    }
+
+    #[test]
+    fn test_diff_summary() {
+        // Arrange
+        let (old, new) = evolution();
+
+        // Act
+        let summary = ModelDiff::new(&old, &new).summary();
+
+        // Assert
+        let expected = "\
++ Object Publication
+- Object Legacy
+~ Object Person -> Author
+~ Object Dataset
+    + license
+    - identifier
+    ~ size (type)
+    ~ keywords (required)
+    ~ status (term)
+    ~ authors (type)
++ Enumeration License
+~ Enumeration Status
+    + ARCHIVED
+    - DRAFT
+";
+        assert_eq!(summary, expected);
+    }
+}
@@ -23,10 +23,17 @@
 
 use std::{collections::HashMap, error::Error, fmt::Display, str::FromStr};
 
-use crate::{datamodel::DataModel, markdown::frontmatter::FrontMatter};
+use crate::{
+    datamodel::DataModel,
+    json::export::{JsonSchemaDraft, JsonSchemaInheritance},
+    tree::{full_type_graph, topological_order},
+};
 use clap::ValueEnum;
+use convert_case::{Case, Casing};
 use lazy_static::lazy_static;
 use minijinja::{context, Environment};
+use regex::Regex;
+use serde::Serialize;
 use textwrap::wrap;
 
 #[cfg(feature = "python")]
@@ -43,6 +50,8 @@ lazy_static! {
         m.insert("integer".to_string(), "int".to_string());
         m.insert("boolean".to_string(), "bool".to_string());
         m.insert("number".to_string(), "float".to_string());
+        m.insert("decimal".to_string(), "Decimal".to_string());
+        m.insert("bigint".to_string(), "int".to_string());
         m
     };
 
@@ -51,6 +60,7 @@ lazy_static! {
         let mut m = std::collections::HashMap::new();
         m.insert("float".to_string(), "double".to_string());
         m.insert("bytes".to_string(), "base64Binary".to_string());
+        m.insert("bigint".to_string(), "integer".to_string());
         m
     };
 
@@ -59,6 +69,7 @@ lazy_static! {
         let mut m = std::collections::HashMap::new();
         m.insert("str".to_string(), "string".to_string());
         m.insert("bytes".to_string(), "base64Binary".to_string());
+        m.insert("bigint".to_string(), "integer".to_string());
         m
     };
 
@@ -69,8 +80,87 @@ lazy_static! {
         m.insert("float".to_string(), "number".to_string());
         m.insert("date".to_string(), "string".to_string());
         m.insert("bytes".to_string(), "string".to_string());
+        m.insert("decimal".to_string(), "string".to_string());
         m
     };
+
+    /// Maps MD-Models type names to Kotlin-specific type names.
+    static ref KOTLIN_TYPE_MAPS: std::collections::HashMap<String, String> = {
+        let mut m = std::collections::HashMap::new();
+        m.insert("integer".to_string(), "Int".to_string());
+        m.insert("number".to_string(), "Double".to_string());
+        m.insert("float".to_string(), "Double".to_string());
+        m.insert("boolean".to_string(), "Boolean".to_string());
+        m.insert("string".to_string(), "String".to_string());
+        m.insert("bytes".to_string(), "ByteArray".to_string());
+        m.insert("date".to_string(), "String".to_string());
+        m.insert("decimal".to_string(), "String".to_string());
+        m.insert("bigint".to_string(), "Long".to_string());
+        m
+    };
+
+    /// Maps MD-Models type names to Scala-specific type names.
+    static ref SCALA_TYPE_MAPS: std::collections::HashMap<String, String> = {
+        let mut m = std::collections::HashMap::new();
+        m.insert("integer".to_string(), "Int".to_string());
+        m.insert("number".to_string(), "Double".to_string());
+        m.insert("float".to_string(), "Double".to_string());
+        m.insert("boolean".to_string(), "Boolean".to_string());
+        m.insert("string".to_string(), "String".to_string());
+        m.insert("bytes".to_string(), "Array[Byte]".to_string());
+        m.insert("date".to_string(), "String".to_string());
+        m.insert("decimal".to_string(), "BigDecimal".to_string());
+        m.insert("bigint".to_string(), "BigInt".to_string());
+        m
+    };
+
+    /// Maps MD-Models type names to Avro primitive type names.
+    static ref AVRO_TYPE_MAPS: std::collections::HashMap<String, String> = {
+        let mut m = std::collections::HashMap::new();
+        m.insert("string".to_string(), "string".to_string());
+        m.insert("integer".to_string(), "int".to_string());
+        m.insert("number".to_string(), "double".to_string());
+        m.insert("float".to_string(), "double".to_string());
+        m.insert("boolean".to_string(), "boolean".to_string());
+        m.insert("bytes".to_string(), "bytes".to_string());
+        m.insert("date".to_string(), "string".to_string());
+        m.insert("decimal".to_string(), "string".to_string());
+        m.insert("bigint".to_string(), "long".to_string());
+        m
+    };
+
+    /// Maps MD-Models type names to C#-specific type names.
+    static ref CSHARP_TYPE_MAPS: std::collections::HashMap<String, String> = {
+        let mut m = std::collections::HashMap::new();
+        m.insert("integer".to_string(), "int".to_string());
+        m.insert("number".to_string(), "double".to_string());
+        m.insert("float".to_string(), "double".to_string());
+        m.insert("boolean".to_string(), "bool".to_string());
+        m.insert("bytes".to_string(), "byte[]".to_string());
+        m.insert("date".to_string(), "DateOnly".to_string());
+        m.insert("bigint".to_string(), "long".to_string());
+        m
+    };
+
+    /// Jinja environment with all embedded templates and custom functions.
+    ///
+    /// Templates are compiled when added, hence the environment is only built once.
+    static ref JINJA_ENV: Environment<'static> = {
+        let mut env = Environment::new();
+        minijinja_embed::load_templates!(&mut env);
+
+        // Add custom functions to the Jinja environment
+        env.add_function("wrap", wrap_text);
+        env.add_filter("pretty_json", pretty_json);
+        env.add_filter("pascal_case", pascal_case);
+        env
+    };
+
+    /// Matches trailing whitespace at the end of each line.
+    static ref TRAILING_WHITESPACE: Regex = Regex::new(r"(?m)[^\S\n]+$").unwrap();
+
+    /// Matches more than two consecutive empty lines.
+    static ref EXCESS_EMPTY_LINES: Regex = Regex::new(r"\n{4,}").unwrap();
 }
 
 /// Enumeration of available templates.
@@ -88,9 +178,24 @@ pub enum Templates {
     PythonDataclass,
     PythonPydanticXML,
     PythonPydantic,
+    PythonAttrs,
     MkDocs,
     Internal,
     Typescript,
+    TypescriptInterface,
+    Kotlin,
+    #[value(name = "csharp")]
+    CSharp,
+    #[value(name = "openapi")]
+    OpenApi,
+    Avro,
+    Scala,
+    #[value(name = "plantuml")]
+    PlantUml,
+    #[value(name = "mermaid-erd")]
+    MermaidErd,
+    #[value(name = "jsonld-context")]
+    JsonLdContext,
 }
 
 impl Display for Templates {
@@ -99,6 +204,7 @@ impl Display for Templates {
             Templates::PythonDataclass => write!(f, "python-dataclass"),
             Templates::PythonPydantic => write!(f, "python-pydantic"),
             Templates::PythonPydanticXML => write!(f, "python-pydantic-xml"),
+            Templates::PythonAttrs => write!(f, "python-attrs"),
             Templates::XmlSchema => write!(f, "xml-schema"),
             Templates::Markdown => write!(f, "markdown"),
             Templates::CompactMarkdown => write!(f, "compact-markdown"),
@@ -109,6 +215,15 @@ impl Display for Templates {
             Templates::MkDocs => write!(f, "mk-docs"),
             Templates::Internal => write!(f, "internal"),
             Templates::Typescript => write!(f, "typescript"),
+            Templates::TypescriptInterface => write!(f, "typescript-interface"),
+            Templates::Kotlin => write!(f, "kotlin"),
+            Templates::CSharp => write!(f, "csharp"),
+            Templates::OpenApi => write!(f, "openapi"),
+            Templates::Avro => write!(f, "avro"),
+            Templates::Scala => write!(f, "scala"),
+            Templates::PlantUml => write!(f, "plantuml"),
+            Templates::MermaidErd => write!(f, "mermaid-erd"),
+            Templates::JsonLdContext => write!(f, "jsonld-context"),
         }
     }
 }
@@ -122,6 +237,7 @@ impl FromStr for Templates {
             "python-dataclass" => Ok(Templates::PythonDataclass),
             "python-sdrdm" => Ok(Templates::PythonPydanticXML),
             "python-pydantic" => Ok(Templates::PythonPydantic),
+            "python-attrs" => Ok(Templates::PythonAttrs),
             "xml-schema" => Ok(Templates::XmlSchema),
             "markdown" => Ok(Templates::Markdown),
             "compact-markdown" => Ok(Templates::CompactMarkdown),
@@ -132,6 +248,15 @@ impl FromStr for Templates {
             "mk-docs" => Ok(Templates::MkDocs),
             "internal" => Ok(Templates::Internal),
             "typescript" => Ok(Templates::Typescript),
+            "typescript-interface" => Ok(Templates::TypescriptInterface),
+            "kotlin" => Ok(Templates::Kotlin),
+            "csharp" => Ok(Templates::CSharp),
+            "openapi" => Ok(Templates::OpenApi),
+            "avro" => Ok(Templates::Avro),
+            "scala" => Ok(Templates::Scala),
+            "plantuml" => Ok(Templates::PlantUml),
+            "mermaid-erd" => Ok(Templates::MermaidErd),
+            "jsonld-context" => Ok(Templates::JsonLdContext),
             _ => {
                 let err = format!("Invalid template type: {}", s);
                 Err(err.into())
@@ -140,6 +265,131 @@ impl FromStr for Templates {
     }
 }
 
+/// Typed configuration of the Jinja templates.
+///
+/// Known options are parsed into dedicated fields. All other options are kept in
+/// `extra` and are available to the templates by their key.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TemplateConfig {
+    /// Whether Go structs carry GORM tags.
+    pub gorm: bool,
+    /// Whether Python classes use Astropy units for quantities.
+    pub astropy: bool,
+    /// Whether generated classes serialize to JSON-LD.
+    pub json_ld: bool,
+    /// Whether the navigation is shown in MkDocs pages.
+    pub nav: bool,
+    /// Whether Protobuf definitions use the `proto2` syntax.
+    pub proto2: bool,
+    /// Whether Rust types implement `Display`.
+    pub display: bool,
+    /// Whether Rust fields skip serializing `None` values.
+    pub serde_skip_none: bool,
+    /// Whether Pydantic models are frozen and hashable by their keys.
+    pub python_frozen: bool,
+    /// Whether Pydantic models declare attribute constraints using the v2 API.
+    pub pydantic_v2: bool,
+    /// The serialization library of the Kotlin template, e.g. `jackson`.
+    pub kotlin_serializer: Option<String>,
+    /// The JSON library of the C# template, either `system` or `newtonsoft`.
+    pub csharp_json: Option<String>,
+    /// Whether the Scala template generates circe encoders and decoders.
+    pub circe: bool,
+    /// Whether the TypeScript interface template declares all fields as `readonly`.
+    pub readonly: bool,
+    /// Base URI that replaces the repository in JSON schema `$id` values.
+    pub json_schema_base_uri: Option<String>,
+    /// Whether JSON schemas are exported as draft-07 instead of draft 2020-12.
+    pub draft07: bool,
+    /// The encoding of inherited attributes in JSON schemas, e.g. `allof`.
+    pub json_schema_inherit: Option<String>,
+    /// Options without a dedicated field.
+    #[serde(flatten)]
+    pub extra: HashMap<String, String>,
+}
+
+impl Default for TemplateConfig {
+    fn default() -> Self {
+        Self {
+            gorm: false,
+            astropy: false,
+            json_ld: false,
+            nav: true,
+            proto2: false,
+            display: false,
+            serde_skip_none: false,
+            python_frozen: false,
+            pydantic_v2: false,
+            kotlin_serializer: None,
+            csharp_json: None,
+            circe: false,
+            readonly: false,
+            json_schema_base_uri: None,
+            draft07: false,
+            json_schema_inherit: None,
+            extra: HashMap::new(),
+        }
+    }
+}
+
+impl TemplateConfig {
+    /// Creates a configuration from untyped key-value options.
+    ///
+    /// Boolean options are enabled by `true`, case-insensitive. Options that are
+    /// not known are kept in `extra`.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - The options, e.g. from a pipeline file.
+    pub fn from_hashmap(m: &HashMap<String, String>) -> TemplateConfig {
+        let mut config = TemplateConfig::default();
+        let is_true = |value: &String| value.eq_ignore_ascii_case("true");
+
+        for (key, value) in m {
+            match key.as_str() {
+                "gorm" => config.gorm = is_true(value),
+                "astropy" => config.astropy = is_true(value),
+                "json_ld" => config.json_ld = is_true(value),
+                "nav" => config.nav = is_true(value),
+                "proto2" => config.proto2 = is_true(value),
+                "display" => config.display = is_true(value),
+                "serde_skip_none" => config.serde_skip_none = is_true(value),
+                "python_frozen" => config.python_frozen = is_true(value),
+                "pydantic_v2" => config.pydantic_v2 = is_true(value),
+                "kotlin_serializer" => config.kotlin_serializer = Some(value.clone()),
+                "csharp_json" => config.csharp_json = Some(value.clone()),
+                "circe" => config.circe = is_true(value),
+                "readonly" => config.readonly = is_true(value),
+                "json_schema_base_uri" => config.json_schema_base_uri = Some(value.clone()),
+                "draft07" => config.draft07 = is_true(value),
+                "json_schema_inherit" => config.json_schema_inherit = Some(value.clone()),
+                _ => {
+                    config.extra.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        config
+    }
+
+    /// Returns the JSON schema draft selected by the `draft07` option.
+    pub fn draft(&self) -> JsonSchemaDraft {
+        if self.draft07 {
+            JsonSchemaDraft::Draft07
+        } else {
+            JsonSchemaDraft::Draft202012
+        }
+    }
+
+    /// Returns the JSON schema inheritance encoding selected by the `json_schema_inherit` option.
+    pub fn inheritance(&self) -> JsonSchemaInheritance {
+        match &self.json_schema_inherit {
+            Some(value) if value.eq_ignore_ascii_case("allof") => JsonSchemaInheritance::AllOf,
+            _ => JsonSchemaInheritance::Inline,
+        }
+    }
+}
+
 /// Renders a Jinja template based on the provided template type and data model.
 ///
 /// # Arguments
@@ -153,34 +403,55 @@ impl FromStr for Templates {
 pub fn render_jinja_template(
     template: &Templates,
     model: &mut DataModel,
-    config: Option<&HashMap<String, String>>,
+    config: Option<&TemplateConfig>,
 ) -> Result<String, minijinja::Error> {
-    // Load the template environment
-    let mut env = Environment::new();
-    minijinja_embed::load_templates!(&mut env);
+    let env = &*JINJA_ENV;
+
+    // Excluded attributes are removed from a copy, so that the caller's model keeps them
+    let mut model = exclude_attributes(model, template);
+    let model = &mut model;
 
     // Perform type conversions and filtering based on the template
     match template {
         Templates::XmlSchema => convert_model_types(model, &XSD_TYPE_MAPS),
-        Templates::Typescript => convert_model_types(model, &TYPESCRIPT_TYPE_MAPS),
+        Templates::Typescript | Templates::TypescriptInterface => {
+            convert_model_types(model, &TYPESCRIPT_TYPE_MAPS)
+        }
+        Templates::Kotlin => {
+            convert_model_types(model, &KOTLIN_TYPE_MAPS);
+            sort_attributes_by_required(model);
+        }
+        Templates::CSharp => {
+            convert_model_types(model, &CSHARP_TYPE_MAPS);
+            sort_attributes_by_required(model);
+        }
+        Templates::Scala => {
+            convert_model_types(model, &SCALA_TYPE_MAPS);
+            sort_attributes_by_required(model);
+        }
+        Templates::Avro => {
+            convert_model_types(model, &AVRO_TYPE_MAPS);
+            sort_objects_by_dependencies(model);
+        }
         Templates::Shacl | Templates::Shex => {
             convert_model_types(model, &SHACL_TYPE_MAPS);
             filter_objects_wo_terms(model);
         }
-        Templates::PythonDataclass | Templates::PythonPydanticXML | Templates::PythonPydantic => {
+        Templates::PythonDataclass
+        | Templates::PythonPydanticXML
+        | Templates::PythonPydantic
+        | Templates::PythonAttrs => {
             convert_model_types(model, &PYTHON_TYPE_MAPS);
             sort_attributes_by_required(model);
         }
         _ => {}
     }
 
-    // Add custom functions to the Jinja environment
-    env.add_function("wrap", wrap_text);
-
     // Get the appropriate template
     let template = match template {
         Templates::PythonDataclass => env.get_template("python-dataclass.jinja")?,
         Templates::PythonPydantic => env.get_template("python-pydantic.jinja")?,
+        Templates::PythonAttrs => env.get_template("python-attrs.jinja")?,
         Templates::XmlSchema => env.get_template("xml-schema.jinja")?,
         Templates::Markdown => env.get_template("markdown.jinja")?,
         Templates::CompactMarkdown => env.get_template("markdown-compact.jinja")?,
@@ -189,6 +460,13 @@ pub fn render_jinja_template(
         Templates::PythonPydanticXML => env.get_template("python-pydantic-xml.jinja")?,
         Templates::MkDocs => env.get_template("mkdocs.jinja")?,
         Templates::Typescript => env.get_template("typescript.jinja")?,
+        Templates::TypescriptInterface => env.get_template("typescript-interface.jinja")?,
+        Templates::Kotlin => env.get_template("kotlin.jinja")?,
+        Templates::CSharp => env.get_template("csharp.jinja")?,
+        Templates::Avro => env.get_template("avro.jinja")?,
+        Templates::Scala => env.get_template("scala.jinja")?,
+        Templates::PlantUml => env.get_template("plantuml.jinja")?,
+        Templates::MermaidErd => env.get_template("mermaid-erd.jinja")?,
         _ => {
             panic!(
                 "The template is not available as a Jinja Template and should not be used using the jinja exporter.
@@ -197,12 +475,6 @@ pub fn render_jinja_template(
         }
     };
 
-    // If there is no config, create an empty one
-    // This is necessary to avoid errors when rendering the template
-    if model.config.is_none() {
-        model.config = Some(FrontMatter::default());
-    }
-
     // Render the template
     let prefixes = get_prefixes(model);
     let rendered = template.render(context! {
@@ -212,8 +484,8 @@ pub fn render_jinja_template(
         enum_names => model.enums.iter().map(|e| e.name.clone()).collect::<Vec<String>>(),
         title => model.name,
         prefixes => prefixes,
-        repo => model.config.as_ref().unwrap().repo.clone(),
-        prefix => model.config.as_ref().unwrap().prefix.clone(),
+        repo => model.config().repo.clone(),
+        prefix => model.config().prefix.clone(),
         config => config,
     });
 
@@ -223,6 +495,40 @@ pub fn render_jinja_template(
     }
 }
 
+/// Renders a Jinja template on a blocking thread for use in async contexts.
+///
+/// The rendering itself is CPU-bound, hence it is moved onto the blocking
+/// thread pool of the current Tokio runtime. It is not available on
+/// `wasm32`, where no threads can be spawned.
+///
+/// # Arguments
+///
+/// * `template` - The type of template to render.
+/// * `model` - The data model to use for rendering the template.
+/// * `config` - Optional configuration passed to the template.
+///
+/// # Returns
+///
+/// A Result containing the rendered template as a String or an error if rendering fails.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn render_jinja_template_async(
+    template: Templates,
+    mut model: DataModel,
+    config: Option<TemplateConfig>,
+) -> Result<String, minijinja::Error> {
+    tokio::task::spawn_blocking(move || {
+        render_jinja_template(&template, &mut model, config.as_ref())
+    })
+    .await
+    .map_err(|err| {
+        minijinja::Error::new(
+            minijinja::ErrorKind::InvalidOperation,
+            "rendering task failed",
+        )
+        .with_source(err)
+    })?
+}
+
 /// Template function that allows to wrap text at a certain length.
 ///
 /// # Arguments
@@ -245,6 +551,38 @@ fn wrap_text(text: &str, width: usize, initial_offset: &str, offset: &str) -> St
     wrap(remove_multiple_spaces(text).as_str(), options).join("\n")
 }
 
+/// Template filter that serializes a value to indented JSON.
+///
+/// # Arguments
+///
+/// * `value` - The value to serialize.
+///
+/// # Returns
+///
+/// A string with the pretty-printed JSON.
+fn pretty_json(value: minijinja::Value) -> Result<String, minijinja::Error> {
+    serde_json::to_string_pretty(&value).map_err(|err| {
+        minijinja::Error::new(
+            minijinja::ErrorKind::InvalidOperation,
+            "cannot serialize to JSON",
+        )
+        .with_source(err)
+    })
+}
+
+/// Template filter that converts a name to PascalCase.
+///
+/// # Arguments
+///
+/// * `name` - The name to convert, e.g. in snake_case.
+///
+/// # Returns
+///
+/// The name with the first letter of each word capitalized and separators removed.
+fn pascal_case(name: &str) -> String {
+    name.to_case(Case::Pascal)
+}
+
 /// Removes leading and trailing whitespace and multiple spaces from a string.
 fn remove_multiple_spaces(input: &str) -> String {
     input.split_whitespace().collect::<Vec<&str>>().join(" ")
@@ -260,15 +598,13 @@ fn convert_model_types(
     model: &mut DataModel,
     type_map: &std::collections::HashMap<String, String>,
 ) {
-    for object in &mut model.objects {
-        for attribute in &mut object.attributes {
-            attribute.dtypes = attribute
-                .dtypes
-                .iter()
-                .map(|t| type_map.get(t).unwrap_or(t))
-                .map(|t| t.to_string())
-                .collect();
-        }
+    for (_, attribute) in model.all_attributes_mut() {
+        attribute.dtypes = attribute
+            .dtypes
+            .iter()
+            .map(|t| type_map.get(t).unwrap_or(t))
+            .map(|t| t.to_string())
+            .collect();
     }
 }
 
@@ -282,10 +618,7 @@ fn convert_model_types(
 ///
 /// A vector of prefix tuples (prefix, URI).
 fn get_prefixes(model: &mut DataModel) -> Vec<(String, String)> {
-    match &model.config {
-        Some(config) => config.prefixes().unwrap_or(vec![]),
-        None => vec![],
-    }
+    model.config().prefixes().unwrap_or(vec![])
 }
 
 /// Filters out objects from the model that do not have any terms.
@@ -301,6 +634,26 @@ fn filter_objects_wo_terms(model: &mut DataModel) {
     }
 }
 
+/// Copies the model without the attributes that are excluded from the given template.
+///
+/// # Arguments
+///
+/// * `model` - The data model whose attributes are to be filtered.
+/// * `template` - The template that is rendered.
+///
+/// # Returns
+///
+/// A copy of the model without the excluded attributes.
+pub(crate) fn exclude_attributes(model: &DataModel, template: &Templates) -> DataModel {
+    let mut model = model.clone();
+    for object in model.objects.iter_mut() {
+        object
+            .attributes
+            .retain(|attr| !attr.is_excluded_from(template));
+    }
+    model
+}
+
 /// Sorts the attributes of each object in the model by their 'required' field.
 ///
 /// # Arguments
@@ -312,32 +665,58 @@ fn sort_attributes_by_required(model: &mut DataModel) {
     }
 }
 
-fn clean_and_trim(s: &str) -> String {
-    let splitted = s.split('\n').collect::<Vec<&str>>();
-    let mut cleaned = vec![];
-    let mut consec_empty = 0;
-
-    for line in splitted {
-        let trimmed = line.trim_end();
-        if !trimmed.is_empty() {
-            cleaned.push(trimmed);
-            consec_empty = 0;
-        } else {
-            consec_empty += 1;
-            if consec_empty < 3 {
-                cleaned.push(trimmed);
-            }
-        }
-    }
+/// Sorts the objects of the model such that each object follows the objects it references.
+///
+/// Avro requires named types to be defined before they are used. Recursive
+/// references are kept as they are, since a record may refer to itself.
+/// If the model has enumerations, these are ordered by their first use,
+/// while unused enumerations are moved to the end.
+///
+/// # Arguments
+///
+/// * `model` - The data model whose objects are to be sorted.
+fn sort_objects_by_dependencies(model: &mut DataModel) {
+    let order: Vec<String> = if model.enums.is_empty() {
+        model
+            .topological_objects()
+            .iter()
+            .map(|object| object.name.clone())
+            .collect()
+    } else {
+        let names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        topological_order(&full_type_graph(model), &names)
+    };
+
+    let position = |name: &str| order.iter().position(|n| n == name).unwrap_or(usize::MAX);
+
+    model.objects.sort_by_key(|object| position(&object.name));
+    model
+        .enums
+        .sort_by_key(|enumeration| position(&enumeration.name));
+}
 
-    cleaned.join("\n").trim().to_string()
-    // s.to_string()
+/// Removes trailing whitespace, collapses more than two consecutive empty
+/// lines and trims the rendered output.
+///
+/// # Arguments
+///
+/// * `s` - The rendered template.
+///
+/// # Returns
+///
+/// The cleaned string.
+fn clean_and_trim(s: &str) -> String {
+    let trimmed = TRAILING_WHITESPACE.replace_all(s, "");
+    EXCESS_EMPTY_LINES
+        .replace_all(&trimmed, "\n\n\n")
+        .trim()
+        .to_string()
 }
 
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
-    use std::{fs, path::Path};
+    use std::fs;
 
     use crate::markdown::parser::parse_markdown;
 
@@ -353,14 +732,35 @@ mod tests {
     ///
     /// A string containing the rendered template.
     fn build_and_convert(template: Templates) -> String {
-        let path = Path::new("tests/data/model.md");
-        let content = fs::read_to_string(path).expect("Could not read markdown file");
-        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
-        render_jinja_template(&template, &mut model, None)
+        build_and_convert_from(template, "tests/data/model.md", None)
+    }
+
+    /// Helper function to build and convert a template from a specific model file.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The template type to use for rendering.
+    /// * `path` - The path to the markdown model.
+    ///
+    /// # Returns
+    ///
+    /// A string containing the rendered template.
+    fn build_and_convert_from(
+        template: Templates,
+        path: &str,
+        config: Option<&TemplateConfig>,
+    ) -> String {
+        let mut model = read_model(path);
+        render_jinja_template(&template, &mut model, config)
             .expect("Could not render template")
             .to_string()
     }
 
+    fn read_model(path: &str) -> DataModel {
+        let content = fs::read_to_string(path).expect("Could not read markdown file");
+        parse_markdown(&content).expect("Failed to parse markdown file")
+    }
+
     #[test]
     fn test_convert_to_shex() {
         // Arrange
@@ -372,6 +772,248 @@ mod tests {
         assert_eq!(rendered, expected);
     }
 
+    #[test]
+    fn test_convert_to_shex_semantic_action() {
+        // Arrange
+        let rendered = build_and_convert_from(Templates::Shex, "tests/data/model_semact.md", None);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_shex_semact.shex")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+        assert_valid_semantic_actions(&rendered);
+    }
+
+    /// Checks that all semantic actions of a ShExC schema are named by a declared prefixed name or an IRI.
+    ///
+    /// # Arguments
+    ///
+    /// * `shex` - The rendered ShExC schema.
+    fn assert_valid_semantic_actions(shex: &str) {
+        let declared: Vec<&str> = shex
+            .lines()
+            .filter_map(|line| line.strip_prefix("PREFIX "))
+            .filter_map(|line| line.split(':').next())
+            .collect();
+        let actions = regex::Regex::new(r"%([^{\s]*)\{").unwrap();
+
+        let mut count = 0;
+        for action in actions.captures_iter(shex) {
+            count += 1;
+            let name = &action[1];
+            if name.starts_with('<') && name.ends_with('>') {
+                continue;
+            }
+
+            let prefix = name
+                .split_once(':')
+                .map(|(prefix, _)| prefix)
+                .unwrap_or_else(|| panic!("Semantic action '%{}{{' is not a prefixed name", name));
+            assert!(
+                declared.contains(&prefix),
+                "Prefix '{}' of semantic action '{}' is not declared",
+                prefix,
+                name
+            );
+        }
+
+        assert!(count > 0, "No semantic actions found");
+    }
+
+    #[test]
+    fn test_convert_numeric_types() {
+        // Arrange
+        let cases = [
+            (
+                Templates::PythonDataclass,
+                vec!["amount: Optional[Decimal]", "List[Decimal]"],
+            ),
+            (
+                Templates::PythonPydantic,
+                vec!["id: int", "amount: Optional[Decimal]"],
+            ),
+            (
+                Templates::PythonPydanticXML,
+                vec!["id: int", "fees: list[Decimal]"],
+            ),
+            (
+                Templates::Typescript,
+                vec!["id: bigint;", "id: BigIntCodec,", "amount?: string | null;"],
+            ),
+            (
+                Templates::XmlSchema,
+                vec![
+                    r#"name="id" type="xs:integer""#,
+                    r#"name="amount" type="xs:decimal""#,
+                ],
+            ),
+            (Templates::Shacl, vec!["xsd:integer", "xsd:decimal"]),
+            (
+                Templates::Shex,
+                vec!["schema:identifier xsd:integer", "schema:price xsd:decimal?"],
+            ),
+        ];
+
+        for (template, expected) in cases {
+            // Act
+            let rendered =
+                build_and_convert_from(template.clone(), "tests/data/model_numeric.md", None);
+
+            // Assert
+            for snippet in expected {
+                assert!(
+                    rendered.contains(snippet),
+                    "Expected '{}' in {} output",
+                    snippet,
+                    template
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_deprecated() {
+        // Arrange
+        let cases = [
+            (
+                Templates::PythonPydantic,
+                "label: Optional[str] = Field(default=None, deprecated=True)",
+            ),
+            (
+                Templates::Typescript,
+                "/** @deprecated */\n  label?: string | null;",
+            ),
+            (
+                Templates::Kotlin,
+                "@Deprecated(\"label is deprecated\")\n    val label: String? = null",
+            ),
+            (
+                Templates::CSharp,
+                "[Obsolete]\n    [JsonPropertyName(\"label\")]",
+            ),
+        ];
+
+        for (template, expected) in cases {
+            // Act
+            let rendered =
+                build_and_convert_from(template.clone(), "tests/data/model_deprecated.md", None);
+
+            // Assert
+            assert!(
+                rendered.contains(expected),
+                "Expected '{}' in {} output",
+                expected,
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_to_pydantic_alias() {
+        // Arrange
+        let rendered =
+            build_and_convert_from(Templates::PythonPydantic, "tests/data/model_alias.md", None);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_pydantic_alias.py")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_alias() {
+        // Arrange
+        let cases = [
+            (
+                Templates::PythonDataclass,
+                "# alias: geneName\n    gene_name: str",
+            ),
+            (
+                Templates::Typescript,
+                "/** @alias sequenceLength */\n  sequence_length?: number | null;",
+            ),
+        ];
+
+        for (template, expected) in cases {
+            // Act
+            let rendered =
+                build_and_convert_from(template.clone(), "tests/data/model_alias.md", None);
+
+            // Assert
+            assert!(
+                rendered.contains(expected),
+                "Expected '{}' in {} output",
+                expected,
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_readonly() {
+        // Arrange
+        let cases = [
+            (Templates::Typescript, "readonly id: string;"),
+            (Templates::Typescript, "readonly issued?: string | null;"),
+            (
+                Templates::TypescriptInterface,
+                "readonly id: string;\n  name?: string;",
+            ),
+            (Templates::PythonPydantic, "id: str = Field(frozen=True)"),
+            (
+                Templates::PythonPydantic,
+                "validate_assigment = True,\n        frozen = True,",
+            ),
+        ];
+
+        for (template, expected) in cases {
+            // Act
+            let rendered =
+                build_and_convert_from(template.clone(), "tests/data/model_readonly.md", None);
+
+            // Assert
+            assert!(
+                rendered.contains(expected),
+                "Expected '{}' in {} output",
+                expected,
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_const() {
+        // Arrange
+        let cases = [
+            (Templates::Typescript, "kind: \"circle\";"),
+            (Templates::Typescript, "version: D.literal(2),"),
+            (Templates::TypescriptInterface, "kind: \"circle\";"),
+            (
+                Templates::PythonPydantic,
+                "kind: Literal[\"circle\"] = \"circle\"",
+            ),
+            (Templates::PythonPydantic, "version: Literal[2] = 2"),
+            (
+                Templates::PythonPydantic,
+                "from typing import Literal, Optional",
+            ),
+        ];
+
+        for (template, expected) in cases {
+            // Act
+            let rendered =
+                build_and_convert_from(template.clone(), "tests/data/model_const.md", None);
+
+            // Assert
+            assert!(
+                rendered.contains(expected),
+                "Expected '{}' in {} output",
+                expected,
+                template
+            );
+        }
+    }
+
     #[test]
     fn test_convert_to_shacl() {
         // Arrange
@@ -383,6 +1025,20 @@ mod tests {
         assert_eq!(rendered, expected);
     }
 
+    #[test]
+    fn test_convert_to_shacl_enum() {
+        // Arrange
+        let rendered =
+            build_and_convert_from(Templates::Shacl, "tests/data/model_shacl_enum.md", None);
+
+        // Assert
+        assert!(rendered.contains(
+            "sh:nodeKind sh:IRI ;\n        sh:in ( go:GO:0008150 <http://semanticscience.org/resource/SIO_000015> ) ;"
+        ));
+        assert!(rendered
+            .contains("sh:datatype xsd:string ;\n        sh:in ( \"active\" \"inactive\" ) ;"));
+    }
+
     #[test]
     fn test_convert_to_python_dc() {
         // Arrange
@@ -394,6 +1050,38 @@ mod tests {
         assert_eq!(rendered, expected);
     }
 
+    #[test]
+    fn test_convert_to_python_attrs() {
+        // Arrange
+        let rendered = build_and_convert(Templates::PythonAttrs);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_python_attrs.py")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_python_attrs_validators() {
+        // Arrange
+        let rendered = build_and_convert_from(
+            Templates::PythonAttrs,
+            "tests/data/model_constraints.md",
+            None,
+        );
+
+        // Assert
+        for expected in [
+            r#"sample_id: str = field(validator=[validators.matches_re(r"^S\d+$"), validators.max_len(16)])"#,
+            "temperature: Optional[float] = field(default=None, validator=validators.optional([validators.ge(0), validators.lt(1000)]))",
+            "replicates: int = field(default=3, validator=[validators.ge(1)])",
+            "values: List[float] = field(default=Factory(list), validator=[validators.min_len(1)])",
+            "comment: Optional[str] = field(default=None)",
+        ] {
+            assert!(rendered.contains(expected), "Expected '{}' in output", expected);
+        }
+    }
+
     #[test]
     fn test_convert_to_python_pydantic_xml() {
         // Arrange
@@ -427,6 +1115,20 @@ mod tests {
         assert_eq!(rendered, expected);
     }
 
+    #[test]
+    fn test_convert_to_mkdocs_examples() {
+        // Arrange
+        let rendered = build_and_convert_from(
+            Templates::MkDocs,
+            "tests/data/model_attribute_examples.md",
+            None,
+        );
+
+        // Assert
+        assert!(rendered.contains("- `Examples`: `Sample A`, `Sample B, diluted`"));
+        assert!(rendered.contains("- `Examples`: `1`, `2`, `3`"));
+    }
+
     #[test]
     fn test_convert_to_typescript() {
         // Arrange
@@ -438,6 +1140,235 @@ mod tests {
         assert_eq!(rendered, expected);
     }
 
+    #[test]
+    fn test_convert_exclude() {
+        // Arrange
+        let content =
+            fs::read_to_string("tests/data/model_exclude.md").expect("Could not read model");
+        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
+
+        // Act
+        let typescript = render_jinja_template(&Templates::Typescript, &mut model, None)
+            .expect("Could not render template");
+        let kotlin = render_jinja_template(&Templates::Kotlin, &mut model, None)
+            .expect("Could not render template");
+        let python = render_jinja_template(&Templates::PythonDataclass, &mut model, None)
+            .expect("Could not render template");
+        let json_schema = model
+            .json_schema(Some("Record".to_string()), false)
+            .expect("Could not render JSON schema");
+
+        // Assert
+        assert!(typescript.contains("name"));
+        assert!(!typescript.contains("revision"));
+        assert!(kotlin.contains("name"));
+        assert!(!kotlin.contains("revision"));
+        assert!(python.contains("revision"));
+        assert!(python.contains("notes"));
+        assert!(json_schema.contains("revision"));
+        assert!(!json_schema.contains("notes"));
+        assert_eq!(model.objects[0].attributes.len(), 3);
+    }
+
+    #[test]
+    fn test_convert_to_typescript_interface() {
+        // Arrange
+        let rendered = build_and_convert(Templates::TypescriptInterface);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_typescript_interface.ts")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_typescript_interface_readonly() {
+        // Arrange
+        let config = TemplateConfig {
+            readonly: true,
+            ..Default::default()
+        };
+
+        // Act
+        let rendered = build_and_convert_from(
+            Templates::TypescriptInterface,
+            "tests/data/model.md",
+            Some(&config),
+        );
+
+        // Assert
+        assert!(rendered.contains("  readonly name: string;"));
+        assert!(rendered.contains("  readonly test2?: Test2[];"));
+        assert!(!rendered.contains("io-ts"));
+    }
+
+    #[test]
+    fn test_convert_to_plantuml() {
+        // Arrange
+        let rendered = build_and_convert(Templates::PlantUml);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_plantuml.puml")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_mermaid_erd() {
+        // Arrange
+        let rendered = build_and_convert(Templates::MermaidErd);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_mermaid_erd.mmd")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_mermaid_erd_relations() {
+        // Arrange
+        let rendered = build_and_convert_from(
+            Templates::MermaidErd,
+            "tests/data/model_mermaid_relations.md",
+            None,
+        );
+
+        // Assert
+        for expected in [
+            "Experiment ||--|| Sample : sample",
+            "Experiment ||--o| Protocol : protocol",
+            "Experiment ||--|{ Measurement : measurements",
+            "Experiment ||--o{ Note : notes",
+        ] {
+            assert!(
+                rendered.contains(expected),
+                "Expected '{}' in output",
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_to_kotlin() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Kotlin);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_kotlin.kt")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_scala() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Scala);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_scala.scala")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_scala_circe() {
+        // Arrange
+        let config = TemplateConfig {
+            circe: true,
+            ..Default::default()
+        };
+
+        // Act
+        let rendered =
+            build_and_convert_from(Templates::Scala, "tests/data/model.md", Some(&config));
+
+        // Assert
+        assert!(rendered.contains("import io.circe.{Decoder, Encoder}"));
+        assert!(rendered.contains("implicit val encoder: Encoder[Test] = deriveEncoder[Test]"));
+        assert!(rendered.contains("Encoder.encodeString.contramap(_.value)"));
+    }
+
+    #[test]
+    fn test_convert_to_kotlin_jackson() {
+        // Arrange
+        let config = TemplateConfig {
+            kotlin_serializer: Some("jackson".to_string()),
+            ..Default::default()
+        };
+
+        // Act
+        let rendered =
+            build_and_convert_from(Templates::Kotlin, "tests/data/model.md", Some(&config));
+
+        // Assert
+        assert!(rendered.contains("import com.fasterxml.jackson.annotation.JsonProperty"));
+        assert!(rendered.contains("@JsonProperty(\"name\") val name: String,"));
+        assert!(!rendered.contains("@Serializable"));
+    }
+
+    #[test]
+    fn test_convert_to_csharp() {
+        // Arrange
+        let rendered = build_and_convert(Templates::CSharp);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_csharp.cs")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_avro() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Avro);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_avro.avsc")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_avro_roundtrip() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Avro);
+
+        // Act
+        let model = DataModel::from_avro_str(&rendered).expect("Could not import Avro schema");
+
+        // Assert
+        let object_names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        let enum_names: Vec<&str> = model.enums.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(object_names, vec!["Test2", "Test"]);
+        assert_eq!(enum_names, vec!["Ontology"]);
+    }
+
+    #[test]
+    fn test_convert_to_csharp_newtonsoft() {
+        // Arrange
+        let config = TemplateConfig {
+            csharp_json: Some("newtonsoft".to_string()),
+            ..Default::default()
+        };
+
+        // Act
+        let rendered =
+            build_and_convert_from(Templates::CSharp, "tests/data/model.md", Some(&config));
+
+        // Assert
+        assert!(rendered.contains("using Newtonsoft.Json;"));
+        assert!(rendered.contains("[JsonProperty(\"name\")]"));
+        assert!(rendered.contains("[EnumMember(Value = \"http://semanticscience.org/resource/\")]"));
+        assert!(!rendered.contains("JsonPropertyName"));
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        assert_eq!(pascal_case("name"), "Name");
+        assert_eq!(pascal_case("created_at"), "CreatedAt");
+        assert_eq!(pascal_case("test2"), "Test2");
+    }
+
     #[test]
     fn test_convert_to_pydantic() {
         // Arrange
@@ -448,4 +1379,152 @@ mod tests {
             .expect("Could not read expected file");
         assert_eq!(rendered, expected);
     }
+
+    #[test]
+    fn test_convert_to_pydantic_base64() {
+        // Arrange
+        let rendered = build_and_convert_from(
+            Templates::PythonPydantic,
+            "tests/data/model_base64.md",
+            None,
+        );
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_pydantic_base64.py")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_pydantic_keys() {
+        // Arrange
+        let rendered =
+            build_and_convert_from(Templates::PythonPydantic, "tests/data/model_keys.md", None);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_pydantic_keys.py")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_pydantic_keys_frozen() {
+        // Arrange
+        let config = TemplateConfig {
+            python_frozen: true,
+            ..Default::default()
+        };
+
+        // Act
+        let rendered = build_and_convert_from(
+            Templates::PythonPydantic,
+            "tests/data/model_keys.md",
+            Some(&config),
+        );
+
+        // Assert
+        assert_eq!(rendered.matches("frozen = True,").count(), 2);
+        assert!(rendered.contains("return hash(self.id)"));
+        assert!(rendered.contains("return hash((self.sample, self.replicate))"));
+    }
+
+    #[test]
+    fn test_convert_to_pydantic_v2() {
+        // Arrange
+        let config = TemplateConfig {
+            pydantic_v2: true,
+            ..Default::default()
+        };
+
+        // Act
+        let rendered = build_and_convert_from(
+            Templates::PythonPydantic,
+            "tests/data/model_constraints.md",
+            Some(&config),
+        );
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_pydantic_v2.py")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_template_config_from_hashmap() {
+        // Arrange
+        let options = HashMap::from([
+            ("nav".to_string(), "false".to_string()),
+            ("gorm".to_string(), "true".to_string()),
+            ("serde_skip_none".to_string(), "TRUE".to_string()),
+            ("python_frozen".to_string(), "True".to_string()),
+            ("kotlin_serializer".to_string(), "jackson".to_string()),
+            ("draft07".to_string(), "true".to_string()),
+            ("json_schema_inherit".to_string(), "allOf".to_string()),
+            ("custom".to_string(), "value".to_string()),
+        ]);
+
+        // Act
+        let config = TemplateConfig::from_hashmap(&options);
+
+        // Assert
+        assert_eq!(
+            config,
+            TemplateConfig {
+                gorm: true,
+                astropy: false,
+                json_ld: false,
+                nav: false,
+                proto2: false,
+                display: false,
+                serde_skip_none: true,
+                python_frozen: true,
+                pydantic_v2: false,
+                kotlin_serializer: Some("jackson".to_string()),
+                csharp_json: None,
+                circe: false,
+                readonly: false,
+                json_schema_base_uri: None,
+                draft07: true,
+                json_schema_inherit: Some("allOf".to_string()),
+                extra: HashMap::from([("custom".to_string(), "value".to_string())]),
+            }
+        );
+        assert_eq!(config.inheritance(), JsonSchemaInheritance::AllOf);
+        assert!(TemplateConfig::from_hashmap(&HashMap::new()).nav);
+    }
+
+    #[test]
+    fn test_clean_and_trim() {
+        // Arrange
+        let input =
+            "\n\nclass A:  \n\tname: str\t\n\n\n\n\n\nclass B:\n \n  \n\n\n  value: int   \n\n";
+
+        // Act
+        let cleaned = clean_and_trim(input);
+
+        // Assert
+        assert_eq!(
+            cleaned,
+            "class A:\n\tname: str\n\n\nclass B:\n\n\n  value: int"
+        );
+    }
+
+    #[test]
+    fn test_render_jinja_template_async() {
+        // Arrange
+        let model = read_model("tests/data/model.md");
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Could not build runtime");
+
+        // Act
+        let rendered = runtime
+            .block_on(render_jinja_template_async(Templates::Shex, model, None))
+            .expect("Could not render template");
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_shex.shex")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
 }
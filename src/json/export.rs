@@ -22,6 +22,7 @@
  */
 
 use std::{
+    borrow::Cow,
     collections::{BTreeMap, HashMap, HashSet},
     str::FromStr,
 };
@@ -29,6 +30,7 @@ use std::{
 use crate::{
     attribute::Attribute,
     datamodel::DataModel,
+    exporters::{exclude_attributes, Templates},
     markdown::frontmatter::FrontMatter,
     object::{Enumeration, Object},
     validation::BASIC_TYPES,
@@ -37,6 +39,27 @@ use crate::{
 use super::schema::{self, PrimitiveType};
 
 const SCHEMA: &str = "https://json-schema.org/draft/2020-12/schema";
+const SCHEMA_DRAFT07: &str = "http://json-schema.org/draft-07/schema#";
+
+/// The JSON schema draft of the exported schemas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonSchemaDraft {
+    /// Draft 2020-12 using `$defs` for definitions.
+    #[default]
+    Draft202012,
+    /// Draft-07 using `definitions` for definitions.
+    Draft07,
+}
+
+/// The encoding of inherited attributes in the exported schemas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonSchemaInheritance {
+    /// Inherited attributes are part of the properties of the child object.
+    #[default]
+    Inline,
+    /// Child objects reference their parent within an `allOf`.
+    AllOf,
+}
 
 /// Converts a `DataModel` into a JSON schema representation.
 ///
@@ -45,6 +68,8 @@ const SCHEMA: &str = "https://json-schema.org/draft/2020-12/schema";
 /// * `model` - A reference to the `DataModel` to be converted.
 /// * `root` - The root object name in the model.
 /// * `openai` - A boolean flag indicating whether to use the OpenAI schema.
+/// * `draft` - The JSON schema draft of the schema.
+/// * `inheritance` - The encoding of inherited attributes.
 ///
 /// # Returns
 ///
@@ -53,7 +78,11 @@ pub fn to_json_schema(
     model: &DataModel,
     root: &str,
     openai: bool,
+    draft: JsonSchemaDraft,
+    inheritance: JsonSchemaInheritance,
 ) -> Result<schema::SchemaObject, String> {
+    let model = exclude_attributes(model, &Templates::JsonSchema);
+    let model = &*extra_basic_types_as_strings(&model);
     let root_object = retrieve_object(model, root)?;
 
     let mut schema_object = schema::SchemaObject::try_from(root_object)?;
@@ -62,18 +91,201 @@ pub fn to_json_schema(
 
     collect_definitions(root_object, model, &mut used_types, &mut used_enums)?;
 
+    if inheritance == JsonSchemaInheritance::AllOf {
+        collect_parents(root_object, model, &mut used_types);
+    }
+
     let definitions = collect_definitions_from_model(model, &used_types, &used_enums)?;
 
     schema_object.schema = Some(SCHEMA.to_string());
     schema_object.definitions = definitions;
 
+    if inheritance == JsonSchemaInheritance::AllOf {
+        apply_allof_inheritance(&mut schema_object, model);
+    }
+
     if let Some(config) = model.config.clone() {
         post_process_schema(&mut schema_object, &config, openai);
     }
 
+    if draft == JsonSchemaDraft::Draft07 {
+        convert_to_draft07(&mut schema_object);
+    }
+
     Ok(schema_object)
 }
 
+/// Replaces the `extra-basic-types` of the front matter by `string`.
+///
+/// JSON schema has no notion of custom primitive types, hence these are
+/// exported as plain strings.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel`.
+///
+/// # Returns
+///
+/// The model itself if it declares no extra basic types, otherwise a converted copy.
+fn extra_basic_types_as_strings(model: &DataModel) -> Cow<'_, DataModel> {
+    let extra_basic_types = &model.config().extra_basic_types;
+    if extra_basic_types.is_empty() {
+        return Cow::Borrowed(model);
+    }
+
+    let mut model = model.clone();
+    for object in model.objects.iter_mut() {
+        for attribute in object.attributes.iter_mut() {
+            for dtype in attribute.dtypes.iter_mut() {
+                if extra_basic_types.contains(dtype) {
+                    *dtype = "string".to_string();
+                }
+            }
+        }
+    }
+
+    Cow::Owned(model)
+}
+
+/// Adds the parents of the root object and of all used types to the used types.
+///
+/// Inherited attributes are also attributes of the child object, hence the types
+/// used by a parent have already been collected.
+///
+/// # Arguments
+///
+/// * `root` - A reference to the root `Object`.
+/// * `model` - A reference to the `DataModel`.
+/// * `used_types` - A mutable reference to a set of used type names.
+fn collect_parents(root: &Object, model: &DataModel, used_types: &mut HashSet<String>) {
+    let mut queue: Vec<&Object> = vec![root];
+    queue.extend(used_types.iter().filter_map(|name| model.get_object(name)));
+
+    while let Some(object) = queue.pop() {
+        let Some(parent) = object.parent.as_ref().and_then(|p| model.get_object(p)) else {
+            continue;
+        };
+
+        if parent.name != root.name && used_types.insert(parent.name.clone()) {
+            queue.push(parent);
+        }
+    }
+}
+
+/// Encodes the inheritance of the root schema and its definitions using `allOf`.
+///
+/// The properties of an object with a parent are reduced to the attributes that
+/// are not inherited and moved into an `allOf` next to a reference to the parent.
+/// Since `additionalProperties` does not look into `allOf`, children are closed by
+/// `unevaluatedProperties` and parents are left open.
+///
+/// # Arguments
+///
+/// * `schema_object` - A mutable reference to the root `SchemaObject`.
+/// * `model` - A reference to the `DataModel`.
+fn apply_allof_inheritance(schema_object: &mut schema::SchemaObject, model: &DataModel) {
+    let parents: HashSet<String> = model
+        .objects
+        .iter()
+        .filter_map(|o| o.parent.clone())
+        .filter(|p| model.get_object(p).is_some())
+        .collect();
+
+    let root = schema_object.title.clone();
+    inherit_from_parent(schema_object, model, &root, &parents);
+
+    for definition in schema_object.definitions.values_mut() {
+        if let schema::SchemaType::Object(definition) = definition {
+            inherit_from_parent(definition, model, &root, &parents);
+        }
+    }
+}
+
+/// Replaces the inherited properties of an object by an `allOf` referencing its parent.
+///
+/// The root schema is not part of the definitions, hence a parent that is the root
+/// is referenced by `#`.
+///
+/// # Arguments
+///
+/// * `object` - A mutable reference to the `SchemaObject`.
+/// * `model` - A reference to the `DataModel`.
+/// * `root` - The name of the root object.
+/// * `parents` - The names of all objects that are a parent of another object.
+fn inherit_from_parent(
+    object: &mut schema::SchemaObject,
+    model: &DataModel,
+    root: &str,
+    parents: &HashSet<String>,
+) {
+    if parents.contains(&object.title) {
+        object.additional_properties = true;
+    }
+
+    let Some(parent) = model
+        .get_object(&object.title)
+        .and_then(|o| o.parent.as_ref())
+        .and_then(|p| model.get_object(p))
+    else {
+        return;
+    };
+
+    let inherited: HashSet<&String> = parent.attributes.iter().map(|a| &a.name).collect();
+    let mut properties = object.properties.take().unwrap_or_default();
+    properties.retain(|name, _| !inherited.contains(name));
+
+    let mut required = object.required.take().unwrap_or_default();
+    required.retain(|name| !inherited.contains(name));
+
+    object.all_of = vec![
+        schema::AllOfItem::Reference(schema::ReferenceItemType {
+            reference: if parent.name == root {
+                "#".to_string()
+            } else {
+                format!("#/$defs/{}", parent.name)
+            },
+        }),
+        schema::AllOfItem::Object(schema::InlineObject {
+            dtype: schema::DataType::Object,
+            properties,
+            required,
+        }),
+    ];
+    object.additional_properties = true;
+    object.unevaluated_properties = Some(false);
+}
+
+/// Converts a draft 2020-12 schema object to draft-07.
+///
+/// The definitions are moved to `definitions` and all local `#/$defs/` references
+/// are rewritten to `#/definitions/`. `unevaluatedProperties` is removed, since it
+/// is not part of draft-07.
+///
+/// # Arguments
+///
+/// * `schema_object` - A mutable reference to the `SchemaObject`.
+fn convert_to_draft07(schema_object: &mut schema::SchemaObject) {
+    let to_draft07 = |reference: &mut String| {
+        if let Some(name) = reference.strip_prefix("#/$defs/") {
+            *reference = format!("#/definitions/{}", name);
+        }
+    };
+
+    schema_object.schema = Some(SCHEMA_DRAFT07.to_string());
+    // Draft-07 does not know `unevaluatedProperties`
+    schema_object.unevaluated_properties = None;
+    visit_references(schema_object, &to_draft07);
+
+    for definition in schema_object.definitions.values_mut() {
+        if let schema::SchemaType::Object(definition) = definition {
+            definition.unevaluated_properties = None;
+            visit_references(definition, &to_draft07);
+        }
+    }
+
+    schema_object.draft07_definitions = std::mem::take(&mut schema_object.definitions);
+}
+
 /// Retrieves an object from the `DataModel` by name.
 ///
 /// # Arguments
@@ -86,9 +298,7 @@ pub fn to_json_schema(
 /// A `Result` containing a reference to the `Object` or an error message.
 fn retrieve_object<'a>(model: &'a DataModel, name: &'a str) -> Result<&'a Object, String> {
     model
-        .objects
-        .iter()
-        .find(|obj| obj.name == name)
+        .get_object(name)
         .ok_or(format!("Object {} not found", name))
 }
 
@@ -104,9 +314,7 @@ fn retrieve_object<'a>(model: &'a DataModel, name: &'a str) -> Result<&'a Object
 /// A `Result` containing a reference to the `EnumObject` or an error message.
 fn retrieve_enum<'a>(model: &'a DataModel, name: &'a str) -> Result<&'a Enumeration, String> {
     model
-        .enums
-        .iter()
-        .find(|e| e.name == name)
+        .get_enum(name)
         .ok_or(format!("Enum {} not found", name))
 }
 
@@ -162,19 +370,20 @@ fn collect_definitions(
     used_types: &mut HashSet<String>,
     used_enums: &mut HashSet<String>,
 ) -> Result<(), String> {
-    for attr in object.attributes.iter() {
+    for attr in object
+        .attributes
+        .iter()
+        .filter(|attr| attr.has_complex_type())
+    {
         for dtype in attr.dtypes.iter() {
             if BASIC_TYPES.contains(&dtype.as_str()) || used_types.contains(dtype) {
                 continue;
             }
 
-            let object = model.objects.iter().find(|obj| obj.name == *dtype);
-            let enumeration = model.enums.iter().find(|e| e.name == *dtype);
-
-            if let Some(object) = object {
+            if let Some(object) = model.get_object(dtype) {
                 used_types.insert(dtype.clone());
                 collect_definitions(object, model, used_types, used_enums)?;
-            } else if let Some(enumeration) = enumeration {
+            } else if let Some(enumeration) = model.get_enum(dtype) {
                 used_enums.insert(enumeration.name.clone());
             } else {
                 return Err(format!("Object or enumeration {} not found", dtype));
@@ -192,7 +401,7 @@ fn collect_definitions(
 /// * `schema` - A mutable reference to the `SchemaObject`.
 /// * `prefixes` - A reference to a map containing prefix-to-URI mappings.
 fn resolve_prefixes(schema: &mut schema::SchemaObject, prefixes: &HashMap<String, String>) {
-    for (_, property) in schema.properties.iter_mut() {
+    for property in properties_mut(schema) {
         if let Some(reference) = property.term.clone() {
             let (prefix, term) = reference.split_once(":").unwrap_or(("", ""));
             if let Some(prefix) = prefixes.get(prefix) {
@@ -208,13 +417,38 @@ fn resolve_prefixes(schema: &mut schema::SchemaObject, prefixes: &HashMap<String
 ///
 /// * `schema` - A mutable reference to the `SchemaObject`.
 fn remove_options(schema: &mut schema::SchemaObject) {
-    for (_, property) in schema.properties.iter_mut() {
+    for property in properties_mut(schema) {
         property.options = HashMap::new();
     }
 }
 
+/// Returns the properties of an object, including those within an `allOf`.
+///
+/// # Arguments
+///
+/// * `schema` - A mutable reference to the `SchemaObject`.
+fn properties_mut(
+    schema: &mut schema::SchemaObject,
+) -> impl Iterator<Item = &mut schema::Property> {
+    let inline = schema.all_of.iter_mut().filter_map(|item| match item {
+        schema::AllOfItem::Object(object) => Some(object.properties.values_mut()),
+        schema::AllOfItem::Reference(_) => None,
+    });
+
+    schema
+        .properties
+        .iter_mut()
+        .flat_map(|properties| properties.values_mut())
+        .chain(inline.flatten())
+}
+
 /// Post-processes the schema object by setting its ID, resolving prefixes, and optionally removing options.
 ///
+/// The root schema and every definition receive an `$id` of the form `<repo>/<Name>`,
+/// which matches the files written by `DataModel::json_schema_all`. Since a definition
+/// with an `$id` forms its own resource, references within definitions are rewritten
+/// to the `$id` of the referenced definition. OpenAI schemas keep plain local references.
+///
 /// # Arguments
 ///
 /// * `schema_object` - A mutable reference to the `SchemaObject` to be post-processed.
@@ -225,16 +459,108 @@ fn post_process_schema(
     config: &FrontMatter,
     openai: bool,
 ) {
-    schema_object.id = Some(config.repo.clone());
+    schema_object.id = Some(schema_id(&config.repo, &schema_object.title));
     post_process_object(schema_object, config, openai);
 
-    for (_, definition) in schema_object.definitions.iter_mut() {
-        if let schema::SchemaType::Object(definition) = definition {
-            post_process_object(definition, config, openai);
+    for (name, definition) in schema_object.definitions.iter_mut() {
+        match definition {
+            schema::SchemaType::Object(definition) => {
+                post_process_object(definition, config, openai);
+
+                if !openai {
+                    definition.id = Some(schema_id(&config.repo, name));
+                    resolve_references(definition, &config.repo);
+                }
+            }
+            schema::SchemaType::Enum(definition) => {
+                if !openai {
+                    definition.id = Some(schema_id(&config.repo, name));
+                }
+            }
         }
     }
 }
 
+/// Rewrites local `#/$defs/<Name>` references of an object to the `$id` of the referenced schema.
+///
+/// # Arguments
+///
+/// * `object` - A mutable reference to the `SchemaObject`.
+/// * `base_uri` - The base URI used to build the `$id` values.
+fn resolve_references(object: &mut schema::SchemaObject, base_uri: &str) {
+    visit_references(object, &|reference| resolve_reference(reference, base_uri));
+}
+
+/// Applies a function to all references of an object's properties.
+///
+/// # Arguments
+///
+/// * `object` - A mutable reference to the `SchemaObject`.
+/// * `f` - The function applied to every reference.
+fn visit_references(object: &mut schema::SchemaObject, f: &impl Fn(&mut String)) {
+    for item in object.all_of.iter_mut() {
+        if let schema::AllOfItem::Reference(item) = item {
+            f(&mut item.reference);
+        }
+    }
+
+    for property in properties_mut(object) {
+        if let Some(reference) = property.reference.as_mut() {
+            f(reference);
+        }
+        if let Some(item) = property.items.as_mut() {
+            visit_item_references(item, f);
+        }
+        for item in property.one_of.iter_mut().flatten() {
+            visit_item_references(item, f);
+        }
+    }
+}
+
+/// Applies a function to the references of an `Item` and its nested items.
+///
+/// # Arguments
+///
+/// * `item` - A mutable reference to the `Item`.
+/// * `f` - The function applied to every reference.
+fn visit_item_references(item: &mut schema::Item, f: &impl Fn(&mut String)) {
+    match item {
+        schema::Item::ReferenceItem(item) => f(&mut item.reference),
+        schema::Item::OneOfItem(item) => {
+            for item in item.one_of.iter_mut() {
+                visit_item_references(item, f);
+            }
+        }
+        schema::Item::DataTypeItem(_) => {}
+    }
+}
+
+/// Rewrites a single local definition reference to the `$id` of the referenced schema.
+///
+/// # Arguments
+///
+/// * `reference` - A mutable reference to the reference string.
+/// * `base_uri` - The base URI used to build the `$id` values.
+fn resolve_reference(reference: &mut String, base_uri: &str) {
+    if let Some(name) = reference.strip_prefix("#/$defs/") {
+        *reference = schema_id(base_uri, name);
+    }
+}
+
+/// Builds the `$id` of a schema by appending its name to the base URI.
+///
+/// # Arguments
+///
+/// * `base_uri` - The base URI, usually the `repo` of the front matter.
+/// * `name` - The name of the object or enumeration.
+///
+/// # Returns
+///
+/// The `$id` for the given schema.
+fn schema_id(base_uri: &str, name: &str) -> String {
+    format!("{}/{}", base_uri.trim_end_matches('/'), name)
+}
+
 fn post_process_object(object: &mut schema::SchemaObject, config: &FrontMatter, openai: bool) {
     if let Some(prefixes) = &config.prefixes {
         resolve_prefixes(object, prefixes);
@@ -314,12 +640,16 @@ impl TryFrom<&Object> for schema::SchemaObject {
             title: obj.name.clone(),
             dtype: schema::DataType::Object,
             description: Some(obj.docstring.clone()),
-            properties: properties?,
+            all_of: Vec::new(),
+            properties: Some(properties?),
             definitions: BTreeMap::new(),
-            required,
+            draft07_definitions: BTreeMap::new(),
+            required: Some(required),
             schema: None,
             id: None,
             additional_properties: false,
+            unevaluated_properties: None,
+            examples: obj.examples.clone(),
         })
     }
 }
@@ -348,6 +678,7 @@ impl TryFrom<&Enumeration> for schema::EnumObject {
             dtype: schema::DataType::String,
             description: Some(enumeration.docstring.clone()),
             enum_values: values,
+            id: None,
         })
     }
 }
@@ -372,11 +703,23 @@ impl TryFrom<&Attribute> for schema::Property {
         let options: HashMap<String, PrimitiveType> = attr
             .options
             .iter()
+            .filter(|o| !["base64", "minitems", "maxitems", "exclude"].contains(&o.key.as_str()))
             .map(|o| (o.key.clone(), PrimitiveType::from(&o.value)))
+            .chain(
+                attr.custom_options
+                    .iter()
+                    .map(|o| (format!("x-{}", o.key), PrimitiveType::from(&o.value))),
+            )
             .collect();
 
+        let content_encoding = attr.is_base64().then(|| "base64".to_string());
+        let format = match attr.dtypes.as_slice() {
+            [dtype] if !attr.is_array => schema::format_of(dtype),
+            _ => None,
+        };
+
         let reference: Option<String> =
-            if attr.is_enum || matches!(dtype, Some(schema::DataType::Object { .. })) {
+            if attr.is_enum || matches!(dtype, Some(schema::DataType::Object)) {
                 Some(format!("#/$defs/{}", attr.dtypes[0]))
             } else {
                 None
@@ -386,26 +729,68 @@ impl TryFrom<&Attribute> for schema::Property {
         let one_of = (!attr.is_array).then(|| attr.into());
         let description = (!attr.docstring.is_empty()).then(|| attr.docstring.clone());
         let enum_values = if attr.is_enum { Some(Vec::new()) } else { None };
+        let examples = (!attr.examples.is_empty()).then(|| {
+            attr.examples
+                .iter()
+                .map(|example| example_value(&attr.dtypes, example))
+                .collect()
+        });
 
         if attr.dtypes.len() > 1 && !attr.is_array {
             // If there are multiple types, we need to use the AnyOf case
             dtype = None;
         }
 
+        let is_array = matches!(dtype, Some(schema::DataType::Array));
+        let min_items = attr.get_min_items().filter(|_| is_array);
+        let max_items = attr.get_max_items().filter(|_| is_array);
+
         Ok(schema::Property {
-            title: attr.name.clone(),
+            title: attr.get_alias().unwrap_or(&attr.name).to_string(),
             dtype,
             description,
             term: attr.term.clone(),
             reference,
+            format,
+            content_encoding,
             options,
+            min_items,
+            max_items,
             one_of,
             items,
             enum_values,
+            deprecated: attr.deprecated,
+            read_only: attr.is_readonly(),
+            examples,
+            const_value: attr.get_const().map(PrimitiveType::from),
         })
     }
 }
 
+/// Converts an example of an attribute into a value matching its data type.
+///
+/// Examples of string attributes are kept as they are, all others are
+/// parsed as numbers or booleans, if possible.
+///
+/// # Arguments
+///
+/// * `dtypes` - The data types of the attribute.
+/// * `example` - The example value as given in the markdown file.
+///
+/// # Returns
+///
+/// The example as a `PrimitiveType`.
+fn example_value(dtypes: &[String], example: &String) -> PrimitiveType {
+    match dtypes {
+        [dtype] if dtype == "string" => PrimitiveType::String(example.clone()),
+        [dtype] if dtype == "integer" => example
+            .parse::<i64>()
+            .map(PrimitiveType::Integer)
+            .unwrap_or_else(|_| PrimitiveType::from(example)),
+        _ => PrimitiveType::from(example),
+    }
+}
+
 impl TryFrom<&Attribute> for schema::DataType {
     type Error = String;
 
@@ -500,9 +885,10 @@ impl From<&Attribute> for Vec<schema::Item> {
 /// An `Item` representing the data type.
 fn process_dtype(dtype: &str) -> schema::Item {
     match schema::DataType::from_str(dtype) {
-        Ok(basic_type) => {
-            schema::Item::DataTypeItem(schema::DataTypeItemType { dtype: basic_type })
-        }
+        Ok(basic_type) => schema::Item::DataTypeItem(schema::DataTypeItemType {
+            dtype: basic_type,
+            format: schema::format_of(dtype),
+        }),
         Err(_) => schema::Item::ReferenceItem(schema::ReferenceItemType {
             reference: format!("#/$defs/{}", dtype),
         }),
@@ -514,7 +900,7 @@ mod tests {
     use serde_json::{json, Value};
 
     use super::*;
-    use crate::attribute::Attribute;
+    use crate::attribute::{AttrOption, Attribute};
 
     #[test]
     fn test_attribute_with_multiple_types() {
@@ -525,9 +911,15 @@ mod tests {
             dtypes: vec!["string".to_string(), "RefType".to_string()],
             docstring: "".to_string(),
             options: vec![],
+            custom_options: vec![],
             term: None,
             required: false,
+            deprecated: false,
+            readonly: false,
+            alias: None,
+            examples: Vec::new(),
             default: None,
+            const_value: None,
             xml: None,
             is_enum: false,
             position: None,
@@ -559,9 +951,15 @@ mod tests {
             dtypes: vec!["string".to_string(), "RefType".to_string()],
             docstring: "".to_string(),
             options: vec![],
+            custom_options: vec![],
             term: None,
             required: false,
+            deprecated: false,
+            readonly: false,
+            alias: None,
+            examples: Vec::new(),
             default: None,
+            const_value: None,
             xml: None,
             is_enum: false,
             position: None,
@@ -585,4 +983,121 @@ mod tests {
 
         assert_eq!(serialized_property, expected_json);
     }
+
+    #[test]
+    fn test_base64_attribute() {
+        let attr = Attribute {
+            name: "content".to_string(),
+            is_array: false,
+            is_id: false,
+            dtypes: vec!["bytes".to_string()],
+            docstring: "".to_string(),
+            options: vec![AttrOption::new("Base64".to_string(), "true".to_string())],
+            custom_options: vec![],
+            term: None,
+            required: false,
+            deprecated: false,
+            readonly: false,
+            alias: None,
+            examples: Vec::new(),
+            default: None,
+            const_value: None,
+            xml: None,
+            is_enum: false,
+            position: None,
+        };
+
+        let property: schema::Property =
+            schema::Property::try_from(&attr).expect("Failed to convert Attribute to Property");
+        let serialized_property: Value =
+            serde_json::to_value(&property).expect("Failed to serialize Property to JSON");
+
+        let expected_json = json!({
+            "title": "content",
+            "type": "string",
+            "contentEncoding": "base64"
+        });
+
+        assert_eq!(serialized_property, expected_json);
+    }
+
+    #[test]
+    fn test_deprecated_attribute_roundtrip() {
+        let attr = Attribute {
+            name: "label".to_string(),
+            is_array: false,
+            is_id: false,
+            dtypes: vec!["string".to_string()],
+            docstring: "".to_string(),
+            options: vec![],
+            custom_options: vec![],
+            term: None,
+            required: false,
+            deprecated: true,
+            readonly: false,
+            alias: None,
+            examples: Vec::new(),
+            default: None,
+            const_value: None,
+            xml: None,
+            is_enum: false,
+            position: None,
+        };
+
+        let property: schema::Property =
+            schema::Property::try_from(&attr).expect("Failed to convert Attribute to Property");
+        let serialized_property: Value =
+            serde_json::to_value(&property).expect("Failed to serialize Property to JSON");
+
+        let expected_json = json!({
+            "title": "label",
+            "type": "string",
+            "deprecated": true
+        });
+
+        assert_eq!(serialized_property, expected_json);
+
+        let parsed: schema::Property = serde_json::from_value(serialized_property)
+            .expect("Failed to deserialize Property from JSON");
+        assert!(parsed.deprecated);
+
+        let parsed: schema::Property = serde_json::from_value(json!({
+            "title": "label",
+            "type": "string"
+        }))
+        .expect("Failed to deserialize Property from JSON");
+        assert!(!parsed.deprecated);
+    }
+
+    #[test]
+    fn test_property_examples() {
+        let mut attr = Attribute::new("replicates".to_string(), false);
+        attr.add_option(AttrOption::new("Type".to_string(), "integer".to_string()))
+            .expect("Failed to add option");
+        attr.add_option(AttrOption::new("Examples".to_string(), "1, 2".to_string()))
+            .expect("Failed to add option");
+        let mut label = Attribute::new("label".to_string(), false);
+        label
+            .add_option(AttrOption::new("Type".to_string(), "string".to_string()))
+            .expect("Failed to add option");
+        label
+            .add_option(AttrOption::new(
+                "Examples".to_string(),
+                r#""1", "a, b""#.to_string(),
+            ))
+            .expect("Failed to add option");
+
+        let property =
+            schema::Property::try_from(&attr).expect("Failed to convert Attribute to Property");
+        let serialized: Value =
+            serde_json::to_value(&property).expect("Failed to serialize Property to JSON");
+        assert_eq!(serialized["examples"], json!([1, 2]));
+
+        let property =
+            schema::Property::try_from(&label).expect("Failed to convert Attribute to Property");
+        let serialized: Value =
+            serde_json::to_value(&property).expect("Failed to serialize Property to JSON");
+        assert_eq!(serialized["examples"], json!(["1", "a, b"]));
+        assert!(!serialized.as_object().unwrap().contains_key("options"));
+    }
 }
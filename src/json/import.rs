@@ -0,0 +1,498 @@
+/*
+ * Copyright (c) 2024 Jan Range
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ *
+ */
+
+use std::error::Error;
+
+use serde_json::{Map, Value};
+
+use crate::attribute::{AttrOption, Attribute, DataType};
+use crate::datamodel::DataModel;
+use crate::object::{Enumeration, Object};
+
+/// Keywords of a property that are kept as attribute options.
+const CONSTRAINT_KEYWORDS: [&str; 10] = [
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "multipleOf",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "minItems",
+    "maxItems",
+];
+
+/// Creates a data model from a JSON schema string.
+///
+/// The root schema becomes the first object, followed by all definitions
+/// found in either `$defs` (draft 2020-12) or `definitions` (draft-07).
+/// Definitions with an `enum` keyword are mapped to enumerations, all others
+/// to objects. References may point to either of both locations.
+///
+/// # Arguments
+///
+/// * `content` - The JSON schema as JSON string.
+///
+/// # Returns
+///
+/// A data model or an error if the schema is not valid or uses unsupported types.
+pub fn from_json_schema_str(content: &str) -> Result<DataModel, Box<dyn Error>> {
+    let schema: Value = serde_json::from_str(content)?;
+    let mut model = DataModel::new(None, None);
+
+    let root = schema.as_object().ok_or("JSON schema must be an object")?;
+    let name = root
+        .get("title")
+        .and_then(Value::as_str)
+        .ok_or("Root schema has no title")?;
+    model.objects.push(schema_to_object(name, root)?);
+
+    let definitions = ["$defs", "definitions"]
+        .iter()
+        .filter_map(|key| root.get(*key).and_then(Value::as_object))
+        .flatten();
+
+    for (name, definition) in definitions {
+        if model.get_object(name).is_some() || model.get_enum(name).is_some() {
+            return Err(format!("Definition '{}' is defined more than once", name).into());
+        }
+
+        let definition = definition
+            .as_object()
+            .ok_or_else(|| format!("Definition '{}' is not an object", name))?;
+
+        match definition.get("enum").and_then(Value::as_array) {
+            Some(values) => model.enums.push(schema_to_enum(name, definition, values)),
+            None => model.objects.push(schema_to_object(name, definition)?),
+        }
+    }
+
+    let enum_names: Vec<String> = model.enums.iter().map(|e| e.name.clone()).collect();
+    for (_, attr) in model.all_attributes_mut() {
+        attr.is_enum = attr.dtypes.iter().any(|dtype| enum_names.contains(dtype));
+    }
+
+    Ok(model)
+}
+
+/// Represents the md-models view of a JSON schema property type.
+#[derive(Debug, Default)]
+struct PropertyType {
+    dtypes: Vec<String>,
+    is_array: bool,
+}
+
+/// Converts an object schema to an object.
+///
+/// # Arguments
+///
+/// * `name` - The name of the object.
+/// * `schema` - The object schema.
+///
+/// # Returns
+///
+/// The converted object or an error if a property type is not supported.
+fn schema_to_object(name: &str, schema: &Map<String, Value>) -> Result<Object, Box<dyn Error>> {
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let mut object = Object::new(name.to_string(), None);
+    object.docstring = description(schema);
+
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        for (attr_name, property) in properties {
+            let attribute =
+                property_to_attribute(attr_name, property, required.contains(&attr_name.as_str()))
+                    .map_err(|e| format!("Property '{}.{}': {}", name, attr_name, e))?;
+            object.attributes.push(attribute);
+        }
+    }
+
+    Ok(object)
+}
+
+/// Converts an enum schema to an enumeration.
+///
+/// JSON schema enums carry no keys, hence keys are derived from the values.
+///
+/// # Arguments
+///
+/// * `name` - The name of the enumeration.
+/// * `schema` - The enum schema.
+/// * `values` - The values of the `enum` keyword.
+///
+/// # Returns
+///
+/// The converted enumeration.
+fn schema_to_enum(name: &str, schema: &Map<String, Value>, values: &[Value]) -> Enumeration {
+    let mappings = values
+        .iter()
+        .map(|value| match value {
+            Value::String(s) => s.clone(),
+            _ => value.to_string(),
+        })
+        .enumerate()
+        .map(|(index, value)| (enum_key(&value, index), value))
+        .collect();
+
+    Enumeration {
+        name: name.to_string(),
+        mappings,
+        docstring: description(schema),
+        position: None,
+    }
+}
+
+/// Converts a property schema to an attribute.
+///
+/// # Arguments
+///
+/// * `name` - The name of the property.
+/// * `property` - The property schema.
+/// * `required` - Whether the property is listed in `required`.
+///
+/// # Returns
+///
+/// The converted attribute or an error if the property type is not supported.
+fn property_to_attribute(
+    name: &str,
+    property: &Value,
+    required: bool,
+) -> Result<Attribute, Box<dyn Error>> {
+    let schema = property.as_object().ok_or("Property is not an object")?;
+    let property_type = resolve_type(schema)?;
+
+    let mut attribute = Attribute::new(name.to_string(), required);
+    attribute.dtypes = property_type.dtypes;
+    attribute.is_array = property_type.is_array;
+    attribute.docstring = description(schema);
+    attribute.term = schema
+        .get("$term")
+        .and_then(Value::as_str)
+        .map(String::from);
+    attribute.default = schema.get("default").and_then(to_default);
+    attribute.const_value = schema.get("const").and_then(to_default);
+    attribute.deprecated = schema.get("deprecated").and_then(Value::as_bool) == Some(true);
+    attribute.readonly = schema.get("readOnly").and_then(Value::as_bool) == Some(true);
+    attribute.examples = schema
+        .get("examples")
+        .and_then(Value::as_array)
+        .map(|examples| examples.iter().map(option_value).collect())
+        .unwrap_or_default();
+
+    for keyword in CONSTRAINT_KEYWORDS {
+        if let Some(value) = schema.get(keyword) {
+            attribute.add_option(AttrOption::new(keyword.to_string(), option_value(value)))?;
+        }
+    }
+
+    Ok(attribute)
+}
+
+/// Resolves the type of a property schema to md-models data types.
+///
+/// # Arguments
+///
+/// * `schema` - The property schema, which may be a reference, an array or a union.
+///
+/// # Returns
+///
+/// The resolved property type or an error if the type is not supported.
+fn resolve_type(schema: &Map<String, Value>) -> Result<PropertyType, Box<dyn Error>> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return Ok(PropertyType {
+            dtypes: vec![extract_reference(reference)?],
+            ..Default::default()
+        });
+    }
+
+    if let Some(members) = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(Value::as_array)
+    {
+        let mut dtypes = Vec::new();
+        for member in members {
+            let member = member.as_object().ok_or("Union member is not an object")?;
+            let member = resolve_type(member)?;
+
+            if member.is_array {
+                return Err("Unions with array members are not supported".into());
+            }
+
+            dtypes.extend(member.dtypes);
+        }
+
+        return Ok(PropertyType {
+            dtypes,
+            ..Default::default()
+        });
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => {
+            let items = schema
+                .get("items")
+                .and_then(Value::as_object)
+                .ok_or("Array has no 'items'")?;
+            let items = resolve_type(items)?;
+
+            if items.is_array {
+                return Err("Nested arrays are not supported".into());
+            }
+
+            Ok(PropertyType {
+                is_array: true,
+                ..items
+            })
+        }
+        Some("object") => Err("Inline object schemas are not supported".into()),
+        Some(primitive) => Ok(PropertyType {
+            dtypes: vec![map_primitive(primitive, schema.get("format"))?],
+            ..Default::default()
+        }),
+        None => Err("Property has no type".into()),
+    }
+}
+
+/// Extracts the name of the referenced definition.
+///
+/// # Arguments
+///
+/// * `reference` - The reference, e.g. `#/$defs/Foo` or `#/definitions/Foo`.
+///
+/// # Returns
+///
+/// The name of the definition or an error if the reference is not local.
+fn extract_reference(reference: &str) -> Result<String, Box<dyn Error>> {
+    reference
+        .strip_prefix("#/$defs/")
+        .or_else(|| reference.strip_prefix("#/definitions/"))
+        .map(String::from)
+        .ok_or_else(|| format!("Unsupported reference '{}'", reference).into())
+}
+
+/// Maps a JSON schema primitive type to the corresponding md-models type.
+fn map_primitive(name: &str, format: Option<&Value>) -> Result<String, Box<dyn Error>> {
+    let dtype = match (name, format.and_then(Value::as_str)) {
+        ("string", Some("decimal")) => "decimal",
+        ("string", _) => "string",
+        ("integer", Some("int64")) => "bigint",
+        ("integer", _) => "integer",
+        ("number", _) => "float",
+        ("boolean", _) => "boolean",
+        _ => return Err(format!("Unsupported type '{}'", name).into()),
+    };
+
+    Ok(dtype.to_string())
+}
+
+/// Derives an enumeration key from its value.
+///
+/// Characters other than ASCII letters and digits are replaced by underscores.
+/// If no valid identifier remains, the key falls back to `VALUE_<index>`.
+fn enum_key(value: &str, index: usize) -> String {
+    let key: String = value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let key = key.trim_matches('_');
+
+    match key.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => key.to_string(),
+        _ => format!("VALUE_{}", index),
+    }
+}
+
+/// Returns the description of a schema.
+fn description(schema: &Map<String, Value>) -> String {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Converts a keyword value to the string representation of an option.
+fn option_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
+
+/// Converts a property default or constant to an attribute value.
+fn to_default(value: &Value) -> Option<DataType> {
+    match value {
+        Value::Bool(b) => Some(DataType::Boolean(*b)),
+        Value::Number(n) if n.is_i64() => n.as_i64().map(DataType::Integer),
+        Value::Number(n) => n.as_f64().map(DataType::Float),
+        Value::String(s) => Some(DataType::String(format!("\"{}\"", s))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_json_schema_str_draft07() {
+        // Arrange
+        let content =
+            std::fs::read_to_string("tests/data/expected_json_schema_draft07.json").unwrap();
+
+        // Act
+        let model = from_json_schema_str(&content).expect("Failed to import JSON schema");
+
+        // Assert
+        let names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, vec!["Test", "Test2"]);
+        assert_eq!(model.enums.len(), 1);
+        assert_eq!(model.enums[0].name, "Ontology");
+        assert_eq!(
+            model.enums[0].docstring,
+            "Ontology endpoints for different types of sequences."
+        );
+        assert_eq!(
+            model.enums[0]
+                .mappings
+                .get("HTTPS___AMIGO_GENEONTOLOGY_ORG_AMIGO_TERM"),
+            Some(&"https://amigo.geneontology.org/amigo/term/".to_string())
+        );
+
+        let (_, name) = model.find_attribute("Test", "name").unwrap();
+        assert_eq!(name.dtypes, vec!["string"]);
+        assert!(name.required);
+        assert_eq!(name.docstring, "A test description");
+        assert_eq!(name.term.as_deref(), Some("http://schema.org/hello"));
+
+        let (_, array_valued) = model.find_attribute("Test", "array_valued").unwrap();
+        assert_eq!(array_valued.dtypes, vec!["Test2"]);
+        assert!(array_valued.is_array);
+        assert!(!array_valued.required);
+
+        let (_, multiple_types) = model
+            .find_attribute("Test", "multiple_types_array")
+            .unwrap();
+        assert_eq!(multiple_types.dtypes, vec!["float", "Test2"]);
+        assert!(multiple_types.is_array);
+
+        let (_, ontology) = model.find_attribute("Test", "ontology").unwrap();
+        assert_eq!(ontology.dtypes, vec!["Ontology"]);
+        assert!(ontology.is_enum);
+
+        let (_, number) = model.find_attribute("Test2", "number").unwrap();
+        assert_eq!(number.options[0].key, "minimum");
+        assert_eq!(number.options[0].value, "0.0");
+    }
+
+    #[test]
+    fn test_from_json_schema_str_drafts_match() {
+        // Arrange
+        let draft07 =
+            std::fs::read_to_string("tests/data/expected_json_schema_draft07.json").unwrap();
+        let draft2020 = std::fs::read_to_string("tests/data/expected_json_schema.json").unwrap();
+
+        // Act
+        let draft07 = from_json_schema_str(&draft07).expect("Failed to import JSON schema");
+        let draft2020 = from_json_schema_str(&draft2020).expect("Failed to import JSON schema");
+
+        // Assert
+        assert_eq!(draft07, draft2020);
+    }
+
+    #[test]
+    fn test_from_json_schema_str_mixed_references() {
+        // Arrange
+        let content = r##"{
+            "title": "Order",
+            "type": "object",
+            "properties": {
+                "items": {"type": "array", "items": {"$ref": "#/definitions/Item"}},
+                "status": {"$ref": "#/$defs/Status", "default": "open"}
+            },
+            "required": ["items"],
+            "definitions": {
+                "Item": {
+                    "type": "object",
+                    "properties": {"count": {"type": "integer", "format": "int64"}}
+                }
+            },
+            "$defs": {
+                "Status": {"type": "string", "enum": ["open", "closed", "1"]}
+            }
+        }"##;
+
+        // Act
+        let model = from_json_schema_str(content).expect("Failed to import JSON schema");
+
+        // Assert
+        let (_, items) = model.find_attribute("Order", "items").unwrap();
+        assert_eq!(items.dtypes, vec!["Item"]);
+        assert!(items.is_array && items.required);
+
+        let (_, status) = model.find_attribute("Order", "status").unwrap();
+        assert!(status.is_enum);
+        assert!(status.default.is_some());
+
+        let (_, count) = model.find_attribute("Item", "count").unwrap();
+        assert_eq!(count.dtypes, vec!["bigint"]);
+
+        let keys: Vec<&String> = model.enums[0].mappings.keys().collect();
+        assert_eq!(keys, vec!["CLOSED", "OPEN", "VALUE_2"]);
+    }
+
+    #[test]
+    fn test_from_json_schema_str_unsupported() {
+        // Arrange
+        let cases = [
+            r#"{"type": "object", "properties": {}}"#,
+            r#"{"title": "A", "properties": {"b": {"$ref": "other.json#/B"}}}"#,
+            r#"{"title": "A", "properties": {"b": {"type": "object", "properties": {}}}}"#,
+            r#"{"title": "A", "properties": {"b": {"type": "array", "items": {"type": "array", "items": {"type": "string"}}}}}"#,
+            r#"{"title": "A", "properties": {"b": {"type": "null"}}}"#,
+            r#""string""#,
+        ];
+
+        // Act & Assert
+        for case in cases {
+            assert!(
+                from_json_schema_str(case).is_err(),
+                "Expected '{}' to fail",
+                case
+            );
+        }
+    }
+}
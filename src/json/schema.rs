@@ -23,6 +23,8 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+
+use crate::attribute;
 use std::{
     collections::{BTreeMap, HashMap},
     str::FromStr,
@@ -46,16 +48,32 @@ pub struct SchemaObject {
     pub dtype: DataType,
     #[serde(skip_serializing_if = "skip_empty_string")]
     pub description: Option<String>,
-    pub properties: BTreeMap<String, Property>,
+    #[serde(rename = "allOf", default, skip_serializing_if = "Vec::is_empty")]
+    pub all_of: Vec<AllOfItem>,
+    /// The properties of the object, `None` if they are declared within `allOf`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<BTreeMap<String, Property>>,
+    #[serde(rename = "$defs", default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub definitions: BTreeMap<String, SchemaType>,
+    /// Definitions of draft-07 schemas, which use `definitions` instead of `$defs`.
     #[serde(
-        rename = "$defs",
-        skip_serializing_if = "BTreeMap::is_empty",
-        alias = "definitions"
+        rename = "definitions",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
     )]
-    pub definitions: BTreeMap<String, SchemaType>,
-    pub required: Vec<String>,
+    pub draft07_definitions: BTreeMap<String, SchemaType>,
+    /// The required properties of the object, `None` if they are declared within `allOf`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<Vec<String>>,
     #[serde(rename = "additionalProperties", default = "default_false")]
     pub additional_properties: bool,
+    #[serde(
+        rename = "unevaluatedProperties",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unevaluated_properties: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<Value>,
 }
 
 impl SchemaObject {
@@ -64,8 +82,29 @@ impl SchemaObject {
     }
 }
 
+/// A subschema of an `allOf`, used to encode the inheritance of an object.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum AllOfItem {
+    /// The reference to the parent object.
+    Reference(ReferenceItemType),
+    /// The properties that are defined by the object itself.
+    Object(InlineObject),
+}
+
+/// An object schema without a title, which is embedded in another schema.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InlineObject {
+    #[serde(rename = "type")]
+    pub dtype: DataType,
+    pub properties: BTreeMap<String, Property>,
+    pub required: Vec<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct EnumObject {
+    #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     pub title: String,
     #[serde(rename = "type")]
     pub dtype: DataType,
@@ -86,14 +125,34 @@ pub struct Property {
     pub term: Option<String>,
     #[serde(rename = "$ref", skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(rename = "contentEncoding", skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<String>,
     #[serde(flatten)]
     pub options: HashMap<String, PrimitiveType>,
+    #[serde(rename = "minItems", skip_serializing_if = "Option::is_none")]
+    pub min_items: Option<usize>,
+    #[serde(rename = "maxItems", skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Item>,
     #[serde(rename = "oneOf", skip_serializing_if = "skip_empty")]
     pub one_of: Option<Vec<Item>>,
     #[serde(skip_serializing_if = "skip_empty", rename = "enum")]
     pub enum_values: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    #[serde(
+        rename = "readOnly",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub read_only: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<PrimitiveType>>,
+    #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+    pub const_value: Option<PrimitiveType>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -133,6 +192,8 @@ pub struct OneOfItemType {
 pub struct DataTypeItemType {
     #[serde(rename = "type")]
     pub dtype: DataType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
 }
 
 /// Represents various data types that can be used in a JSON schema.
@@ -170,9 +231,12 @@ impl FromStr for DataType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "string" => Ok(DataType::String),
+            "bytes" => Ok(DataType::String),
+            "decimal" => Ok(DataType::String),
             "number" => Ok(DataType::Number),
             "float" => Ok(DataType::Number),
             "integer" => Ok(DataType::Integer),
+            "bigint" => Ok(DataType::Integer),
             "boolean" => Ok(DataType::Boolean),
             "object" => Ok(DataType::Object),
             "array" => Ok(DataType::Array),
@@ -181,14 +245,29 @@ impl FromStr for DataType {
     }
 }
 
+/// Returns the JSON schema `format` of md-models types that are represented by a more generic type.
+///
+/// `decimal` values are encoded as strings to preserve precision and `bigint`
+/// values are 64-bit integers.
+pub fn format_of(dtype: &str) -> Option<String> {
+    match dtype {
+        "decimal" => Some("decimal".to_string()),
+        "bigint" => Some("int64".to_string()),
+        _ => None,
+    }
+}
+
 impl TryFrom<&String> for DataType {
     type Error = String;
 
     fn try_from(s: &String) -> Result<Self, Self::Error> {
         match s.as_str() {
             "string" => Ok(DataType::String),
+            "bytes" => Ok(DataType::String),
+            "decimal" => Ok(DataType::String),
             "number" => Ok(DataType::Number),
             "integer" => Ok(DataType::Integer),
+            "bigint" => Ok(DataType::Integer),
             "boolean" => Ok(DataType::Boolean),
             "array" => Ok(DataType::Array),
             "float" => Ok(DataType::Number),
@@ -233,6 +312,28 @@ impl From<&String> for PrimitiveType {
     }
 }
 
+impl From<&attribute::DataType> for PrimitiveType {
+    /// Converts an attribute value, such as a default or constant, into a `PrimitiveType`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A reference to the attribute value, where strings are enclosed in quotes.
+    ///
+    /// # Returns
+    ///
+    /// The corresponding `PrimitiveType` enum variant.
+    fn from(value: &attribute::DataType) -> Self {
+        match value {
+            attribute::DataType::Boolean(b) => PrimitiveType::Boolean(*b),
+            attribute::DataType::Integer(i) => PrimitiveType::Integer(*i),
+            attribute::DataType::Float(f) => PrimitiveType::Number(*f),
+            attribute::DataType::String(s) => {
+                PrimitiveType::String(s.trim_matches('"').to_string())
+            }
+        }
+    }
+}
+
 fn skip_empty<T>(option: &Option<Vec<T>>) -> bool {
     match option {
         Some(vec) => vec.is_empty(),
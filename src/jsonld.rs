@@ -0,0 +1,131 @@
+/*
+ * Copyright (c) 2024 Jan Range
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ *
+ */
+
+use std::error::Error;
+
+use serde_json::{json, Map, Value};
+
+use crate::datamodel::DataModel;
+
+/// Converts a data model into a standalone JSON-LD 1.1 context document.
+///
+/// The context declares the model prefix and all prefixes of the front matter.
+/// Objects and attributes with a term are mapped to their compact IRI, which is
+/// expanded using the declared prefixes. Identifier attributes are additionally
+/// typed as `@id`. Objects and attributes without a term are omitted. If
+/// attributes of different objects share a name, the first mapping is kept.
+///
+/// # Arguments
+///
+/// * `model` - The data model to convert.
+///
+/// # Returns
+///
+/// The context document as pretty-printed JSON or an error if serialization fails.
+pub fn to_jsonld_context(model: &DataModel) -> Result<String, Box<dyn Error>> {
+    let mut context = Map::new();
+    context.insert("@version".into(), json!(1.1));
+
+    if let Some(config) = &model.config {
+        context.insert(config.prefix.clone(), json!(config.repo));
+
+        let mut prefixes = config.prefixes().unwrap_or_default();
+        prefixes.sort();
+        for (prefix, address) in prefixes {
+            context.insert(prefix, json!(address));
+        }
+    }
+
+    for object in &model.objects {
+        if let Some(term) = &object.term {
+            context
+                .entry(object.name.clone())
+                .or_insert_with(|| json!({ "@id": term }));
+        }
+    }
+
+    for object in &model.objects {
+        for attribute in &object.attributes {
+            let Some(term) = &attribute.term else {
+                continue;
+            };
+
+            let mut definition = Map::new();
+            definition.insert("@id".into(), json!(term));
+            if attribute.is_id {
+                definition.insert("@type".into(), json!("@id"));
+            }
+
+            context
+                .entry(attribute.name.clone())
+                .or_insert(Value::Object(definition));
+        }
+    }
+
+    Ok(serde_json::to_string_pretty(
+        &json!({ "@context": context }),
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn test_to_jsonld_context() {
+        // Arrange
+        let model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse model");
+        let expected = fs::read_to_string("tests/data/expected_jsonld_context.json")
+            .expect("Could not read expected file");
+
+        // Act
+        let context = to_jsonld_context(&model).expect("Failed to convert");
+
+        // Assert
+        assert_eq!(context, expected);
+    }
+
+    #[test]
+    fn test_to_jsonld_context_omits_unannotated() {
+        // Arrange
+        let model = DataModel::from_markdown_string(
+            "### Plain\n\n- value\n  - Type: string\n\n### Annotated (schema:Thing)\n\n- name\n  - Type: string\n  - Term: schema:name\n",
+        )
+        .expect("Failed to parse model");
+
+        // Act
+        let context: Value =
+            serde_json::from_str(&to_jsonld_context(&model).expect("Failed to convert")).unwrap();
+
+        // Assert
+        let context = &context["@context"];
+        assert_eq!(context["Annotated"], json!({ "@id": "schema:Thing" }));
+        assert_eq!(context["name"], json!({ "@id": "schema:name" }));
+        assert!(context.get("Plain").is_none());
+        assert!(context.get("value").is_none());
+    }
+}
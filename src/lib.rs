@@ -29,23 +29,38 @@ use crate::exporters::Templates;
 use pyo3::prelude::*;
 
 pub mod datamodel;
+pub mod diff;
 pub mod exporters;
+pub mod jsonld;
+pub mod openapi;
 pub mod pipeline;
 pub mod validation;
 
 pub(crate) mod attribute;
 pub(crate) mod object;
+pub(crate) mod tree;
 pub(crate) mod xmltype;
 
 pub mod prelude {
-    pub use crate::datamodel::DataModel;
+    pub use crate::attribute::Attribute;
+    pub use crate::datamodel::{DataModel, DataModelBuilder};
     pub use crate::exporters::Templates;
+    pub use crate::markdown::frontmatter::FrontMatter;
+    pub use crate::object::{Enumeration, Object};
     pub use crate::validation::Validator;
 }
 
+pub mod avro {
+    pub mod import;
+}
+
+pub mod proto {
+    pub mod import;
+}
+
 pub mod json {
-    mod datatype;
     pub mod export;
+    pub mod import;
     pub mod schema;
     pub mod validation;
 }
@@ -56,6 +71,12 @@ pub(crate) mod markdown {
     pub(crate) mod position;
 }
 
+pub mod streaming {
+    pub use crate::markdown::parser::{
+        model_from_events, parse_markdown_streaming, ParseError, ParseEvent, ParseEvents,
+    };
+}
+
 #[cfg(feature = "openai")]
 pub mod llm {
     pub mod extraction;
@@ -76,5 +97,6 @@ fn mdmodels_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     m.add_class::<python::DataModel>()?;
     m.add_class::<Templates>()?;
+    m.add_function(wrap_pyfunction!(python::validate, m)?)?;
     Ok(())
 }
@@ -3,7 +3,10 @@ use std::env;
 use openai_api_rs::v1::{api::OpenAIClient, chat_completion};
 use serde_json::{json, Value};
 
-use crate::{datamodel::DataModel, json::export::to_json_schema};
+use crate::{
+    datamodel::DataModel,
+    json::export::{to_json_schema, JsonSchemaDraft, JsonSchemaInheritance},
+};
 
 /// Queries the OpenAI API with a given prompt and pre-prompt, using a specified data model and root.
 ///
@@ -50,7 +53,13 @@ fn prepare_response_format(
     root: &str,
     multiple: bool,
 ) -> Result<Value, Box<dyn std::error::Error>> {
-    let schema = to_json_schema(model, root, true)?;
+    let schema = to_json_schema(
+        model,
+        root,
+        true,
+        JsonSchemaDraft::Draft202012,
+        JsonSchemaInheritance::Inline,
+    )?;
 
     if multiple {
         Ok(json!(
@@ -23,7 +23,10 @@
 
 use std::collections::HashMap;
 
-use gray_matter::{engine::YAML, Matter};
+use gray_matter::{
+    engine::{TOML, YAML},
+    Matter,
+};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "python")]
@@ -32,6 +35,9 @@ use pyo3::pyclass;
 #[cfg(feature = "wasm")]
 use tsify_next::Tsify;
 
+/// Delimiter of TOML front matter, as opposed to `---` for YAML front matter.
+pub(crate) const TOML_DELIMITER: &str = "+++";
+
 /// Represents the front matter data of a markdown file.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[cfg_attr(feature = "python", pyclass(get_all))]
@@ -51,6 +57,63 @@ pub struct FrontMatter {
     /// A string field with a default value representing the prefix.
     #[serde(default = "default_prefix")]
     pub prefix: String,
+    /// Optional maximum nesting depth of objects, renamed from `max-nesting-depth`.
+    #[serde(
+        default,
+        rename = "max-nesting-depth",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_nesting_depth: Option<usize>,
+    /// Optional semantic version of the data model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Optional version requirement other models must satisfy, renamed from `requires-version`.
+    #[serde(
+        default,
+        rename = "requires-version",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub requires_version: Option<String>,
+    /// Custom attribute option keys that are allowed, renamed from `allow-unknown-options`.
+    #[serde(
+        default,
+        rename = "allow-unknown-options",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub allow_unknown_options: Vec<String>,
+    /// Whether to validate the model strictly, e.g. warning about unknown attribute options.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub strict: bool,
+    /// Whether basic types such as `string` have to be declared, renamed from `strict-types`.
+    #[serde(
+        default,
+        rename = "strict-types",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub strict_types: bool,
+    /// Additional primitive types that are valid without a definition, renamed from `extra-basic-types`.
+    #[serde(
+        default,
+        rename = "extra-basic-types",
+        alias = "extraBasicTypes",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub extra_basic_types: Vec<String>,
+    /// Whether attributes without a description are reported, renamed from `require-docstrings`.
+    #[serde(
+        default,
+        rename = "require-docstrings",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub require_docstrings: bool,
+    /// Whether attribute names may start with an underscore, renamed from `allow-underscore-prefix`.
+    #[serde(
+        default,
+        rename = "allow-underscore-prefix",
+        alias = "allowUnderscorePrefix",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub allow_underscore_prefix: bool,
 }
 
 impl FrontMatter {
@@ -61,6 +124,15 @@ impl FrontMatter {
             nsmap: None,
             repo: default_repo(),
             prefix: default_prefix(),
+            max_nesting_depth: None,
+            version: None,
+            requires_version: None,
+            allow_unknown_options: Vec::new(),
+            strict: false,
+            strict_types: false,
+            extra_basic_types: Vec::new(),
+            require_docstrings: false,
+            allow_underscore_prefix: false,
         }
     }
 
@@ -92,6 +164,48 @@ impl FrontMatter {
     pub fn nsmap(&self) -> &Option<HashMap<String, String>> {
         &self.nsmap
     }
+
+    /// Checks whether the given attribute option key is declared in `allow-unknown-options`.
+    ///
+    /// # Returns
+    /// A boolean indicating whether the option key is allowed, compared case-insensitively.
+    pub fn is_custom_option(&self, key: &str) -> bool {
+        self.allow_unknown_options
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(key))
+    }
+
+    /// Merges the validation options of another front matter into this one.
+    ///
+    /// Lists are combined, boolean switches are enabled if either side enables
+    /// them, and the stricter `max-nesting-depth` wins. Metadata such as
+    /// prefixes or the repository is left untouched.
+    ///
+    /// # Arguments
+    /// * `other` - The front matter whose validation options are merged in.
+    pub fn merge_validation_options(&mut self, other: &FrontMatter) {
+        for option in &other.allow_unknown_options {
+            if !self.is_custom_option(option) {
+                self.allow_unknown_options.push(option.clone());
+            }
+        }
+
+        for basic_type in &other.extra_basic_types {
+            if !self.extra_basic_types.contains(basic_type) {
+                self.extra_basic_types.push(basic_type.clone());
+            }
+        }
+
+        self.strict |= other.strict;
+        self.strict_types |= other.strict_types;
+        self.require_docstrings |= other.require_docstrings;
+        self.allow_underscore_prefix |= other.allow_underscore_prefix;
+
+        self.max_nesting_depth = match (self.max_nesting_depth, other.max_nesting_depth) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    }
 }
 
 impl Default for FrontMatter {
@@ -130,24 +244,26 @@ fn default_repo() -> String {
 
 /// Parses the front matter from the given content.
 ///
+/// The front matter is read as TOML if it is delimited by `+++` and as YAML otherwise.
+///
 /// # Arguments
 /// * `content` - A string slice that holds the content to parse.
 ///
 /// # Returns
 /// An optional `FrontMatter` if parsing is successful, otherwise `None`.
 pub fn parse_frontmatter(content: &str) -> Option<FrontMatter> {
-    let matter = Matter::<YAML>::new();
-    let result = matter.parse(content);
-
-    match result.data {
-        None => None,
-        Some(data) => {
-            let matter = data
-                .deserialize()
-                .expect("Could not deserialize frontmatter");
-            Some(matter)
-        }
-    }
+    let data = if content.starts_with(TOML_DELIMITER) {
+        let mut matter = Matter::<TOML>::new();
+        matter.delimiter = TOML_DELIMITER.to_string();
+        matter.parse(content).data
+    } else {
+        Matter::<YAML>::new().parse(content).data
+    };
+
+    data.map(|data| {
+        data.deserialize()
+            .expect("Could not deserialize frontmatter")
+    })
 }
 
 #[cfg(test)]
@@ -179,4 +295,88 @@ mod tests {
             "http://example.com/test/"
         );
     }
+
+    /// Tests the `parse_frontmatter` function with TOML front matter.
+    #[test]
+    fn test_parse_toml_frontmatter() {
+        // Arrange
+        let content = r#"+++
+id-field = false
+repo = "https://www.github.com/my/repo/"
+prefix = "tst"
+max-nesting-depth = 3
+version = "1.2.0"
+requires-version = ">=1.0.0"
+allow-unknown-options = ["unit"]
+strict = true
+strict-types = true
+extra-basic-types = ["uuid"]
+require-docstrings = true
+allow-underscore-prefix = true
+
+[prefixes]
+schema = "http://schema.org/"
+
+[nsmap]
+tst = "http://example.com/test/"
++++
+
+### Test
+"#;
+
+        // Act
+        let frontmatter = parse_frontmatter(content).expect("Could not parse TOML frontmatter");
+
+        // Assert
+        assert_eq!(
+            frontmatter,
+            FrontMatter {
+                id_field: false,
+                prefixes: Some(HashMap::from([(
+                    "schema".to_string(),
+                    "http://schema.org/".to_string()
+                )])),
+                nsmap: Some(HashMap::from([(
+                    "tst".to_string(),
+                    "http://example.com/test/".to_string()
+                )])),
+                repo: "https://www.github.com/my/repo/".to_string(),
+                prefix: "tst".to_string(),
+                max_nesting_depth: Some(3),
+                version: Some("1.2.0".to_string()),
+                requires_version: Some(">=1.0.0".to_string()),
+                allow_unknown_options: vec!["unit".to_string()],
+                strict: true,
+                strict_types: true,
+                extra_basic_types: vec!["uuid".to_string()],
+                require_docstrings: true,
+                allow_underscore_prefix: true,
+            }
+        );
+    }
+
+    /// Tests that TOML and YAML front matter of the same model are equal.
+    #[test]
+    fn test_parse_toml_frontmatter_matches_yaml() {
+        // Arrange
+        let yaml = std::fs::read_to_string("tests/data/model.md").expect("Could not read file");
+        let toml = std::fs::read_to_string("tests/data/model_toml_frontmatter.md")
+            .expect("Could not read file");
+
+        // Act
+        let yaml = parse_frontmatter(&yaml).expect("Could not parse YAML frontmatter");
+        let toml = parse_frontmatter(&toml).expect("Could not parse TOML frontmatter");
+
+        // Assert
+        assert_eq!(toml, yaml);
+    }
+
+    /// Tests that content without front matter yields no front matter.
+    #[test]
+    fn test_parse_missing_frontmatter() {
+        assert_eq!(
+            parse_frontmatter("### Test\n\n- name\n  - Type: string\n"),
+            None
+        );
+    }
 }
@@ -23,20 +23,24 @@
 
 use colored::Colorize;
 use core::panic;
+use gray_matter::engine::{Engine, YAML};
+use gray_matter::Pod;
 use lazy_static::lazy_static;
 use log::error;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::error::Error;
 
-use pulldown_cmark::{CowStr, Event, HeadingLevel, OffsetIter, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{
+    CodeBlockKind, CowStr, Event, HeadingLevel, OffsetIter, Options, Parser, Tag, TagEnd,
+};
 use regex::Regex;
 
-use crate::attribute;
+use crate::attribute::{self, PATTERN_OPTIONS};
 use crate::datamodel::DataModel;
 use crate::object::{self, Enumeration, Object};
-use crate::validation::Validator;
+use crate::validation::{ErrorType, ValidationError, Validator};
 
-use super::frontmatter::parse_frontmatter;
+use super::frontmatter::{parse_frontmatter, FrontMatter, TOML_DELIMITER};
 use super::position::{Position, PositionRange};
 
 lazy_static! {
@@ -52,6 +56,12 @@ lazy_static! {
         );
         m
     };
+    static ref CODE_PATTERN: Regex = Regex::new(
+        r"(?ms)^[ \t]*```.*?^[ \t]*```[^\n]*$|^[ \t]*~~~.*?^[ \t]*~~~[^\n]*$|``.+?``|`[^`]+`"
+    )
+    .unwrap();
+    static ref HTML_TAG_PATTERN: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref LINK_PATTERN: Regex = Regex::new(r"\[([^]]+)]\([^)]+\)").unwrap();
 }
 
 // Heading levels for re-use
@@ -94,6 +104,22 @@ enum ParserState {
 /// A `Result` containing a `DataModel` on success or an error on failure.
 #[allow(clippy::result_large_err)]
 pub fn parse_markdown(content: &str) -> Result<DataModel, Validator> {
+    finalize_model(parse_markdown_unvalidated(content))
+}
+
+/// Parses a Markdown file into a `DataModel` without post-processing or validation.
+///
+/// Use this when several files make up one model, so that references between
+/// the files resolve once the models are merged and passed to [`finalize_model`].
+///
+/// # Arguments
+///
+/// * `content` - The content of the Markdown file.
+///
+/// # Returns
+///
+/// The model with the objects and enumerations as written in the file.
+pub(crate) fn parse_markdown_unvalidated(content: &str) -> DataModel {
     // Remove HTML and links
     let content = clean_content(content);
 
@@ -150,34 +176,375 @@ pub fn parse_markdown(content: &str) -> Result<DataModel, Validator> {
     model.enums = enums.into_iter().filter(|e| e.has_values()).collect();
     model.objects = objects.into_iter().filter(|o| o.has_attributes()).collect();
 
+    model
+}
+
+/// Applies the post-processing steps to a parsed model and validates it.
+///
+/// # Arguments
+///
+/// * `model` - The model containing the parsed objects and enumerations.
+///
+/// # Returns
+///
+/// A `Result` containing the `DataModel` or the `Validator` with all errors.
+#[allow(clippy::result_large_err)]
+pub(crate) fn finalize_model(mut model: DataModel) -> Result<DataModel, Validator> {
     // Set 'is_enum' for all attributes using an enumeration
     set_enum_attributes(&mut model);
 
+    // Move options declared in 'allow-unknown-options' to the custom options
+    set_custom_options(&mut model);
+
     // Add internal types, if used
     add_internal_types(&mut model);
 
-    // Apply inheritance (THIS NEEDS TO BE SPLIT INTO A SEPARATE FUNCTION)
-    add_parent_types(&mut model).expect("Failed to add parent types");
+    // Apply inheritance and collect unresolved parents
+    let parent_errors = add_parent_types(&mut model);
 
     // Validate the model
     let mut validator = Validator::new();
     validator.validate(&model);
 
+    for error in parent_errors {
+        validator.add_error(error);
+    }
+
     if !validator.is_valid {
         return Err(validator);
     }
 
+    validator.log_warnings();
+
     Ok(model)
 }
 
+/// An event emitted by the streaming Markdown parser.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseEvent {
+    /// The front matter at the start of the document.
+    FrontMatter(FrontMatter),
+    /// An object definition with at least one attribute.
+    Object(Object),
+    /// An enumeration definition with at least one value.
+    Enum(Enumeration),
+    /// The name of the model, given by a level one heading.
+    ModelName(String),
+}
+
+/// An error of a single definition encountered by the streaming parser.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// The line of the heading of the erroneous definition.
+    pub line: usize,
+    /// A description of the error.
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[line: {}] {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Iterator over the events of a Markdown model, see [`parse_markdown_streaming`].
+pub struct ParseEvents {
+    content: String,
+    cursor: usize,
+    line: usize,
+    front_matter: Option<FrontMatter>,
+    pending: VecDeque<Result<ParseEvent, ParseError>>,
+}
+
+/// Parses a Markdown model incrementally.
+///
+/// The document is split at level one to three headings and each definition is
+/// parsed once the iterator reaches it, hence objects can be processed before
+/// the whole file is parsed. Erroneous definitions are reported as `ParseError`
+/// and parsing continues with the next definition.
+///
+/// The events do not pass validation. Use [`model_from_events`] to build and
+/// validate a model from the collected events.
+///
+/// # Arguments
+///
+/// * `content` - The Markdown content of the model.
+///
+/// # Returns
+///
+/// An iterator over the parse events.
+pub fn parse_markdown_streaming(content: &str) -> ParseEvents {
+    let content = clean_content(content);
+    let front_matter = parse_frontmatter(&content);
+    let (cursor, line) = front_matter_end(&content);
+
+    ParseEvents {
+        content,
+        cursor,
+        line,
+        front_matter,
+        pending: VecDeque::new(),
+    }
+}
+
+/// Builds and validates a data model from the events of the streaming parser.
+///
+/// # Arguments
+///
+/// * `events` - The events, e.g. collected from [`parse_markdown_streaming`].
+///
+/// # Returns
+///
+/// A `Result` containing the `DataModel` or the `Validator` with all errors.
+#[allow(clippy::result_large_err)]
+pub fn model_from_events(
+    events: impl IntoIterator<Item = ParseEvent>,
+) -> Result<DataModel, Validator> {
+    let mut model = DataModel::new(None, None);
+
+    for event in events {
+        match event {
+            ParseEvent::FrontMatter(config) => model.config = Some(config),
+            ParseEvent::ModelName(name) => model.name = Some(name),
+            ParseEvent::Object(object) => model.objects.push(object),
+            ParseEvent::Enum(enumeration) => model.enums.push(enumeration),
+        }
+    }
+
+    finalize_model(model)
+}
+
+impl Iterator for ParseEvents {
+    type Item = Result<ParseEvent, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(config) = self.front_matter.take() {
+            return Some(Ok(ParseEvent::FrontMatter(config)));
+        }
+
+        while self.pending.is_empty() {
+            let (start, end, line) = self.next_section()?;
+            // Keep the preceding line break, so that column positions match
+            // those of the non-streaming parser
+            let (start, line) = if start > 0 {
+                (start - 1, line - 1)
+            } else {
+                (start, line)
+            };
+            let section = self.content[start..end].to_string();
+            self.parse_section(&section, start, line);
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+impl ParseEvents {
+    /// Finds the next section starting with a heading of level one to three.
+    ///
+    /// # Returns
+    ///
+    /// The byte range and the line number of the section, or `None` at the end.
+    fn next_section(&mut self) -> Option<(usize, usize, usize)> {
+        let mut start = None;
+        let mut in_fence = false;
+        let mut offset = self.cursor;
+        let mut line = self.line;
+
+        for text in self.content[self.cursor..].split_inclusive('\n') {
+            let trimmed = text.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+            }
+
+            if !in_fence && heading_level(text).is_some() {
+                match start {
+                    None => start = Some((offset, line)),
+                    Some((section_start, section_line)) => {
+                        self.cursor = offset;
+                        self.line = line;
+                        return Some((section_start, offset, section_line));
+                    }
+                }
+            }
+
+            offset += text.len();
+            line += 1;
+        }
+
+        self.cursor = self.content.len();
+        self.line = line;
+        start.map(|(section_start, section_line)| (section_start, offset, section_line))
+    }
+
+    /// Parses a single section and queues its events.
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The content of the section, starting with its heading or
+    ///   the line break preceding it.
+    /// * `offset` - The byte offset of the section within the document.
+    /// * `line` - The line number of the section start within the document.
+    fn parse_section(&mut self, section: &str, offset: usize, line: usize) {
+        let heading_line = line + usize::from(section.starts_with('\n'));
+        let heading = section
+            .trim_start_matches('\n')
+            .lines()
+            .next()
+            .unwrap_or_default();
+        let title = heading.trim_start_matches('#').trim();
+
+        match heading_level(heading) {
+            Some(1) if !title.is_empty() => {
+                self.pending
+                    .push_back(Ok(ParseEvent::ModelName(title.to_string())));
+                return;
+            }
+            Some(3) => {}
+            _ => return,
+        }
+
+        if let Err(message) = check_object_heading(title) {
+            self.pending.push_back(Err(ParseError {
+                line: heading_line,
+                message,
+            }));
+            return;
+        }
+
+        let line_offsets: Vec<usize> = section
+            .char_indices()
+            .filter(|(_, c)| *c == '\n')
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_HEADING_ATTRIBUTES);
+        let mut iterator = Parser::new_ext(section, options).into_offset_iter();
+
+        let mut objects = Vec::new();
+        let mut model = DataModel::new(None, None);
+        let mut state = ParserState::OutsideDefinition;
+        while let Some(event) = iterator.next() {
+            process_object_event(
+                section,
+                &mut iterator,
+                &mut objects,
+                event,
+                &mut model,
+                &mut state,
+                &line_offsets,
+            );
+        }
+
+        let mut iterator = Parser::new(section).into_offset_iter();
+        let mut enums = Vec::new();
+        while let Some(event) = iterator.next() {
+            process_enum_event(section, &mut iterator, &mut enums, event, &line_offsets);
+        }
+
+        let shift = |position: &mut Option<Position>| {
+            if let Some(position) = position {
+                position.line += line - 1;
+                position.offset.start += offset;
+                position.offset.end += offset;
+            }
+        };
+
+        for mut object in objects.into_iter().filter(|o| o.has_attributes()) {
+            shift(&mut object.position);
+            for attribute in object.attributes.iter_mut() {
+                shift(&mut attribute.position);
+            }
+            self.pending.push_back(Ok(ParseEvent::Object(object)));
+        }
+
+        for mut enumeration in enums.into_iter().filter(|e| e.has_values()) {
+            shift(&mut enumeration.position);
+            self.pending.push_back(Ok(ParseEvent::Enum(enumeration)));
+        }
+    }
+}
+
+/// Determines the level of an ATX heading line, if it is one of level one to three.
+fn heading_level(line: &str) -> Option<usize> {
+    let hashes = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[hashes..];
+
+    match hashes {
+        1..=3 if rest.is_empty() || rest.starts_with([' ', '\t', '\n', '\r']) => Some(hashes),
+        _ => None,
+    }
+}
+
+/// Checks the heading of an object definition for syntax errors.
+///
+/// # Arguments
+///
+/// * `title` - The heading text without the leading hashes.
+fn check_object_heading(title: &str) -> Result<(), String> {
+    if title.is_empty() {
+        return Err("Definition has no name".to_string());
+    }
+
+    if let Some((_, parent)) = title.split_once('[') {
+        let parent = parent.trim_end().trim_end_matches(']').trim();
+        if parent.is_empty() {
+            return Err(format!(
+                "Definition '{}' has an opening bracket but no parent name",
+                title.split_whitespace().next().unwrap_or_default()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the end of the front matter block.
+///
+/// # Returns
+///
+/// The byte offset and line number of the first line after the front matter.
+fn front_matter_end(content: &str) -> (usize, usize) {
+    let mut lines = content.split_inclusive('\n');
+
+    match lines.next().map(|first| (first, first.trim_end())) {
+        Some((first, delimiter)) if delimiter == "---" || delimiter == TOML_DELIMITER => {
+            let mut offset = first.len();
+            for (index, text) in lines.enumerate() {
+                offset += text.len();
+                if text.trim_end() == delimiter {
+                    return (offset, index + 3);
+                }
+            }
+            (0, 1)
+        }
+        _ => (0, 1),
+    }
+}
+
 fn clean_content(content: &str) -> String {
+    // Replace code spans and fenced blocks by placeholders to leave them untouched
+    let mut placeholders: HashMap<String, String> = HashMap::new();
+    let content = CODE_PATTERN
+        .replace_all(content, |caps: &regex::Captures| {
+            let placeholder = format!("\u{FDD0}{}\u{FDD1}", placeholders.len());
+            placeholders.insert(placeholder.clone(), caps[0].to_string());
+            placeholder
+        })
+        .to_string();
+
     // Remove all html tags
-    let re = Regex::new(r"<[^>]*>").unwrap();
-    let content = re.replace_all(content, "").to_string();
+    let content = HTML_TAG_PATTERN.replace_all(&content, "").to_string();
 
     // Remove all Markdown links
-    let re = Regex::new(r"\[([^]]+)]\([^)]+\)").unwrap();
-    let content = re.replace_all(content.as_str(), "$1").to_string();
+    let mut content = LINK_PATTERN.replace_all(&content, "$1").to_string();
+
+    for (placeholder, code) in placeholders {
+        content = content.replacen(&placeholder, &code, 1);
+    }
 
     content
 }
@@ -262,27 +629,23 @@ fn process_object_event(
         Event::End(tag) if tag == H3_END => {
             *state = ParserState::InDefinition;
         }
-        Event::Text(CowStr::Borrowed("[")) => {
-            if *state == ParserState::InHeading {
-                // Extract parent from the next text event
-                let last_object = objects.last_mut().unwrap();
-                let parent = iterator.next();
+        Event::Text(CowStr::Borrowed("[")) if *state == ParserState::InHeading => {
+            // Extract parent from the next text event
+            let last_object = objects.last_mut().unwrap();
+            let parent = iterator.next();
 
-                match parent {
-                    Some((Event::Text(text), _)) if text.to_string() != "]" => {
-                        last_object.parent = Some(text.to_string());
-                    }
-                    _ => {
-                        error!(
-                            "[{}] {}: Opening bracket but no parent name. Inheritance wont be applied",
-                            last_object.name.bold(),
-                            "SyntaxError".bold(),
-                        );
-
-                        panic!(
-                            "Inheritance syntax error. Expected parent name after opening bracket."
-                        );
-                    }
+            match parent {
+                Some((Event::Text(text), _)) if text.to_string() != "]" => {
+                    last_object.parent = Some(text.to_string());
+                }
+                _ => {
+                    error!(
+                        "[{}] {}: Opening bracket but no parent name. Inheritance wont be applied",
+                        last_object.name.bold(),
+                        "SyntaxError".bold(),
+                    );
+
+                    panic!("Inheritance syntax error. Expected parent name after opening bracket.");
                 }
             }
         }
@@ -315,16 +678,84 @@ fn process_object_event(
             attribute.set_position(get_position(content, line_offsets, range.start, range.end));
             objects.last_mut().unwrap().add_attribute(attribute);
         }
-        Event::Text(text) => {
-            if *state == ParserState::InDefinition {
-                let last_object = objects.last_mut().unwrap();
-                last_object.docstring.push_str(text.as_ref());
-            }
+        Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info)))
+            if *state == ParserState::InDefinition && is_example_block(&info) =>
+        {
+            process_example_block(iterator, objects);
+        }
+        Event::Text(text) if *state == ParserState::InDefinition => {
+            let last_object = objects.last_mut().unwrap();
+            last_object.docstring.push_str(text.as_ref());
         }
         _ => {}
     }
 }
 
+/// Checks whether the info string of a fenced code block marks an example.
+///
+/// # Arguments
+///
+/// * `info` - The info string of the fenced code block, e.g. `json example`.
+///
+/// # Returns
+///
+/// `true` if the info string contains the `example` marker.
+fn is_example_block(info: &str) -> bool {
+    info.split_whitespace().any(|word| word == "example")
+}
+
+/// Processes an example code block and adds its content to the last object.
+///
+/// The content is parsed as JSON and, if that fails, as YAML.
+///
+/// # Arguments
+///
+/// * `iterator` - A mutable reference to the parser iterator.
+/// * `objects` - A mutable reference to the vector of objects.
+fn process_example_block(iterator: &mut OffsetIter, objects: &mut [Object]) {
+    let mut code = String::new();
+    for (event, _) in iterator.by_ref() {
+        match event {
+            Event::Text(text) => code.push_str(text.as_ref()),
+            Event::End(TagEnd::CodeBlock) => break,
+            _ => {}
+        }
+    }
+
+    let Some(last_object) = objects.last_mut() else {
+        return;
+    };
+
+    match parse_example(&code) {
+        Some(example) => last_object.examples.push(example),
+        None => error!(
+            "[{}] {}: Could not parse example as JSON or YAML",
+            last_object.name.bold(),
+            "SyntaxError".bold(),
+        ),
+    }
+}
+
+/// Parses the content of an example code block.
+///
+/// # Arguments
+///
+/// * `code` - The content of the code block.
+///
+/// # Returns
+///
+/// The parsed example or `None` if the content is neither valid JSON nor YAML.
+fn parse_example(code: &str) -> Option<serde_json::Value> {
+    if let Ok(value) = serde_json::from_str(code) {
+        return Some(value);
+    }
+
+    match YAML::parse(code) {
+        Pod::Null => None,
+        pod => pod.deserialize().ok(),
+    }
+}
+
 /// Processes the heading of an object.
 ///
 /// # Arguments
@@ -377,7 +808,17 @@ fn extract_name(iterator: &mut OffsetIter) -> String {
 /// A tuple containing a boolean indicating if the attribute is required and the attribute name.
 fn extract_attr_name_required(iterator: &mut OffsetIter) -> (bool, String) {
     if let Some((Event::Text(text), _)) = iterator.next() {
-        return (false, text.to_string());
+        let mut name = text.to_string();
+
+        // Unmatched leading underscores, as in `_id`, are emitted as separate text events
+        while name.chars().all(|c| c == '_') {
+            match iterator.next() {
+                Some((Event::Text(text), _)) => name.push_str(text.as_ref()),
+                _ => break,
+            }
+        }
+
+        return (false, name);
     }
 
     // Try for two text events
@@ -469,7 +910,18 @@ fn add_option_to_last_attribute(
 fn distribute_attribute_options(objects: &mut [object::Object], attr_string: String) -> Option<()> {
     if attr_string.contains(':') {
         let (key, value) = process_option(&attr_string);
-        add_option_to_last_attribute(objects, key, value).expect("Failed to add option");
+        let result = add_option_to_last_attribute(objects, key.clone(), value.clone());
+
+        if result.is_err() && PATTERN_OPTIONS.contains(&key.to_lowercase().as_str()) {
+            // Invalid patterns are kept and reported by the validator along with their position
+            let last_attr = objects.last_mut().unwrap().get_last_attribute();
+            last_attr
+                .options
+                .push(attribute::AttrOption::new(key, value));
+        } else {
+            result.expect("Failed to add option");
+        }
+
         return None;
     }
 
@@ -535,7 +987,7 @@ pub fn process_enum_event(
             enum_obj.set_position(get_position(content, line_offsets, range.start, range.end));
             enums.push(enum_obj);
         }
-        Event::Start(Tag::CodeBlock(pulldown_cmark::CodeBlockKind::Fenced(_))) => {
+        Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) if !is_example_block(&info) => {
             let event = iterator.next().unwrap();
             if let (Event::Text(text), _) = event {
                 let mappings = text.to_string();
@@ -546,10 +998,39 @@ pub fn process_enum_event(
                 }
             }
         }
+        Event::Start(Tag::Paragraph) => {
+            // Paragraphs between the heading and the code block describe the enum
+            if let Some(enum_obj) = enums.last_mut().filter(|e| !e.has_values()) {
+                process_enum_docstring(iterator, enum_obj);
+            }
+        }
         _ => {}
     }
 }
 
+/// Appends the text of a paragraph to the docstring of an enumeration.
+///
+/// # Arguments
+///
+/// * `iterator` - A mutable reference to the parser iterator, positioned after the start of the paragraph.
+/// * `enum_obj` - A mutable reference to the enumeration object.
+fn process_enum_docstring(iterator: &mut OffsetIter, enum_obj: &mut Enumeration) {
+    let mut paragraph = String::new();
+    for (event, _) in iterator.by_ref() {
+        match event {
+            Event::End(TagEnd::Paragraph) => break,
+            Event::Text(text) | Event::Code(text) => paragraph.push_str(text.as_ref()),
+            Event::SoftBreak | Event::HardBreak => paragraph.push(' '),
+            _ => {}
+        }
+    }
+
+    if !enum_obj.docstring.is_empty() {
+        enum_obj.docstring.push(' ');
+    }
+    enum_obj.docstring.push_str(paragraph.trim());
+}
+
 /// Processes enumeration mappings from a code block.
 ///
 /// # Arguments
@@ -574,37 +1055,39 @@ fn process_enum_mappings(enum_obj: &mut Enumeration, mappings: String) {
 
 /// Adds parent types to the objects in the model.
 ///
+/// The parent chain is followed up to the root, hence attributes of
+/// grandparents are inherited as well. Chains that run into a cycle are
+/// stopped, the cycle itself is reported by `Validator::check_circular_inheritance`.
+///
 /// # Arguments
 ///
 /// * `model` - A mutable reference to the data model.
 ///
+/// # Returns
 ///
-/// # Panics
-///
-/// Panics if an object has a parent that does not exist.
-///
-/// # Errors
-///
-/// An error is logged if an object has a parent that does not exist.
+/// A validation error for each object that has a parent that does not exist.
 ///
-fn add_parent_types(model: &mut DataModel) -> Result<(), Box<dyn Error>> {
-    // Filter and clone the objects without a parent
-    let parents: Vec<Object> = model
-        .objects
-        .iter()
-        .filter(|o| o.parent.is_none())
-        .cloned()
-        .collect();
+fn add_parent_types(model: &mut DataModel) -> Vec<ValidationError> {
+    // Keep the original objects, so inherited attributes are not inherited twice
+    let originals: Vec<Object> = model.objects.clone();
 
     let mut to_merge: Vec<DataModel> = vec![];
     let mut added_internals: Vec<String> = vec![];
+    let mut errors: Vec<ValidationError> = vec![];
 
-    // REFACTOR THIS
-    // Iterate over the objects and add the parent attributes
     for object in model.objects.iter_mut() {
-        if let Some(parent_name) = &object.parent {
-            if let Some(parent) = parents.iter().find(|o| o.name == *parent_name) {
+        let mut visited = HashSet::from([object.name.clone()]);
+        let mut current = object.parent.clone();
+
+        while let Some(parent_name) = current.take() {
+            if !visited.insert(parent_name.clone()) {
+                // Circular inheritance is reported by the validator
+                break;
+            }
+
+            if let Some(parent) = originals.iter().find(|o| o.name == parent_name) {
                 object.attributes.extend(parent.attributes.clone());
+                current = parent.parent.clone();
             } else if let Some(internal_type) = MD_MODEL_TYPES.get(parent_name.as_str()) {
                 let mut internal_type = serde_json::from_str::<DataModel>(internal_type)
                     .expect("Failed to parse internal data type");
@@ -615,19 +1098,19 @@ fn add_parent_types(model: &mut DataModel) -> Result<(), Box<dyn Error>> {
 
                 object.attributes.extend(target_obj.attributes.clone());
 
-                if !added_internals.contains(parent_name) {
+                if !added_internals.contains(&parent_name) {
                     to_merge.push(internal_type);
                     added_internals.push(parent_name.clone());
                 }
             } else {
-                error!(
-                    "[{}] {}: Parent {} does not exist.",
-                    object.name.red().bold(),
-                    "InheritanceError".bold(),
-                    parent_name.red().bold(),
-                );
-
-                return Err("Object has a parent that does not exist".into());
+                errors.push(ValidationError {
+                    message: format!("Parent '{}' does not exist.", parent_name),
+                    object: Some(object.name.clone()),
+                    attribute: None,
+                    location: "Global".into(),
+                    error_type: ErrorType::ObjectError,
+                    positions: object.position.iter().cloned().collect(),
+                });
             }
         }
     }
@@ -636,17 +1119,15 @@ fn add_parent_types(model: &mut DataModel) -> Result<(), Box<dyn Error>> {
         model.merge(&internal);
     }
 
-    Ok(())
+    errors
 }
 
 fn add_internal_types(model: &mut DataModel) {
     // Get all datatypes within the model
-    let mut all_types = vec![];
-    for object in &model.objects {
-        for attr in &object.attributes {
-            all_types.extend(attr.dtypes.clone());
-        }
-    }
+    let all_types: Vec<String> = model
+        .all_attributes()
+        .flat_map(|(_, attr)| attr.dtypes.clone())
+        .collect();
 
     let object_names = model
         .objects
@@ -668,6 +1149,34 @@ fn add_internal_types(model: &mut DataModel) {
     }
 }
 
+/// Moves attribute options that are declared as custom options to `custom_options`.
+///
+/// Option keys listed in `allow-unknown-options` of the frontmatter are known to
+/// external tooling but not part of the standard options. These are kept separate
+/// from the other options and are exported as extension fields.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn set_custom_options(model: &mut DataModel) {
+    let config = model.config().clone();
+    if config.allow_unknown_options.is_empty() {
+        return;
+    }
+
+    for object in model.objects.iter_mut() {
+        for attr in object.attributes.iter_mut() {
+            let (custom, other): (Vec<_>, Vec<_>) = attr
+                .options
+                .drain(..)
+                .partition(|option| config.is_custom_option(&option.key));
+
+            attr.options = other;
+            attr.custom_options.extend(custom);
+        }
+    }
+}
+
 /// Sets the `is_enum` flag for attributes that are enumerations.
 ///
 /// This function iterates through all objects and their attributes in the data model.
@@ -678,24 +1187,153 @@ fn add_internal_types(model: &mut DataModel) {
 /// # Arguments
 ///
 /// * `model` - A mutable reference to the data model.
-fn set_enum_attributes(model: &mut DataModel) {
+pub(crate) fn set_enum_attributes(model: &mut DataModel) {
     let enums = model
         .enums
         .iter()
         .map(|e| e.name.clone())
         .collect::<Vec<String>>();
 
-    for object in model.objects.iter_mut() {
-        for attr in object.attributes.iter_mut() {
-            let enum_dtypes: Vec<String> = attr
-                .dtypes
-                .iter()
-                .filter(|dtype| enums.contains(dtype))
-                .cloned()
-                .collect();
-            if !enum_dtypes.is_empty() && enum_dtypes.len() == attr.dtypes.len() {
-                attr.is_enum = true;
-            }
+    for (_, attr) in model.all_attributes_mut() {
+        let enum_dtypes: Vec<String> = attr
+            .dtypes
+            .iter()
+            .filter(|dtype| enums.contains(dtype))
+            .cloned()
+            .collect();
+        if !enum_dtypes.is_empty() && enum_dtypes.len() == attr.dtypes.len() {
+            attr.is_enum = true;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_clean_content_preserves_code() {
+        // Arrange
+        let content = "Some <b>bold</b> [link](https://example.com) and `Type[MyClass](x)` with `<T>`.\n\n```python\nx = \"<div>[a](b)</div>\"\n```\n\n~~~\n<span>[c](d)</span>\n~~~\n";
+
+        // Act
+        let cleaned = clean_content(content);
+
+        // Assert
+        assert_eq!(
+            cleaned,
+            "Some bold link and `Type[MyClass](x)` with `<T>`.\n\n```python\nx = \"<div>[a](b)</div>\"\n```\n\n~~~\n<span>[c](d)</span>\n~~~\n"
+        );
+    }
+
+    #[test]
+    fn test_streaming_matches_parse_markdown() {
+        for path in [
+            "tests/data/model.md",
+            "tests/data/model_full_documentation.md",
+            "tests/data/model_inheritance_nested.md",
+            "tests/data/model_toml_frontmatter.md",
+        ] {
+            // Arrange
+            let content = std::fs::read_to_string(path).unwrap();
+
+            // Act
+            let events = parse_markdown_streaming(&content)
+                .collect::<Result<Vec<_>, _>>()
+                .expect("Streaming parser failed");
+            let streamed = model_from_events(events).expect("Model is invalid");
+            let expected = parse_markdown(&content).expect("Model is invalid");
+
+            // Assert
+            assert_eq!(streamed, expected, "Mismatch for {}", path);
+        }
+    }
+
+    #[test]
+    fn test_enum_docstring() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model_enum_docstring.md").unwrap();
+
+        // Act
+        let model = parse_markdown(&content).expect("Model is invalid");
+
+        // Assert
+        let docstrings: Vec<(&str, &str)> = model
+            .enums
+            .iter()
+            .map(|e| (e.name.as_str(), e.docstring.as_str()))
+            .collect();
+        assert_eq!(
+            docstrings,
+            vec![
+                (
+                    "Unit",
+                    "Units of measurement used for samples. Values follow the SI notation."
+                ),
+                ("Role", ""),
+            ]
+        );
+        assert_eq!(model.objects[0].docstring, "A sample with a unit.");
+    }
+
+    #[test]
+    fn test_streaming_event_order() {
+        // Arrange
+        let content = "---\nid: test\n---\n\n# Model\n\n### First\n\n- name\n  - Type: string\n\n```\n### NotAHeading\n```\n\n### Second\n\n- value\n  - Type: First\n\n## Enumerations\n\n### Kind\n\n```python\nA = \"a\"\n```\n";
+
+        // Act
+        let events: Vec<ParseEvent> = parse_markdown_streaming(content)
+            .map(|event| event.expect("Unexpected parse error"))
+            .collect();
+
+        // Assert
+        let kinds: Vec<String> = events
+            .iter()
+            .map(|event| match event {
+                ParseEvent::FrontMatter(_) => "frontmatter".to_string(),
+                ParseEvent::ModelName(name) => format!("model:{}", name),
+                ParseEvent::Object(object) => format!("object:{}", object.name),
+                ParseEvent::Enum(enumeration) => format!("enum:{}", enumeration.name),
+            })
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                "frontmatter",
+                "model:Model",
+                "object:First",
+                "object:Second",
+                "enum:Kind",
+            ]
+        );
+
+        let ParseEvent::Object(second) = &events[3] else {
+            unreachable!("Expected an object event");
+        };
+        assert_eq!(second.position.as_ref().unwrap().line, 16);
+    }
+
+    #[test]
+    fn test_streaming_continues_after_error() {
+        // Arrange
+        let content =
+            "### Broken [\n\n- name\n  - Type: string\n\n### Valid\n\n- name\n  - Type: string\n";
+
+        // Act
+        let events: Vec<_> = parse_markdown_streaming(content).collect();
+
+        // Assert
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0],
+            Err(ParseError {
+                line: 1,
+                message: "Definition 'Broken' has an opening bracket but no parent name"
+                    .to_string(),
+            })
+        );
+        assert!(matches!(&events[1], Ok(ParseEvent::Object(o)) if o.name == "Valid"));
+    }
+}
@@ -34,7 +34,7 @@ use tsify_next::Tsify;
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "python", pyclass(get_all))]
+#[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(feature = "wasm", derive(Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
 /// Represents an object with a name, attributes, docstring, and an optional term.
@@ -51,6 +51,9 @@ pub struct Object {
     pub parent: Option<String>,
     /// The line number of the object
     pub position: Option<Position>,
+    /// Example instances of the object.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<serde_json::Value>,
 }
 
 impl Object {
@@ -72,6 +75,7 @@ impl Object {
             term,
             parent: None,
             position: None,
+            examples: Vec::new(),
         }
     }
 
@@ -153,22 +157,148 @@ impl Object {
         self.attributes.iter().any(|attr| attr.has_term())
     }
 
-    /// Sorts the attributes of the object by their `required` field in descending order.
-    pub fn sort_attrs_by_required(&mut self) {
-        let mut top_elements: Vec<Attribute> = vec![];
-        let mut bottom_elements: Vec<Attribute> = vec![];
-
-        for attr in self.attributes.iter() {
-            if attr.required && attr.default.is_none() && !attr.is_array {
-                top_elements.push(attr.clone());
-            } else {
-                bottom_elements.push(attr.clone());
-            }
+    /// Returns all attributes which have the given data type.
+    ///
+    /// # Arguments
+    ///
+    /// * `dtype` - The name of the data type, e.g. `string` or an object name.
+    pub fn attributes_by_dtype<'a>(
+        &'a self,
+        dtype: &'a str,
+    ) -> impl Iterator<Item = &'a Attribute> {
+        self.attributes
+            .iter()
+            .filter(move |attr| attr.dtypes.iter().any(|d| d == dtype))
+    }
+
+    /// Returns all attributes which are typed by an enumeration.
+    pub fn enum_attributes(&self) -> impl Iterator<Item = &Attribute> {
+        self.attributes.iter().filter(|attr| attr.is_enum)
+    }
+
+    /// Returns all attributes which are arrays.
+    pub fn array_attributes(&self) -> impl Iterator<Item = &Attribute> {
+        self.attributes.iter().filter(|attr| attr.is_array)
+    }
+
+    /// Returns the number of attributes of the object.
+    pub fn attribute_count(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns the number of attributes which are marked as required.
+    pub fn required_count(&self) -> usize {
+        self.attributes.iter().filter(|attr| attr.required).count()
+    }
+
+    /// Returns the number of attributes which are not marked as required.
+    pub fn optional_count(&self) -> usize {
+        self.attribute_count() - self.required_count()
+    }
+
+    /// Returns the number of attributes which are arrays.
+    pub fn array_count(&self) -> usize {
+        self.array_attributes().count()
+    }
+
+    /// Returns the number of attributes which are typed by an enumeration.
+    pub fn enum_attribute_count(&self) -> usize {
+        self.enum_attributes().count()
+    }
+
+    /// Returns the fraction of attributes which have both a term and a docstring.
+    ///
+    /// Objects without attributes have a ratio of `0.0`.
+    pub fn coverage_ratio(&self) -> f64 {
+        if self.attributes.is_empty() {
+            return 0.0;
         }
 
-        self.attributes = top_elements;
-        self.attributes.append(&mut bottom_elements);
+        let covered = self
+            .attributes
+            .iter()
+            .filter(|attr| attr.has_term() && !attr.docstring.is_empty())
+            .count();
+
+        covered as f64 / self.attribute_count() as f64
     }
+
+    /// Checks if all attributes of the object are only typed by basic types.
+    pub fn has_only_primitives(&self) -> bool {
+        self.attributes.iter().all(|attr| attr.is_primitive())
+    }
+
+    /// Converts the object to a markdown definition.
+    ///
+    /// The definition consists of the H3 heading including the term and the
+    /// parent, the docstring paragraph, the list of attributes and the
+    /// example instances as JSON code blocks.
+    ///
+    /// # Returns
+    ///
+    /// A markdown string that is parsed back into an equivalent object.
+    pub fn to_markdown(&self) -> String {
+        let mut heading = format!("### {}", self.name);
+
+        if let Some(term) = &self.term {
+            heading.push_str(&format!(" ({})", term));
+        }
+
+        if let Some(parent) = &self.parent {
+            heading.push_str(&format!(" [{}]", parent));
+        }
+
+        let mut blocks = vec![heading];
+
+        if !self.docstring.is_empty() {
+            blocks.push(self.docstring.clone());
+        }
+
+        let attributes: Vec<String> = self.attributes.iter().map(|a| a.to_markdown()).collect();
+        blocks.push(attributes.join("\n"));
+
+        for example in &self.examples {
+            let example = serde_json::to_string_pretty(example).unwrap_or_default();
+            blocks.push(format!("```json example\n{}\n```", example));
+        }
+
+        blocks.join("\n\n") + "\n"
+    }
+
+    /// Returns all attributes which have to be given on construction.
+    ///
+    /// These are required attributes without a default value which are not arrays,
+    /// since arrays default to an empty list.
+    pub fn required_attributes(&self) -> impl Iterator<Item = &Attribute> {
+        self.attributes.iter().filter(|attr| is_required(attr))
+    }
+
+    /// Returns all attributes which may be omitted on construction.
+    ///
+    /// See `Object::required_attributes` for the complement.
+    pub fn optional_attributes(&self) -> impl Iterator<Item = &Attribute> {
+        self.attributes.iter().filter(|attr| !is_required(attr))
+    }
+
+    /// Splits the attributes into required and optional attributes, keeping their order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the required and the optional attributes.
+    pub fn split_attrs_by_required(&self) -> (Vec<&Attribute>, Vec<&Attribute>) {
+        self.attributes.iter().partition(|attr| is_required(attr))
+    }
+
+    /// Sorts the attributes of the object by their `required` field in descending order.
+    pub fn sort_attrs_by_required(&mut self) {
+        let (required, optional) = self.split_attrs_by_required();
+        self.attributes = required.into_iter().chain(optional).cloned().collect();
+    }
+}
+
+/// Checks whether an attribute has to be given on construction of its object.
+fn is_required(attr: &Attribute) -> bool {
+    attr.required && attr.default.is_none() && !attr.is_array
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -188,6 +318,46 @@ pub struct Enumeration {
 }
 
 impl Enumeration {
+    /// Creates an enumeration whose keys are equal to their values.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the enumeration.
+    /// * `values` - The values of the enumeration.
+    pub fn from_values(name: &str, values: &[&str]) -> Self {
+        let mappings: Vec<(&str, &str)> = values.iter().map(|value| (*value, *value)).collect();
+        Self::from_mapping(name, &mappings)
+    }
+
+    /// Creates an enumeration from key-value pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the enumeration.
+    /// * `mappings` - The keys and values of the enumeration.
+    pub fn from_mapping(name: &str, mappings: &[(&str, &str)]) -> Self {
+        Self {
+            name: name.to_string(),
+            mappings: mappings
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            docstring: String::new(),
+            position: None,
+        }
+    }
+
+    /// Adds a key-value pair to the enumeration, replacing an existing value of the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the mapping.
+    /// * `value` - The value of the mapping.
+    pub fn add_mapping(&mut self, key: &str, value: &str) -> &mut Self {
+        self.mappings.insert(key.to_string(), value.to_string());
+        self
+    }
+
     /// Checks if the enumeration has any values.
     ///
     /// # Returns
@@ -210,7 +380,11 @@ impl Enumeration {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::attribute::AttrOption;
+    use crate::datamodel::DataModel;
+    use crate::markdown::parser::parse_markdown;
     use pretty_assertions::assert_eq;
+    use std::path::Path;
 
     #[test]
     fn test_create_new_object() {
@@ -253,4 +427,224 @@ mod tests {
         assert_eq!(object.attributes.len(), 1);
         assert_eq!(object.attributes[0].name, "name");
     }
+
+    /// Builds attributes covering the options written by `Attribute::to_markdown`.
+    fn sample_attributes() -> Vec<Attribute> {
+        let mut attributes = Vec::new();
+        let options = [
+            vec![("Type", "Identifier"), ("Description", "The identifier.")],
+            vec![("Type", "string[]"), ("Term", "schema:name")],
+            vec![("Type", "float"), ("Default", "1.5"), ("XML", "@value")],
+            vec![("Type", "integer, string"), ("Multiple", "true")],
+            vec![
+                ("Type", "string"),
+                ("Default", "unknown"),
+                ("Const", "\"fixed\""),
+            ],
+            vec![
+                ("Type", "boolean"),
+                ("Deprecated", "true"),
+                ("ReadOnly", "true"),
+            ],
+            vec![
+                ("Type", "string"),
+                ("Alias", "label"),
+                ("Examples", "\"a, b\", c"),
+            ],
+            vec![("Type", "integer"), ("Minimum", "0"), ("Maximum", "10")],
+        ];
+
+        for (i, options) in options.iter().enumerate() {
+            let mut attribute = Attribute::new(format!("attribute_{}", i), i % 2 == 0);
+            for (key, value) in options {
+                let option = AttrOption::new(key.to_string(), value.to_string());
+                attribute.add_option(option).expect("Failed to add option");
+            }
+            attributes.push(attribute);
+        }
+
+        attributes
+    }
+
+    #[test]
+    fn test_to_markdown_roundtrip() {
+        let attributes = sample_attributes();
+
+        // Every contiguous window of attributes combined with every optional
+        // part of an object is written and parsed again
+        for size in 1..=attributes.len() {
+            for window in attributes.windows(size) {
+                for variant in 0..8 {
+                    // Arrange
+                    let mut object = Object::new(
+                        "Sample".to_string(),
+                        (variant & 1 == 1).then(|| "schema:Thing".to_string()),
+                    );
+                    object.attributes = window.to_vec();
+                    if variant & 2 == 2 {
+                        object.docstring = "A sample object.".to_string();
+                    }
+                    if variant & 4 == 4 {
+                        object.examples = vec![serde_json::json!({"attribute_0": "a"})];
+                    }
+
+                    // Act
+                    let markdown = object.to_markdown();
+                    let mut model = parse_markdown(&markdown).unwrap_or_else(|validator| {
+                        panic!("Failed to parse:\n{}\n{:?}", markdown, validator.errors)
+                    });
+
+                    // Assert
+                    let mut parsed = model.objects.remove(0);
+                    parsed.position = None;
+                    parsed.attributes.iter_mut().for_each(|a| a.position = None);
+                    assert_eq!(parsed, object, "Roundtrip failed for:\n{}", markdown);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_parent() {
+        // Arrange
+        let mut object = Object::new("Child".to_string(), Some("schema:Thing".to_string()));
+        object.parent = Some("Base".to_string());
+        object.attributes = sample_attributes()[..1].to_vec();
+
+        let mut base = Object::new("Base".to_string(), None);
+        base.attributes = sample_attributes()[1..2].to_vec();
+
+        // Act
+        let markdown = format!("{}\n{}", object.to_markdown(), base.to_markdown());
+        let model = parse_markdown(&markdown).expect("Failed to parse markdown");
+
+        // Assert
+        assert!(markdown.starts_with("### Child (schema:Thing) [Base]\n"));
+        let child = &model.objects[0];
+        assert_eq!(child.parent, Some("Base".to_string()));
+        assert_eq!(child.term, Some("schema:Thing".to_string()));
+        assert_eq!(child.attributes.len(), 2);
+    }
+
+    #[test]
+    fn test_attribute_filters() {
+        // Arrange
+        let model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse model");
+        let object = model.get_object("Test").expect("Object not found");
+
+        // Act
+        let floats: Vec<&str> = object
+            .attributes_by_dtype("float")
+            .map(|attr| attr.name.as_str())
+            .collect();
+        let arrays: Vec<&str> = object
+            .array_attributes()
+            .map(|attr| attr.name.as_str())
+            .collect();
+        let enums: Vec<&str> = object
+            .enum_attributes()
+            .map(|attr| attr.name.as_str())
+            .collect();
+
+        // Assert
+        assert_eq!(floats, vec!["number"]);
+        assert_eq!(arrays, vec!["test2"]);
+        assert_eq!(enums, vec!["ontology"]);
+    }
+
+    #[test]
+    fn test_has_only_primitives() {
+        // Arrange
+        let model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse model");
+
+        // Act
+        let test = model.get_object("Test").expect("Object not found");
+        let test2 = model.get_object("Test2").expect("Object not found");
+
+        // Assert
+        assert!(!test.has_only_primitives());
+        assert!(test2.has_only_primitives());
+    }
+
+    #[test]
+    fn test_split_attrs_by_required() {
+        // Arrange
+        let model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse model");
+        let object = model.get_object("Test").expect("Object not found");
+
+        // Act
+        let (required, optional) = object.split_attrs_by_required();
+
+        // Assert
+        let names = |attrs: &[&Attribute]| attrs.iter().map(|a| a.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&required), vec!["name"]);
+        assert_eq!(names(&optional), vec!["number", "test2", "ontology"]);
+        assert_eq!(object.required_attributes().collect::<Vec<_>>(), required);
+        assert_eq!(object.optional_attributes().collect::<Vec<_>>(), optional);
+    }
+
+    #[test]
+    fn test_attribute_metrics() {
+        // Arrange
+        let model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Failed to parse model");
+        let object = model.get_object("Test").expect("Object not found");
+
+        // Act & Assert
+        assert_eq!(object.attribute_count(), 4);
+        assert_eq!(object.required_count(), 1);
+        assert_eq!(object.optional_count(), 3);
+        assert_eq!(object.array_count(), 1);
+        assert_eq!(object.enum_attribute_count(), 1);
+        assert_eq!(object.coverage_ratio(), 0.25);
+    }
+
+    #[test]
+    fn test_attribute_metrics_empty_object() {
+        let object = Object::new("Empty".to_string(), None);
+
+        assert_eq!(object.attribute_count(), 0);
+        assert_eq!(object.required_count(), 0);
+        assert_eq!(object.optional_count(), 0);
+        assert_eq!(object.array_count(), 0);
+        assert_eq!(object.enum_attribute_count(), 0);
+        assert_eq!(object.coverage_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_enumeration_from_values() {
+        let enumeration = Enumeration::from_values("Status", &["active", "inactive"]);
+
+        assert_eq!(enumeration.name, "Status");
+        assert_eq!(
+            enumeration.mappings,
+            BTreeMap::from([
+                ("active".to_string(), "active".to_string()),
+                ("inactive".to_string(), "inactive".to_string()),
+            ])
+        );
+        assert_eq!(enumeration.docstring, "");
+        assert_eq!(enumeration.position, None);
+    }
+
+    #[test]
+    fn test_enumeration_from_mapping() {
+        let mut enumeration = Enumeration::from_mapping("Unit", &[("KELVIN", "K")]);
+        enumeration
+            .add_mapping("CELSIUS", "C")
+            .add_mapping("KELVIN", "kelvin");
+
+        assert_eq!(
+            enumeration.mappings,
+            BTreeMap::from([
+                ("CELSIUS".to_string(), "C".to_string()),
+                ("KELVIN".to_string(), "kelvin".to_string()),
+            ])
+        );
+        assert_eq!(enumeration.docstring, "");
+        assert_eq!(enumeration.position, None);
+    }
 }
@@ -0,0 +1,386 @@
+/*
+ * Copyright (c) 2024 Jan Range
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ *
+ */
+
+use std::error::Error;
+use std::path::Path;
+use std::str::FromStr;
+
+use convert_case::{Case, Casing};
+use serde_json::{json, Map, Value};
+use yaml_rust::{yaml::Hash, Yaml, YamlEmitter};
+
+use crate::attribute::Attribute;
+use crate::datamodel::DataModel;
+use crate::object::{Enumeration, Object};
+
+/// Version of the OpenAPI specification that is generated.
+const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Output format of an OpenAPI specification.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OpenApiFormat {
+    Json,
+    Yaml,
+}
+
+impl OpenApiFormat {
+    /// Determines the format from a file extension, defaulting to YAML.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The output path of the specification.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => OpenApiFormat::Json,
+            _ => OpenApiFormat::Yaml,
+        }
+    }
+}
+
+impl FromStr for OpenApiFormat {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(OpenApiFormat::Json),
+            "yaml" | "yml" => Ok(OpenApiFormat::Yaml),
+            _ => Err(format!("Invalid OpenAPI format: {}", s).into()),
+        }
+    }
+}
+
+/// Converts a data model into an OpenAPI 3.1 specification.
+///
+/// Every object becomes a component schema, references between objects use
+/// `$ref` and enumerations are inlined as `enum` arrays. Optional attributes
+/// are not listed as required and additionally accept `null`. CRUD operations
+/// for the root object are added under `paths`.
+///
+/// # Arguments
+///
+/// * `model` - The data model to convert.
+/// * `root` - Name of the object the paths are generated for.
+/// * `format` - Whether to render JSON or YAML.
+///
+/// # Returns
+///
+/// The rendered specification or an error if the root object does not exist.
+pub fn to_openapi(
+    model: &DataModel,
+    root: &str,
+    format: OpenApiFormat,
+) -> Result<String, Box<dyn Error>> {
+    if model.get_object(root).is_none() {
+        return Err(format!("Object '{}' not found in the model", root).into());
+    }
+
+    let schemas: Map<String, Value> = model
+        .objects
+        .iter()
+        .map(|object| (object.name.clone(), object_schema(object, &model.enums)))
+        .collect();
+
+    let version = model
+        .config()
+        .version
+        .clone()
+        .unwrap_or_else(|| "0.1.0".to_string());
+
+    let spec = json!({
+        "openapi": OPENAPI_VERSION,
+        "info": {
+            "title": model.name.clone().unwrap_or_else(|| root.to_string()),
+            "version": version,
+        },
+        "paths": crud_paths(root),
+        "components": {
+            "schemas": schemas,
+        },
+    });
+
+    match format {
+        OpenApiFormat::Json => Ok(serde_json::to_string_pretty(&spec)?),
+        OpenApiFormat::Yaml => {
+            let mut out = String::new();
+            YamlEmitter::new(&mut out).dump(&to_yaml(&spec))?;
+            Ok(out)
+        }
+    }
+}
+
+/// Builds the component schema of an object.
+fn object_schema(object: &Object, enums: &[Enumeration]) -> Value {
+    let mut schema = Map::new();
+    schema.insert("title".into(), json!(object.name));
+    schema.insert("type".into(), json!("object"));
+
+    if !object.docstring.is_empty() {
+        schema.insert("description".into(), json!(object.docstring));
+    }
+
+    let properties: Map<String, Value> = object
+        .attributes
+        .iter()
+        .map(|attribute| (attribute.name.clone(), attribute_schema(attribute, enums)))
+        .collect();
+    schema.insert("properties".into(), Value::Object(properties));
+
+    let required: Vec<&str> = object
+        .attributes
+        .iter()
+        .filter(|attribute| attribute.required)
+        .map(|attribute| attribute.name.as_str())
+        .collect();
+    if !required.is_empty() {
+        schema.insert("required".into(), json!(required));
+    }
+
+    Value::Object(schema)
+}
+
+/// Builds the schema of an attribute, including arrays and unions.
+fn attribute_schema(attribute: &Attribute, enums: &[Enumeration]) -> Value {
+    let mut variants: Vec<Value> = attribute
+        .dtypes
+        .iter()
+        .map(|dtype| dtype_schema(dtype, enums))
+        .collect();
+
+    let mut schema = match variants.len() {
+        1 => variants.remove(0),
+        _ => json!({ "oneOf": variants }),
+    };
+
+    if attribute.is_array {
+        schema = json!({ "type": "array", "items": schema });
+    } else if !attribute.required {
+        schema = nullable(schema);
+    }
+
+    if let Value::Object(map) = &mut schema {
+        if !attribute.docstring.is_empty() {
+            map.insert("description".into(), json!(attribute.docstring));
+        }
+        if attribute.deprecated {
+            map.insert("deprecated".into(), json!(true));
+        }
+    }
+
+    schema
+}
+
+/// Builds the schema of a single data type.
+fn dtype_schema(dtype: &str, enums: &[Enumeration]) -> Value {
+    if let Some(enumeration) = enums.iter().find(|e| e.name == dtype) {
+        return json!({
+            "type": "string",
+            "enum": enumeration.mappings.values().collect::<Vec<_>>(),
+        });
+    }
+
+    match dtype {
+        "string" => json!({ "type": "string" }),
+        "integer" => json!({ "type": "integer" }),
+        "float" | "number" => json!({ "type": "number" }),
+        "boolean" => json!({ "type": "boolean" }),
+        "date" => json!({ "type": "string", "format": "date" }),
+        "bytes" => json!({ "type": "string", "contentEncoding": "base64" }),
+        "decimal" => json!({ "type": "string", "format": "decimal" }),
+        "bigint" => json!({ "type": "integer", "format": "int64" }),
+        _ => json!({ "$ref": format!("#/components/schemas/{}", dtype) }),
+    }
+}
+
+/// Allows `null` for a schema, as OpenAPI 3.1 no longer supports `nullable`.
+fn nullable(schema: Value) -> Value {
+    match schema {
+        Value::Object(mut map) if map.get("type").is_some_and(Value::is_string) => {
+            let dtype = map.remove("type").unwrap();
+
+            if let Some(Value::Array(values)) = map.get_mut("enum") {
+                values.push(Value::Null);
+            }
+
+            let mut nullable = Map::new();
+            nullable.insert("type".into(), json!([dtype, "null"]));
+            nullable.extend(map);
+            Value::Object(nullable)
+        }
+        schema => json!({ "oneOf": [schema, { "type": "null" }] }),
+    }
+}
+
+/// Builds the CRUD path items of the root object.
+fn crud_paths(root: &str) -> Value {
+    let collection = format!("/{}", root.to_case(Case::Kebab));
+    let item = format!("{}/{{id}}", collection);
+    let reference = json!({ "$ref": format!("#/components/schemas/{}", root) });
+
+    let body = json!({
+        "required": true,
+        "content": { "application/json": { "schema": reference } },
+    });
+    let ok = |description: &str, schema: &Value| {
+        json!({
+            "description": description,
+            "content": { "application/json": { "schema": schema } },
+        })
+    };
+    let id_parameter = json!([{
+        "name": "id",
+        "in": "path",
+        "required": true,
+        "schema": { "type": "string" },
+    }]);
+    let not_found = json!({ "description": format!("{} not found", root) });
+
+    json!({
+        collection: {
+            "get": {
+                "operationId": format!("list{}", root),
+                "responses": {
+                    "200": ok(
+                        &format!("List of {}", root),
+                        &json!({ "type": "array", "items": reference }),
+                    ),
+                },
+            },
+            "post": {
+                "operationId": format!("create{}", root),
+                "requestBody": body,
+                "responses": {
+                    "201": ok(&format!("Created {}", root), &reference),
+                },
+            },
+        },
+        item: {
+            "parameters": id_parameter,
+            "get": {
+                "operationId": format!("get{}", root),
+                "responses": {
+                    "200": ok(root, &reference),
+                    "404": not_found,
+                },
+            },
+            "put": {
+                "operationId": format!("update{}", root),
+                "requestBody": body,
+                "responses": {
+                    "200": ok(&format!("Updated {}", root), &reference),
+                    "404": not_found,
+                },
+            },
+            "delete": {
+                "operationId": format!("delete{}", root),
+                "responses": {
+                    "204": { "description": format!("Deleted {}", root) },
+                    "404": not_found,
+                },
+            },
+        },
+    })
+}
+
+/// Converts a JSON value into a YAML value, preserving the key order.
+fn to_yaml(value: &Value) -> Yaml {
+    match value {
+        Value::Null => Yaml::Null,
+        Value::Bool(b) => Yaml::Boolean(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => Yaml::Integer(i),
+            None => Yaml::Real(n.to_string()),
+        },
+        Value::String(s) => Yaml::String(s.clone()),
+        Value::Array(values) => Yaml::Array(values.iter().map(to_yaml).collect()),
+        Value::Object(map) => {
+            let mut hash = Hash::new();
+            for (key, value) in map {
+                hash.insert(Yaml::String(key.clone()), to_yaml(value));
+            }
+            Yaml::Hash(hash)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+
+    fn model() -> DataModel {
+        DataModel::from_markdown(Path::new("tests/data/model.md")).expect("Failed to parse model")
+    }
+
+    #[test]
+    fn test_to_openapi_json() {
+        // Arrange
+        let model = model();
+        let expected = fs::read_to_string("tests/data/expected_openapi.json")
+            .expect("Could not read expected file");
+
+        // Act
+        let spec = to_openapi(&model, "Test", OpenApiFormat::Json).expect("Failed to convert");
+
+        // Assert
+        assert_eq!(spec, expected);
+    }
+
+    #[test]
+    fn test_to_openapi_yaml() {
+        // Arrange
+        let model = model();
+
+        // Act
+        let spec = to_openapi(&model, "Test", OpenApiFormat::Yaml).expect("Failed to convert");
+
+        // Assert
+        assert!(spec.contains("openapi: 3.1.0"));
+        assert!(spec.contains("\"/test/{id}\":"));
+        assert!(spec.contains("$ref: \"#/components/schemas/Test2\""));
+    }
+
+    #[test]
+    fn test_to_openapi_unknown_root() {
+        // Arrange
+        let model = model();
+
+        // Act
+        let result = to_openapi(&model, "Unknown", OpenApiFormat::Json);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_nullable() {
+        assert_eq!(
+            nullable(json!({ "type": "string" })),
+            json!({ "type": ["string", "null"] })
+        );
+        assert_eq!(
+            nullable(json!({ "$ref": "#/components/schemas/Test" })),
+            json!({ "oneOf": [{ "$ref": "#/components/schemas/Test" }, { "type": "null" }] })
+        );
+    }
+}
@@ -21,12 +21,17 @@
  *
  */
 
-use crate::{datamodel::DataModel, exporters::Templates};
+use crate::{
+    datamodel::DataModel,
+    exporters::{TemplateConfig, Templates},
+    markdown::frontmatter::FrontMatter,
+    openapi::OpenApiFormat,
+};
 use colored::Colorize;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap, HashSet},
     error::Error,
     fs,
     path::{Path, PathBuf},
@@ -37,6 +42,7 @@ use std::{
 #[derive(Debug, Serialize, Deserialize)]
 struct GenTemplate {
     meta: Meta,
+    #[serde(default)]
     generate: HashMap<String, GenSpecs>,
 }
 
@@ -52,6 +58,41 @@ impl GenTemplate {
             .iter_mut()
             .map(|spec| path.join(spec))
             .collect();
+
+        self.meta.include = self
+            .meta
+            .include
+            .iter_mut()
+            .map(|include| path.join(include))
+            .collect();
+    }
+
+    /// Merges an included template into this template.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The included template.
+    /// * `source` - Path to the included template file, used for error messages.
+    ///
+    /// # Returns
+    ///
+    /// A Result indicating success or an error if a generation target is defined twice.
+    pub fn merge(&mut self, other: GenTemplate, source: &Path) -> Result<(), Box<dyn Error>> {
+        for (name, specs) in other.generate {
+            if self.generate.contains_key(&name) {
+                return Err(format!(
+                    "Generation target '{}' from included file {:?} is already defined",
+                    name, source
+                )
+                .into());
+            }
+
+            self.generate.insert(name, specs);
+        }
+
+        self.meta.paths.extend(other.meta.paths);
+
+        Ok(())
     }
 }
 
@@ -60,7 +101,10 @@ impl GenTemplate {
 struct Meta {
     name: Option<String>,
     description: Option<String>,
+    #[serde(default)]
     paths: Vec<PathBuf>,
+    #[serde(default)]
+    include: Vec<PathBuf>,
 }
 
 /// Represents generation specifications for a template.
@@ -85,6 +129,19 @@ impl GenSpecs {
     }
 }
 
+/// A single generation step of a pipeline, as reported by a dry run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipelineAction {
+    /// The template used for the generation.
+    pub template: Templates,
+    /// The markdown models that are read.
+    pub input_paths: Vec<PathBuf>,
+    /// The file or directory that is written.
+    pub output_path: PathBuf,
+    /// Whether the input models are merged into a single output.
+    pub merge: bool,
+}
+
 /// Sate that determines whether objects are merged or not.
 #[derive(Debug)]
 enum MergeState {
@@ -102,6 +159,23 @@ impl From<bool> for MergeState {
     }
 }
 
+/// Error returned by `process_pipeline` listing all failed generation steps
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PipelineErrors(pub Vec<(String, String)>);
+
+impl std::fmt::Display for PipelineErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} generation step(s) failed:", self.0.len())?;
+        for (name, message) in &self.0 {
+            writeln!(f, "  {}: {}", name, message)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for PipelineErrors {}
+
 /// Processes the pipeline by reading the template file, building the data model, and generating files based on the specifications.
 ///
 /// # Arguments
@@ -110,134 +184,408 @@ impl From<bool> for MergeState {
 ///
 /// # Returns
 ///
+/// A Result indicating success or failure. Failing generation steps do not
+/// abort the pipeline; their errors are collected and returned as
+/// [`PipelineErrors`] once all steps have been attempted. Missing input files
+/// abort the pipeline before anything is generated.
+pub fn process_pipeline(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let gen_template = load_template(path, &mut Vec::new())?;
+    let paths = gen_template.meta.paths.as_slice();
+
+    for path in paths {
+        path_exists(path)?;
+    }
+
+    let generate: BTreeMap<String, GenSpecs> = gen_template.generate.into_iter().collect();
+    let mut errors: Vec<(String, Box<dyn Error>)> = Vec::new();
+
+    for (name, specs) in generate {
+        if let Err(error) = process_generate(&name, specs, paths) {
+            errors.push((name, error));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(PipelineErrors(
+            errors
+                .into_iter()
+                .map(|(name, error)| (name, error.to_string()))
+                .collect(),
+        )
+        .into())
+    }
+}
+
+/// Runs a single generation step of a pipeline.
+///
+/// # Arguments
+///
+/// * `name` - Name of the template to render.
+/// * `specs` - Generation specifications of the template.
+/// * `paths` - Paths to the markdown model files.
+///
+/// # Returns
+///
 /// A Result indicating success or failure.
-pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(path)?;
-    let mut gen_template: GenTemplate = toml::from_str(content.as_str()).unwrap();
+fn process_generate(name: &str, specs: GenSpecs, paths: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+    let template = Templates::from_str(name)?;
+    let merge_state = MergeState::from(specs.per_spec.unwrap_or(false));
+    let mut config = TemplateConfig::from_hashmap(&specs.config);
 
-    if let Some(parent) = path.parent() {
-        gen_template.prepend_root(parent);
+    match template {
+        Templates::JsonSchema => {
+            let mut model = build_models(paths)?;
+            apply_json_schema_base_uri(&mut model, &config);
+            serialize_to_json_schema(model, specs.root, &specs.out, &merge_state, &config)?;
+        }
+        Templates::JsonSchemaAll => {
+            serialize_all_json_schemes(&specs.out, paths, &merge_state, &config)?;
+        }
+        Templates::OpenApi => {
+            let model = build_models(paths)?;
+            serialize_to_openapi(model, specs.root, &specs.out, &merge_state)?;
+        }
+        Templates::Shex => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::Shacl => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::Markdown => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::CompactMarkdown => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::PythonDataclass => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::PythonPydantic => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::PythonPydanticXML => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::PythonAttrs => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::XmlSchema => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::Typescript
+        | Templates::TypescriptInterface
+        | Templates::Kotlin
+        | Templates::CSharp
+        | Templates::Avro
+        | Templates::Scala
+        | Templates::PlantUml
+        | Templates::MermaidErd => {
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::MkDocs => {
+            // If the template is not set to merge, then disable the navigation.
+            if let MergeState::Merge = merge_state {
+                if !specs.config.contains_key("nav") {
+                    config.nav = false;
+                }
+            }
+
+            serialize_by_template(&specs.out, paths, &merge_state, &template, Some(&config))?;
+        }
+        Templates::Internal => {
+            let model = build_models(paths)?;
+            serialize_to_internal_schema(model, &specs.out, &merge_state)?;
+        }
+        Templates::JsonLdContext => {
+            let model = build_models(paths)?;
+            serialize_to_jsonld_context(model, &specs.out, &merge_state)?;
+        }
     }
 
-    let paths = gen_template.meta.paths.as_slice();
+    Ok(())
+}
+
+/// Lists the actions of a pipeline without rendering or writing any files.
+///
+/// The pipeline is validated first, hence unknown templates or missing input
+/// paths result in an error. Actions are ordered by their template name.
+///
+/// # Arguments
+///
+/// * `path` - Path to the template file.
+///
+/// # Returns
+///
+/// A Result containing the actions of the pipeline or an error.
+pub fn dry_run_pipeline(path: &Path) -> Result<Vec<PipelineAction>, Box<dyn Error>> {
+    validate_pipeline(path).map_err(|errors| errors.join("\n"))?;
+
+    let gen_template = load_template(path, &mut Vec::new())?;
+    let paths = gen_template.meta.paths;
+    let generate: BTreeMap<String, GenSpecs> = gen_template.generate.into_iter().collect();
 
-    for (name, mut specs) in gen_template.generate.into_iter() {
+    let mut actions = Vec::new();
+    for (name, specs) in generate {
         let template = Templates::from_str(name.as_str())?;
-        let merge_state = MergeState::from(specs.per_spec.unwrap_or(false));
 
-        match template {
-            Templates::JsonSchema => {
-                let model = build_models(paths)?;
-                serialize_to_json_schema(model, specs.root, &specs.out, &merge_state)?;
-            }
-            Templates::JsonSchemaAll => {
-                serialize_all_json_schemes(&specs.out, paths, &merge_state)?;
-            }
-            Templates::Shex => {
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
-            }
-            Templates::Shacl => {
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
-            }
-            Templates::Markdown => {
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
+        if !specs.per_spec.unwrap_or(false) {
+            actions.push(PipelineAction {
+                template,
+                input_paths: paths.clone(),
+                output_path: specs.out,
+                merge: true,
+            });
+            continue;
+        }
+
+        for spec in &paths {
+            let output_path = match template {
+                Templates::JsonSchemaAll => specs.out.join(get_file_name(spec)),
+                _ => replace_wildcard_fname(&specs.out, get_file_name(spec).as_str()),
+            };
+
+            actions.push(PipelineAction {
+                template: template.clone(),
+                input_paths: vec![spec.clone()],
+                output_path,
+                merge: false,
+            });
+        }
+    }
+
+    Ok(actions)
+}
+
+/// Checks the structure of a pipeline without building any models.
+///
+/// All problems are collected, such as unknown template names, missing input
+/// paths and output paths that do not fit the generation mode.
+///
+/// # Arguments
+///
+/// * `path` - Path to the template file.
+///
+/// # Returns
+///
+/// A Result indicating success or containing all found problems.
+pub fn validate_pipeline(path: &Path) -> Result<(), Vec<String>> {
+    let gen_template = load_template(path, &mut Vec::new()).map_err(|e| vec![e.to_string()])?;
+    let mut errors = Vec::new();
+
+    if gen_template.meta.paths.is_empty() {
+        errors.push("No input paths are defined in 'meta.paths'".to_string());
+    }
+
+    for input in &gen_template.meta.paths {
+        if !input.exists() {
+            errors.push(format!("Input path does not exist: {:?}", input));
+        }
+    }
+
+    let generate: BTreeMap<&String, &GenSpecs> = gen_template.generate.iter().collect();
+    for (name, specs) in generate {
+        let template = match Templates::from_str(name) {
+            Ok(template) => template,
+            Err(_) => {
+                errors.push(format!("Unknown template '{}'", name));
+                continue;
             }
-            Templates::CompactMarkdown => {
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
+        };
+
+        let per_spec = specs.per_spec.unwrap_or(false);
+        match template {
+            Templates::JsonSchema
+            | Templates::Internal
+            | Templates::OpenApi
+            | Templates::JsonLdContext
+                if per_spec =>
+            {
+                errors.push(format!("Template '{}' does not support 'per-spec'", name));
             }
-            Templates::PythonDataclass => {
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
+            Templates::JsonSchema if specs.root.is_none() => {
+                errors.push(format!("Template '{}' requires a 'root' object", name));
             }
-            Templates::PythonPydantic => {
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
+            Templates::JsonSchemaAll if specs.out.is_file() => {
+                errors.push(format!(
+                    "Output path {:?} of '{}' must be a directory",
+                    specs.out, name
+                ));
             }
-            Templates::PythonPydanticXML => {
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
+            Templates::JsonSchema
+            | Templates::JsonSchemaAll
+            | Templates::Internal
+            | Templates::OpenApi
+            | Templates::JsonLdContext => {}
+            _ if per_spec && !has_wildcard_fname(&specs.out) => {
+                errors.push(format!(
+                    "Output path {:?} of '{}' must contain a wildcard file name, e.g. 'path/to/*.json'",
+                    specs.out, name
+                ));
             }
-            Templates::XmlSchema => {
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
+            _ => {}
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Loads a template file and recursively merges all templates listed in `meta.include`.
+///
+/// Files listed in a top-level `$include` array are deep-merged into the document first,
+/// see [`load_toml_document`]. The two mechanisms differ in how they combine templates:
+///
+/// * `$include` merges the raw documents before they are interpreted, so the including file
+///   may override any value of an included file, including single generation targets.
+/// * `meta.include` loads every listed file as a complete template (with its own `$include`s
+///   already applied) and only adds its `paths` and `generate` targets. Defining a target
+///   that already exists is an error.
+///
+/// Paths of each template are resolved relative to the directory of the file they are defined in.
+///
+/// # Arguments
+///
+/// * `path` - Path to the template file.
+/// * `stack` - Canonical paths of the templates currently being loaded, used to detect cycles.
+///
+/// # Returns
+///
+/// A Result containing the combined GenTemplate or an error.
+fn load_template(path: &Path, stack: &mut Vec<PathBuf>) -> Result<GenTemplate, Box<dyn Error>> {
+    let canonical = fs::canonicalize(path)
+        .map_err(|e| format!("Could not read template file {:?}: {}", path, e))?;
+
+    if stack.contains(&canonical) {
+        return Err(format!("Template file {:?} includes itself", path).into());
+    }
+
+    let document = load_toml_document(path, &mut HashSet::new())?;
+    let mut gen_template: GenTemplate = toml::Value::Table(document).try_into()?;
+
+    if let Some(parent) = path.parent() {
+        gen_template.prepend_root(parent);
+    }
+
+    stack.push(canonical);
+    for include in std::mem::take(&mut gen_template.meta.include) {
+        let included = load_template(&include, stack)?;
+        gen_template.merge(included, &include)?;
+    }
+    stack.pop();
+
+    Ok(gen_template)
+}
+
+/// Reads a TOML file and deep-merges all files listed in its top-level `$include` array.
+///
+/// Included files are merged in the given order and the including file is merged last,
+/// so its values take precedence. Include paths are resolved relative to the directory of
+/// the including file. Paths defined within an included file are rebased onto the directory
+/// of the including file, so that every path is resolved relative to the file it is defined in.
+///
+/// # Arguments
+///
+/// * `path` - Path to the TOML file.
+/// * `visited` - Canonical paths of the files currently being loaded, used to detect cycles.
+///
+/// # Returns
+///
+/// A Result containing the merged TOML table or an error.
+fn load_toml_document(
+    path: &Path,
+    visited: &mut HashSet<PathBuf>,
+) -> Result<toml::Table, Box<dyn Error>> {
+    let canonical = fs::canonicalize(path)
+        .map_err(|e| format!("Could not read template file {:?}: {}", path, e))?;
+
+    if visited.contains(&canonical) {
+        return Err(format!("Template file {:?} includes itself", path).into());
+    }
+
+    let content = fs::read_to_string(path)?;
+    let mut document: toml::Table = toml::from_str(content.as_str())?;
+
+    let includes = match document.remove("$include") {
+        None => Vec::new(),
+        Some(toml::Value::Array(includes)) => includes,
+        Some(_) => {
+            return Err(format!("'$include' in {:?} must be an array of paths", path).into())
+        }
+    };
+
+    let root = path.parent().unwrap_or(Path::new(""));
+    let mut merged = toml::Table::new();
+
+    visited.insert(canonical.clone());
+    for include in includes {
+        let toml::Value::String(include) = include else {
+            return Err(format!("'$include' in {:?} must be an array of paths", path).into());
+        };
+
+        let mut included = load_toml_document(&root.join(&include), visited)?;
+        if let Some(include_dir) = Path::new(&include).parent() {
+            rebase_paths(&mut included, include_dir);
+        }
+
+        deep_merge(&mut merged, included);
+    }
+    visited.remove(&canonical);
+
+    deep_merge(&mut merged, document);
+
+    Ok(merged)
+}
+
+/// Prepends `dir` to all paths of a raw template document.
+///
+/// Affected are `meta.paths`, `meta.include` and the `out` path of every generation target.
+fn rebase_paths(document: &mut toml::Table, dir: &Path) {
+    if dir.as_os_str().is_empty() {
+        return;
+    }
+
+    let rebase = |value: &mut toml::Value| {
+        if let toml::Value::String(path) = value {
+            *path = dir.join(&*path).to_string_lossy().to_string();
+        }
+    };
+
+    if let Some(toml::Value::Table(meta)) = document.get_mut("meta") {
+        for key in ["paths", "include"] {
+            if let Some(toml::Value::Array(paths)) = meta.get_mut(key) {
+                paths.iter_mut().for_each(rebase);
             }
-            Templates::Typescript => {
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
+        }
+    }
+
+    if let Some(toml::Value::Table(generate)) = document.get_mut("generate") {
+        for (_, specs) in generate.iter_mut() {
+            if let Some(out) = specs.get_mut("out") {
+                rebase(out);
             }
-            Templates::MkDocs => {
-                // If the template is not set to merge, then disable the navigation.
-                if let MergeState::Merge = merge_state {
-                    if !specs.config.contains_key("nav") {
-                        specs.config.insert("nav".to_string(), "false".to_string());
-                    }
-                }
+        }
+    }
+}
 
-                serialize_by_template(
-                    &specs.out,
-                    paths,
-                    &merge_state,
-                    &template,
-                    Some(&specs.config),
-                )?;
+/// Recursively merges `other` into `base`.
+///
+/// Nested tables are merged key by key, all other values of `other` replace those of `base`.
+fn deep_merge(base: &mut toml::Table, other: toml::Table) {
+    for (key, value) in other {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(other_table)) => {
+                deep_merge(base_table, other_table);
             }
-            Templates::Internal => {
-                let model = build_models(paths)?;
-                serialize_to_internal_schema(model, &specs.out, &merge_state)?;
+            (_, value) => {
+                base.insert(key, value);
             }
         }
     }
-
-    Ok(())
 }
 
 /// Builds the data model by reading and merging multiple paths.
@@ -265,7 +613,9 @@ fn build_models(paths: &[PathBuf]) -> Result<DataModel, Box<dyn Error>> {
     for path in paths.iter().skip(1) {
         path_exists(path)?;
         let new_model = DataModel::from_markdown(path)?;
-        model.merge(&new_model);
+        model
+            .merge_strict(&new_model)
+            .map_err(|e| format!("Could not merge {:?}: {}", path, e))?;
     }
 
     Ok(model)
@@ -294,6 +644,8 @@ fn path_exists(path: &PathBuf) -> Result<(), Box<dyn Error>> {
 /// * `model` - The DataModel to serialize.
 /// * `root` - The root object for the JSON schema.
 /// * `out` - The output path for the JSON schema file.
+/// * `merge_state` - The merge state.
+/// * `config` - The configuration of the generation specification.
 ///
 /// # Returns
 ///
@@ -303,6 +655,7 @@ fn serialize_to_json_schema(
     root: Option<String>,
     out: &PathBuf,
     merge_state: &MergeState,
+    config: &TemplateConfig,
 ) -> Result<(), Box<dyn Error>> {
     if let MergeState::NoMerge = merge_state {
         return Err(
@@ -312,7 +665,12 @@ fn serialize_to_json_schema(
 
     match root {
         Some(root) => {
-            let schema = model.json_schema(Some(root), false)?;
+            let schema = model.json_schema_with_draft(
+                Some(root),
+                false,
+                config.draft(),
+                config.inheritance(),
+            )?;
             save_to_file(out, &schema)?;
             print_render_msg(out, &Templates::JsonSchema);
             Ok(())
@@ -321,6 +679,36 @@ fn serialize_to_json_schema(
     }
 }
 
+/// Serializes the data model to an OpenAPI specification.
+///
+/// The specification is written as JSON if the output has a `.json` extension
+/// and as YAML otherwise.
+///
+/// # Arguments
+///
+/// * `model` - The DataModel to serialize.
+/// * `root` - The object for which CRUD paths are generated.
+/// * `out` - The output path for the specification.
+///
+/// # Returns
+///
+/// A Result indicating success or failure.
+fn serialize_to_openapi(
+    model: DataModel,
+    root: Option<String>,
+    out: &PathBuf,
+    merge_state: &MergeState,
+) -> Result<(), Box<dyn Error>> {
+    if let MergeState::NoMerge = merge_state {
+        return Err("Per spec is not supported for OpenAPI generation at the moment.".into());
+    }
+
+    let spec = model.to_openapi(root, OpenApiFormat::from_path(out))?;
+    save_to_file(out, &spec)?;
+    print_render_msg(out, &Templates::OpenApi);
+    Ok(())
+}
+
 /// Serializes the data model to the internal schema.
 ///
 /// Please note, this format may only be used for internal purposes.
@@ -351,12 +739,42 @@ fn serialize_to_internal_schema(
     }
 }
 
-/// Serializes all JSON schemas for the data model to the specified output directory.
+/// Serializes the data model to a standalone JSON-LD context.
 ///
 /// # Arguments
 ///
 /// * `model` - The DataModel to serialize.
+/// * `out` - The output path for the JSON-LD context file.
+/// * `merge_state` - The merge state.
+///
+/// # Returns
+///
+/// A Result indicating success or failure.
+fn serialize_to_jsonld_context(
+    model: DataModel,
+    out: &PathBuf,
+    merge_state: &MergeState,
+) -> Result<(), Box<dyn Error>> {
+    if let MergeState::NoMerge = merge_state {
+        return Err(
+            "Per spec is not supported for JSON-LD context generation at the moment.".into(),
+        );
+    }
+
+    let context = model.to_jsonld_context()?;
+    save_to_file(out, &context)?;
+    print_render_msg(out, &Templates::JsonLdContext);
+    Ok(())
+}
+
+/// Serializes all JSON schemas for the data model to the specified output directory.
+///
+/// # Arguments
+///
 /// * `out` - The output directory for the JSON schema files.
+/// * `specs` - A slice of PathBuf representing the paths to read.
+/// * `merge_state` - The merge state.
+/// * `config` - The configuration of the generation specification.
 ///
 /// # Returns
 ///
@@ -365,6 +783,7 @@ fn serialize_all_json_schemes(
     out: &PathBuf,
     specs: &[PathBuf],
     merge_state: &MergeState,
+    config: &TemplateConfig,
 ) -> Result<(), Box<dyn Error>> {
     if out.is_file() {
         return Err("Output path is a file".into());
@@ -375,16 +794,28 @@ fn serialize_all_json_schemes(
 
     match merge_state {
         MergeState::Merge => {
-            let model = build_models(specs)?;
-            model.json_schema_all(out.to_path_buf(), false)?;
+            let mut model = build_models(specs)?;
+            apply_json_schema_base_uri(&mut model, config);
+            model.json_schema_all_with_draft(
+                out.to_path_buf(),
+                false,
+                config.draft(),
+                config.inheritance(),
+            )?;
             print_render_msg(out, &Templates::JsonSchemaAll);
             Ok(())
         }
         MergeState::NoMerge => {
             for spec in specs {
-                let model = DataModel::from_markdown(spec)?;
+                let mut model = DataModel::from_markdown(spec)?;
+                apply_json_schema_base_uri(&mut model, config);
                 let path = out.join(get_file_name(spec));
-                model.json_schema_all(path.to_path_buf(), false)?;
+                model.json_schema_all_with_draft(
+                    path.to_path_buf(),
+                    false,
+                    config.draft(),
+                    config.inheritance(),
+                )?;
                 print_render_msg(&path, &Templates::JsonSchemaAll);
             }
             Ok(())
@@ -392,6 +823,18 @@ fn serialize_all_json_schemes(
     }
 }
 
+/// Overrides the base URI of the JSON schema `$id` values, if `json_schema_base_uri` is configured.
+///
+/// # Arguments
+///
+/// * `model` - The DataModel to update.
+/// * `config` - The configuration of the generation specification.
+fn apply_json_schema_base_uri(model: &mut DataModel, config: &TemplateConfig) {
+    if let Some(base_uri) = &config.json_schema_base_uri {
+        model.config.get_or_insert_with(FrontMatter::default).repo = base_uri.clone();
+    }
+}
+
 /// Serializes the data model by the specified template.
 ///
 /// # Arguments
@@ -409,7 +852,7 @@ fn serialize_by_template(
     specs: &[PathBuf],
     merge_state: &MergeState,
     template: &Templates,
-    config: Option<&HashMap<String, String>>,
+    config: Option<&TemplateConfig>,
 ) -> Result<(), Box<dyn Error>> {
     match merge_state {
         MergeState::Merge => {
@@ -554,6 +997,29 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_apply_json_schema_base_uri() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model.md")).unwrap();
+        let config = TemplateConfig {
+            json_schema_base_uri: Some("https://example.org/schemas".to_string()),
+            ..Default::default()
+        };
+
+        // Act
+        apply_json_schema_base_uri(&mut model, &config);
+
+        // Assert
+        let schema: serde_json::Value =
+            serde_json::from_str(&model.json_schema(Some("Test".to_string()), false).unwrap())
+                .unwrap();
+        assert_eq!(schema["$id"], "https://example.org/schemas/Test");
+        assert_eq!(
+            schema["$defs"]["Test2"]["$id"],
+            "https://example.org/schemas/Test2"
+        );
+    }
+
     #[test]
     fn test_prepend_root() {
         let mut gen_template = GenTemplate {
@@ -561,6 +1027,7 @@ mod tests {
                 name: None,
                 description: None,
                 paths: vec![PathBuf::from("model.md")],
+                include: vec![PathBuf::from("other.toml")],
             },
             generate: HashMap::from_iter(vec![(
                 "json-schema".to_string(),
@@ -581,9 +1048,230 @@ mod tests {
             gen_template.meta.paths[0],
             PathBuf::from("tests/data/model.md")
         );
+        assert_eq!(
+            gen_template.meta.include[0],
+            PathBuf::from("tests/data/other.toml")
+        );
         assert_eq!(
             gen_template.generate["json-schema"].out,
             PathBuf::from("tests/data/schema.json")
         );
     }
+
+    #[test]
+    fn test_load_template_include() {
+        // Arrange
+        let monolithic = Path::new("tests/data/pipeline/monolithic.toml");
+        let main = Path::new("tests/data/pipeline/main.toml");
+
+        // Act
+        let expected = load_template(monolithic, &mut Vec::new()).unwrap();
+        let combined = load_template(main, &mut Vec::new()).unwrap();
+
+        // Assert
+        let canonical = |paths: &[PathBuf]| {
+            paths
+                .iter()
+                .map(|path| fs::canonicalize(path).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            canonical(&combined.meta.paths),
+            canonical(&expected.meta.paths)
+        );
+
+        let mut names = combined.generate.keys().collect::<Vec<_>>();
+        names.sort();
+        let mut expected_names = expected.generate.keys().collect::<Vec<_>>();
+        expected_names.sort();
+        assert_eq!(names, expected_names);
+
+        for (name, specs) in &expected.generate {
+            let included = &combined.generate[name];
+            assert_eq!(included.out, specs.out);
+            assert_eq!(included.root, specs.root);
+            assert_eq!(included.config, specs.config);
+        }
+    }
+
+    #[test]
+    fn test_load_template_include_collision() {
+        // Arrange
+        let path = Path::new("tests/data/pipeline/collision.toml");
+
+        // Act
+        let result = load_template(path, &mut Vec::new());
+
+        // Assert
+        let error = result.expect_err("Expected a collision error").to_string();
+        assert!(error.contains("'python-dataclass'"), "{}", error);
+    }
+
+    #[test]
+    fn test_load_template_include_cycle() {
+        // Arrange
+        let path = Path::new("tests/data/pipeline/cycle.toml");
+
+        // Act
+        let result = load_template(path, &mut Vec::new());
+
+        // Assert
+        let error = result.expect_err("Expected a cycle error").to_string();
+        assert!(error.contains("includes itself"), "{}", error);
+    }
+
+    #[test]
+    fn test_load_template_dollar_include() {
+        // Arrange
+        let path = Path::new("tests/data/pipeline/dollar_include.toml");
+
+        // Act
+        let gen_template = load_template(path, &mut Vec::new()).unwrap();
+
+        // Assert
+        let root = Path::new("tests/data/pipeline");
+        assert_eq!(gen_template.meta.name, Some("Dollar Include".to_string()));
+        assert_eq!(
+            gen_template.meta.paths,
+            vec![root.join("base/../../model.md")]
+        );
+
+        let mut names = gen_template.generate.keys().collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, vec!["json-schema", "python-dataclass", "typescript"]);
+
+        let schema = &gen_template.generate["json-schema"];
+        assert_eq!(schema.out, root.join("gen/override.json"));
+        assert_eq!(schema.root, Some("Test".to_string()));
+        assert_eq!(
+            gen_template.generate["python-dataclass"].out,
+            root.join("base/gen/base.py")
+        );
+        assert_eq!(
+            gen_template.generate["typescript"].out,
+            root.join("base/gen/extra.ts")
+        );
+    }
+
+    #[test]
+    fn test_load_template_dollar_include_cycle() {
+        // Arrange
+        let path = Path::new("tests/data/pipeline/dollar_cycle.toml");
+
+        // Act
+        let result = load_template(path, &mut Vec::new());
+
+        // Assert
+        let error = result.expect_err("Expected a cycle error").to_string();
+        assert!(error.contains("includes itself"), "{}", error);
+    }
+
+    #[test]
+    fn test_dry_run_pipeline() {
+        // Arrange
+        let path = Path::new("tests/test_pipeline_per_spec.toml");
+
+        // Act
+        let actions = dry_run_pipeline(path).expect("Dry run failed");
+
+        // Assert
+        assert_eq!(actions.len(), 14);
+        assert!(actions.iter().all(|action| !action.merge));
+
+        let xsd: Vec<&PipelineAction> = actions
+            .iter()
+            .filter(|action| action.template == Templates::XmlSchema)
+            .collect();
+        assert_eq!(
+            xsd[0].output_path,
+            PathBuf::from("tests/intermediates/merge/schemes/model.xsd")
+        );
+        assert_eq!(
+            xsd[1].input_paths,
+            vec![PathBuf::from("tests/data/model_merge.md")]
+        );
+    }
+
+    #[test]
+    fn test_dry_run_pipeline_merge() {
+        // Arrange
+        let path = Path::new("tests/test_pipeline.toml");
+
+        // Act
+        let actions = dry_run_pipeline(path).expect("Dry run failed");
+
+        // Assert
+        assert_eq!(actions.len(), 9);
+        assert!(actions.iter().all(|action| action.merge));
+        assert_eq!(actions[0].template, Templates::CompactMarkdown);
+        assert_eq!(
+            actions[0].output_path,
+            PathBuf::from("tests/intermediates/non_merge/compact-model.md")
+        );
+    }
+
+    #[test]
+    fn test_process_pipeline_collects_errors() {
+        // Arrange
+        let dir = tempfile::tempdir().expect("Could not create temporary directory");
+        let model = fs::canonicalize("tests/data/model.md").expect("Could not resolve model");
+        let path = dir.path().join("pipeline.toml");
+        let content = format!(
+            r#"
+            [meta]
+            paths = [{:?}]
+
+            [generate.json-schema]
+            out = "gen/schema.json"
+
+            [generate.python-dataclass]
+            out = "gen/lib.py"
+            "#,
+            model
+        );
+        fs::write(&path, content).expect("Could not write pipeline");
+
+        // Act
+        let error = process_pipeline(&path).expect_err("Expected pipeline errors");
+
+        // Assert
+        let errors = error
+            .downcast_ref::<PipelineErrors>()
+            .expect("Expected PipelineErrors");
+        assert_eq!(errors.0.len(), 1, "{}", errors);
+        assert_eq!(errors.0[0].0, "json-schema");
+        assert!(errors.0[0].1.contains("Root object has to be specified"));
+        assert!(errors.to_string().contains("json-schema"));
+        assert!(dir.path().join("gen/lib.py").exists());
+    }
+
+    #[test]
+    fn test_process_pipeline_missing_input() {
+        // Arrange
+        let path = Path::new("tests/data/pipeline/invalid.toml");
+
+        // Act
+        let error = process_pipeline(path).expect_err("Expected a pre-flight error");
+
+        // Assert
+        assert!(error.downcast_ref::<PipelineErrors>().is_none());
+        assert!(error.to_string().contains("does_not_exist.md"));
+    }
+
+    #[test]
+    fn test_validate_pipeline() {
+        // Arrange
+        let path = Path::new("tests/data/pipeline/invalid.toml");
+
+        // Act
+        let errors = validate_pipeline(path).expect_err("Expected validation errors");
+
+        // Assert
+        assert_eq!(errors.len(), 4, "{:#?}", errors);
+        assert!(errors[0].contains("does_not_exist.md"));
+        assert!(errors[1].contains("requires a 'root' object"));
+        assert!(errors[2].contains("must contain a wildcard"));
+        assert!(errors[3].contains("Unknown template 'unknown-template'"));
+        assert!(dry_run_pipeline(path).is_err());
+    }
 }
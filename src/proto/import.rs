@@ -0,0 +1,545 @@
+/*
+ * Copyright (c) 2024 Jan Range
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ *
+ */
+
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::attribute::{Attribute, DataType};
+use crate::datamodel::DataModel;
+use crate::object::{Enumeration, Object};
+
+/// Creates a data model from a Protocol Buffers definition.
+///
+/// `message` definitions are mapped to objects and `enum` blocks to
+/// enumerations. `repeated` fields set `is_array`, while `repeated` and
+/// `optional` fields as well as members of a `oneof` are not required.
+/// Comments preceding a definition or trailing a field become its docstring
+/// and the `deprecated` and `default` field options are taken over.
+///
+/// Messages and enums nested within messages are extracted as objects or
+/// enumerations of their own. Services, options and reserved ranges are
+/// ignored.
+///
+/// # Arguments
+///
+/// * `content` - The content of the `.proto` file.
+///
+/// # Returns
+///
+/// A data model or an error if the definition is not valid or uses unsupported types.
+pub fn from_proto(content: &str) -> Result<DataModel, Box<dyn Error>> {
+    let mut parser = Parser::new(tokenize(content)?);
+    let mut model = DataModel::new(None, None);
+
+    while let Some(token) = parser.next_token() {
+        match token.as_str() {
+            "message" => parser.parse_message(&mut model)?,
+            "enum" => parser.parse_enum(&mut model)?,
+            "service" => {
+                parser.next_identifier()?;
+                parser.skip_block()?;
+            }
+            ";" => {}
+            // syntax, package, import, option and extend statements
+            _ => parser.skip_statement()?,
+        }
+    }
+
+    let enum_names: Vec<String> = model.enums.iter().map(|e| e.name.clone()).collect();
+    for object in model.objects.iter_mut() {
+        for attr in object.attributes.iter_mut() {
+            attr.is_enum = attr.dtypes.iter().any(|dtype| enum_names.contains(dtype));
+        }
+    }
+
+    Ok(model)
+}
+
+/// A token of a `.proto` file.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// An identifier, keyword, number or punctuation character.
+    Word { text: String, line: usize },
+    /// A line comment without the leading slashes.
+    Comment { text: String, line: usize },
+}
+
+/// Splits the content of a `.proto` file into tokens.
+///
+/// String literals are kept including their quotes and block comments are
+/// dropped.
+fn tokenize(content: &str) -> Result<Vec<Token>, Box<dyn Error>> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    let mut line = 1;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            '/' if chars.peek() == Some(&'/') => {
+                let text: String = chars.by_ref().take_while(|c| *c != '\n').collect();
+                tokens.push(Token::Comment {
+                    text: text.trim_start_matches('/').trim().to_string(),
+                    line,
+                });
+                line += 1;
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if previous == '*' => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            previous = c;
+                        }
+                        None => return Err("Unterminated block comment".into()),
+                    }
+                }
+            }
+            '"' | '\'' => {
+                let mut text = c.to_string();
+                loop {
+                    match chars.next() {
+                        Some('\\') => {
+                            text.push('\\');
+                            text.extend(chars.next());
+                        }
+                        Some(q) if q == c => {
+                            text.push(q);
+                            break;
+                        }
+                        Some('\n') | None => {
+                            return Err(format!("Unterminated string in line {}", line).into())
+                        }
+                        Some(other) => text.push(other),
+                    }
+                }
+                tokens.push(Token::Word { text, line });
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let mut text = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_alphanumeric() || next == '_' || next == '.' {
+                        text.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Word { text, line });
+            }
+            _ => tokens.push(Token::Word {
+                text: c.to_string(),
+                line,
+            }),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// A recursive descent parser over the tokens of a `.proto` file.
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+    /// Comments collected since the last definition.
+    comments: Vec<String>,
+}
+
+impl Parser {
+    fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            index: 0,
+            comments: Vec::new(),
+        }
+    }
+
+    /// Returns the next word, collecting all comments in front of it.
+    fn next_token(&mut self) -> Option<String> {
+        while let Some(token) = self.tokens.get(self.index) {
+            self.index += 1;
+            match token {
+                Token::Comment { text, .. } => self.comments.push(text.clone()),
+                Token::Word { text, .. } => return Some(text.clone()),
+            }
+        }
+
+        None
+    }
+
+    /// Returns the next word or an error at the end of the file.
+    fn expect_token(&mut self) -> Result<String, Box<dyn Error>> {
+        self.next_token()
+            .ok_or_else(|| "Unexpected end of the proto file".into())
+    }
+
+    /// Consumes the next word and fails if it is not the expected one.
+    fn expect(&mut self, expected: &str) -> Result<(), Box<dyn Error>> {
+        let token = self.expect_token()?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(format!("Expected '{}', found '{}'", expected, token).into())
+        }
+    }
+
+    /// Consumes the next word, which has to be an identifier.
+    fn next_identifier(&mut self) -> Result<String, Box<dyn Error>> {
+        let token = self.expect_token()?;
+        if token.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '.') {
+            Ok(token)
+        } else {
+            Err(format!("Expected an identifier, found '{}'", token).into())
+        }
+    }
+
+    /// Takes the comments collected so far as a docstring.
+    fn take_docstring(&mut self) -> String {
+        std::mem::take(&mut self.comments).join(" ")
+    }
+
+    /// Returns a comment in the same line as the previous word.
+    fn trailing_comment(&mut self) -> Option<String> {
+        let line = match self.index.checked_sub(1).and_then(|i| self.tokens.get(i)) {
+            Some(Token::Word { line, .. }) => *line,
+            _ => return None,
+        };
+
+        match self.tokens.get(self.index) {
+            Some(Token::Comment { text, line: l }) if *l == line => {
+                self.index += 1;
+                Some(text.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Skips all words up to and including the next semicolon.
+    fn skip_statement(&mut self) -> Result<(), Box<dyn Error>> {
+        while self.expect_token()? != ";" {}
+        self.comments.clear();
+        Ok(())
+    }
+
+    /// Skips a block enclosed in braces, including nested blocks.
+    fn skip_block(&mut self) -> Result<(), Box<dyn Error>> {
+        self.expect("{")?;
+        let mut depth = 1;
+
+        while depth > 0 {
+            match self.expect_token()?.as_str() {
+                "{" => depth += 1,
+                "}" => depth -= 1,
+                _ => {}
+            }
+        }
+
+        self.comments.clear();
+        Ok(())
+    }
+
+    /// Consumes the field options enclosed in brackets, if present, and the closing semicolon.
+    ///
+    /// # Returns
+    ///
+    /// The options as pairs of names and values.
+    fn finish_field(&mut self) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+        let mut options = Vec::new();
+        let mut token = self.expect_token()?;
+
+        if token == "[" {
+            let mut words = Vec::new();
+            loop {
+                match self.expect_token()?.as_str() {
+                    "]" => break,
+                    "," => words.clear(),
+                    word => words.push(word.to_string()),
+                }
+
+                if let [name, eq, value] = words.as_slice() {
+                    if eq == "=" {
+                        options.push((name.clone(), value.clone()));
+                    }
+                }
+            }
+            token = self.expect_token()?;
+        }
+
+        if token == ";" {
+            Ok(options)
+        } else {
+            Err(format!("Expected ';', found '{}'", token).into())
+        }
+    }
+
+    /// Parses a message and registers it and its nested types within the model.
+    fn parse_message(&mut self, model: &mut DataModel) -> Result<(), Box<dyn Error>> {
+        let docstring = self.take_docstring();
+        let name = self.next_identifier()?;
+
+        if model.get_object(&name).is_some() {
+            return Err(format!("Message '{}' is defined more than once", name).into());
+        }
+
+        // Reserve the position before nested messages are registered
+        let index = model.objects.len();
+        let mut object = Object::new(name.clone(), None);
+        object.docstring = docstring;
+        model.objects.push(object);
+
+        self.expect("{")?;
+        let mut attributes = Vec::new();
+
+        loop {
+            let token = self.expect_token()?;
+            match token.as_str() {
+                "}" => break,
+                ";" => {}
+                "message" => self.parse_message(model)?,
+                "enum" => self.parse_enum(model)?,
+                "oneof" => {
+                    self.next_identifier()?;
+                    self.expect("{")?;
+                    self.comments.clear();
+
+                    loop {
+                        let token = self.expect_token()?;
+                        match token.as_str() {
+                            "}" => break,
+                            "option" => self.skip_statement()?,
+                            _ => {
+                                let mut attribute = self.parse_field(&name, token)?;
+                                attribute.required = false;
+                                attributes.push(attribute);
+                            }
+                        }
+                    }
+                }
+                "option" | "reserved" | "extensions" => self.skip_statement()?,
+                "extend" => {
+                    self.next_identifier()?;
+                    self.skip_block()?;
+                }
+                _ => attributes.push(self.parse_field(&name, token)?),
+            }
+        }
+
+        model.objects[index].attributes = attributes;
+        self.comments.clear();
+
+        Ok(())
+    }
+
+    /// Parses a field of a message, starting at its label or type.
+    fn parse_field(&mut self, message: &str, token: String) -> Result<Attribute, Box<dyn Error>> {
+        let docstring = self.take_docstring();
+
+        let (label, dtype) = match token.as_str() {
+            "repeated" | "optional" | "required" => (Some(token), self.next_identifier()?),
+            _ => (None, token),
+        };
+
+        if dtype == "map" {
+            return Err(format!("Message '{}': map fields are not supported", message).into());
+        }
+
+        if dtype == "group" {
+            return Err(format!("Message '{}': groups are not supported", message).into());
+        }
+
+        let name = self.next_identifier()?;
+        self.expect("=")?;
+        self.expect_token()?;
+        let options = self.finish_field()?;
+
+        let mut attribute = Attribute::new(
+            name,
+            !matches!(label.as_deref(), Some("optional") | Some("repeated")),
+        );
+        attribute.dtypes = vec![map_scalar(&dtype).unwrap_or_else(|| short_name(&dtype))];
+        attribute.is_array = label.as_deref() == Some("repeated");
+
+        for (key, value) in options {
+            match key.as_str() {
+                "deprecated" => attribute.deprecated = value == "true",
+                "default" => attribute.default = DataType::from_str(value.trim_matches('"')).ok(),
+                _ => {}
+            }
+        }
+        attribute.docstring = match self.trailing_comment() {
+            Some(comment) if docstring.is_empty() => comment,
+            _ => docstring,
+        };
+
+        Ok(attribute)
+    }
+
+    /// Parses an enum and registers it within the model.
+    fn parse_enum(&mut self, model: &mut DataModel) -> Result<(), Box<dyn Error>> {
+        let docstring = self.take_docstring();
+        let name = self.next_identifier()?;
+
+        if model.enums.iter().any(|e| e.name == name) {
+            return Err(format!("Enum '{}' is defined more than once", name).into());
+        }
+
+        self.expect("{")?;
+        let mut enumeration = Enumeration {
+            name,
+            mappings: Default::default(),
+            docstring,
+            position: None,
+        };
+
+        loop {
+            let token = self.expect_token()?;
+            match token.as_str() {
+                "}" => break,
+                ";" => {}
+                "option" | "reserved" => self.skip_statement()?,
+                _ => {
+                    self.expect("=")?;
+                    self.expect_token()?;
+                    self.finish_field()?;
+                    self.trailing_comment();
+                    self.comments.clear();
+                    enumeration.mappings.insert(token.clone(), token);
+                }
+            }
+        }
+
+        model.enums.push(enumeration);
+        self.comments.clear();
+
+        Ok(())
+    }
+}
+
+/// Maps a Protocol Buffers scalar type to the corresponding md-models type.
+fn map_scalar(name: &str) -> Option<String> {
+    let dtype = match name {
+        "string" => "string",
+        "int32" | "int64" | "uint32" | "uint64" | "sint32" | "sint64" | "fixed32" | "fixed64"
+        | "sfixed32" | "sfixed64" => "integer",
+        "float" | "double" => "float",
+        "bool" => "boolean",
+        "bytes" => "bytes",
+        _ => return None,
+    };
+
+    Some(dtype.to_string())
+}
+
+/// Strips the package from a fully qualified type name.
+fn short_name(name: &str) -> String {
+    name.rsplit('.').next().unwrap_or(name).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_proto() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model.proto").unwrap();
+
+        // Act
+        let model = from_proto(&content).expect("Failed to import proto file");
+
+        // Assert
+        let names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, vec!["Test", "Test2"]);
+
+        let enums: Vec<&str> = model.enums.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(enums, vec!["Kind", "Ontology"]);
+        assert_eq!(
+            model.enums[1].docstring,
+            "Ontology endpoints for different types of sequences."
+        );
+        assert_eq!(model.enums[1].mappings.len(), 3);
+
+        let test = &model.objects[0];
+        assert_eq!(test.docstring, "A test object.");
+
+        let attrs: Vec<(&str, &str, bool, bool)> = test
+            .attributes
+            .iter()
+            .map(|a| {
+                (
+                    a.name.as_str(),
+                    a.dtypes[0].as_str(),
+                    a.required,
+                    a.is_array,
+                )
+            })
+            .collect();
+        assert_eq!(
+            attrs,
+            vec![
+                ("name", "string", true, false),
+                ("number", "float", false, false),
+                ("test2", "Test2", false, true),
+                ("ontology", "Ontology", true, false),
+                ("int_value", "integer", false, false),
+                ("string_value", "string", false, false),
+                ("legacy", "integer", true, false),
+            ]
+        );
+
+        assert_eq!(test.attributes[0].docstring, "The name of the test.");
+        assert_eq!(test.attributes[1].default, Some(DataType::Float(1.0)));
+        assert!(test.attributes[3].is_enum);
+        assert_eq!(test.attributes[5].docstring, "The value as a string.");
+        assert!(test.attributes[6].deprecated);
+
+        let test2 = &model.objects[1];
+        assert_eq!(test2.docstring, "");
+        assert_eq!(test2.attributes[2].dtypes, vec!["Kind"]);
+        assert!(test2.attributes[2].is_enum);
+    }
+
+    #[test]
+    fn test_from_proto_unsupported() {
+        // Arrange
+        let cases = [
+            "message A { map<string, int32> values = 1; }",
+            "message A { string name = 1 }",
+            "message A { string name = 1; }\nmessage A { string id = 1; }",
+            "message A { string name = 1;",
+            "/* unterminated",
+        ];
+
+        // Act & Assert
+        for case in cases {
+            assert!(from_proto(case).is_err(), "Expected '{}' to fail", case);
+        }
+    }
+}
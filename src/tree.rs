@@ -0,0 +1,570 @@
+/*
+ * Copyright (c) 2024 Jan Range
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ *
+ */
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use crate::datamodel::DataModel;
+
+/// A directed graph mapping each object to the objects it references.
+pub type DependencyGraph = BTreeMap<String, BTreeSet<String>>;
+
+/// Builds the dependency graph of a data model.
+///
+/// Each object is a node and each attribute type that refers to another
+/// object of the model is an edge. Basic types and enumerations are not
+/// part of the graph.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel`.
+///
+/// # Returns
+///
+/// A `DependencyGraph` containing every object of the model.
+pub fn dependency_graph(model: &DataModel) -> DependencyGraph {
+    let object_names: HashSet<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+
+    model
+        .objects
+        .iter()
+        .map(|object| {
+            let dependencies = object
+                .attributes
+                .iter()
+                .flat_map(|attr| attr.dtypes.iter())
+                .filter(|dtype| object_names.contains(dtype.as_str()))
+                .cloned()
+                .collect();
+
+            (object.name.clone(), dependencies)
+        })
+        .collect()
+}
+
+/// Builds the dependency graph of a data model including enumerations.
+///
+/// In addition to the edges of `dependency_graph`, each object has an edge
+/// to every enumeration used by its enum attributes. Enumerations are nodes
+/// without dependencies, hence a topological order of this graph places each
+/// enumeration before its first use.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel`.
+///
+/// # Returns
+///
+/// A `DependencyGraph` containing every object and enumeration of the model.
+pub fn full_type_graph(model: &DataModel) -> DependencyGraph {
+    let enum_names: HashSet<&str> = model.enums.iter().map(|e| e.name.as_str()).collect();
+    let mut graph = dependency_graph(model);
+
+    for object in &model.objects {
+        let enums = object
+            .enum_attributes()
+            .flat_map(|attr| attr.dtypes.iter())
+            .filter(|dtype| enum_names.contains(dtype.as_str()))
+            .cloned();
+
+        graph.entry(object.name.clone()).or_default().extend(enums);
+    }
+
+    for enumeration in &model.enums {
+        graph.entry(enumeration.name.clone()).or_default();
+    }
+
+    graph
+}
+
+/// Builds the graph of references that must be present in every instance.
+///
+/// Only required attributes holding a single value are edges, since optional
+/// and array attributes allow an instance to end the chain of references.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel`.
+///
+/// # Returns
+///
+/// A `DependencyGraph` containing every object of the model.
+pub fn required_dependency_graph(model: &DataModel) -> DependencyGraph {
+    let object_names: HashSet<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+
+    model
+        .objects
+        .iter()
+        .map(|object| {
+            let dependencies = object
+                .attributes
+                .iter()
+                .filter(|attr| attr.required && !attr.is_array)
+                .flat_map(|attr| attr.dtypes.iter())
+                .filter(|dtype| object_names.contains(dtype.as_str()))
+                .cloned()
+                .collect();
+
+            (object.name.clone(), dependencies)
+        })
+        .collect()
+}
+
+/// Finds the longest path of nested objects in the dependency graph.
+///
+/// Each object occurs at most once on a path. Edges that point back to an
+/// object already on the current path, such as recursive types, are ignored,
+/// hence a cycle counts each of its objects once. For example, the cycle
+/// `A -> B -> C -> A` referenced by `D` yields the path `D -> C -> A -> B`.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the `DependencyGraph`.
+///
+/// # Returns
+///
+/// The object names along the longest path, starting with the outermost object.
+pub fn longest_path(graph: &DependencyGraph) -> Vec<String> {
+    // The longest path from an object on a cycle depends on the objects already
+    // visited, hence only paths of objects outside of cycles are cached
+    let cyclic: HashSet<&str> = graph
+        .iter()
+        .filter(|(node, dependencies)| {
+            let roots: Vec<&str> = dependencies.iter().map(String::as_str).collect();
+            reachable_from(graph, &roots).contains(node.as_str())
+        })
+        .map(|(node, _)| node.as_str())
+        .collect();
+
+    let mut memo: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    let mut longest = Vec::new();
+
+    for node in graph.keys() {
+        let mut stack = HashSet::new();
+        let path = longest_path_from(node, graph, &cyclic, &mut stack, &mut memo);
+        if path.len() > longest.len() {
+            longest = path;
+        }
+    }
+
+    longest
+}
+
+/// Collects all nodes that are reachable from the given roots.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the `DependencyGraph`.
+/// * `roots` - The names of the nodes to start from.
+///
+/// # Returns
+///
+/// The roots and all of their transitive dependencies.
+pub fn reachable_from(graph: &DependencyGraph, roots: &[&str]) -> BTreeSet<String> {
+    let mut visited = BTreeSet::new();
+    let mut stack: Vec<&str> = roots.to_vec();
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.to_string()) {
+            continue;
+        }
+
+        for dependency in graph.get(node).into_iter().flatten() {
+            if !visited.contains(dependency) {
+                stack.push(dependency);
+            }
+        }
+    }
+
+    visited
+}
+
+/// Orders the nodes of a dependency graph such that dependencies come first.
+///
+/// Nodes are visited depth-first in the given order, hence the result is
+/// stable for the same input. Edges closing a cycle are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the `DependencyGraph`.
+/// * `nodes` - The nodes to order, determining the order of independent nodes.
+///
+/// # Returns
+///
+/// The given nodes and their dependencies in topological order.
+pub fn topological_order(graph: &DependencyGraph, nodes: &[&str]) -> Vec<String> {
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+
+    for node in nodes {
+        visit_topological(node, graph, &mut visited, &mut order);
+    }
+
+    order
+}
+
+/// Checks whether any objects of the model reference each other in a cycle.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel`.
+///
+/// # Returns
+///
+/// `true` if at least one cycle exists, including objects referencing themselves.
+pub fn has_cycles(model: &DataModel) -> bool {
+    !find_cycles(model).is_empty()
+}
+
+/// Finds all cycles of object references in the model.
+///
+/// The dependency graph is traversed depth-first, marking nodes as unvisited,
+/// on the current path or finished. Every edge pointing to a node on the
+/// current path closes a cycle.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel`.
+///
+/// # Returns
+///
+/// The object names of each cycle, starting with the object the cycle is entered at.
+pub fn find_cycles(model: &DataModel) -> Vec<Vec<String>> {
+    cycles_in(&dependency_graph(model))
+}
+
+/// Finds all cycles in a dependency graph.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the `DependencyGraph`.
+///
+/// # Returns
+///
+/// The node names of each cycle, starting with the node the cycle is entered at.
+pub fn cycles_in(graph: &DependencyGraph) -> Vec<Vec<String>> {
+    let mut colors: BTreeMap<&str, Color> =
+        graph.keys().map(|k| (k.as_str(), Color::White)).collect();
+    let mut cycles = Vec::new();
+
+    for node in graph.keys() {
+        if colors[node.as_str()] == Color::White {
+            let mut path = Vec::new();
+            visit_cycles(node, graph, &mut colors, &mut path, &mut cycles);
+        }
+    }
+
+    cycles
+}
+
+/// Visits a node after all of its dependencies, appending it to the order.
+///
+/// # Arguments
+///
+/// * `node` - The name of the node to visit.
+/// * `graph` - A reference to the `DependencyGraph`.
+/// * `visited` - The nodes visited so far.
+/// * `order` - The nodes in topological order.
+fn visit_topological<'a>(
+    node: &'a str,
+    graph: &'a DependencyGraph,
+    visited: &mut HashSet<&'a str>,
+    order: &mut Vec<String>,
+) {
+    if !visited.insert(node) {
+        return;
+    }
+
+    for dependency in graph.get(node).into_iter().flatten() {
+        visit_topological(dependency, graph, visited, order);
+    }
+
+    order.push(node.to_string());
+}
+
+/// The state of a node during the depth-first search for cycles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    /// The node has not been visited yet.
+    White,
+    /// The node is on the current path.
+    Gray,
+    /// The node and all of its dependencies have been visited.
+    Black,
+}
+
+/// Visits a node and its dependencies, collecting every cycle that is closed.
+///
+/// # Arguments
+///
+/// * `node` - The name of the node to visit.
+/// * `graph` - A reference to the `DependencyGraph`.
+/// * `colors` - The state of each node.
+/// * `path` - The nodes on the current path.
+/// * `cycles` - The cycles found so far.
+fn visit_cycles<'a>(
+    node: &'a str,
+    graph: &'a DependencyGraph,
+    colors: &mut BTreeMap<&'a str, Color>,
+    path: &mut Vec<&'a str>,
+    cycles: &mut Vec<Vec<String>>,
+) {
+    colors.insert(node, Color::Gray);
+    path.push(node);
+
+    for dependency in graph.get(node).into_iter().flatten() {
+        match colors.get(dependency.as_str()) {
+            Some(Color::White) => visit_cycles(dependency, graph, colors, path, cycles),
+            Some(Color::Gray) => {
+                let start = path
+                    .iter()
+                    .position(|n| n == dependency)
+                    .expect("Gray nodes are on the current path");
+                cycles.push(path[start..].iter().map(|n| n.to_string()).collect());
+            }
+            _ => {}
+        }
+    }
+
+    path.pop();
+    colors.insert(node, Color::Black);
+}
+
+/// Recursively computes the longest path starting at the given node.
+///
+/// # Arguments
+///
+/// * `node` - The name of the node to start from.
+/// * `graph` - A reference to the `DependencyGraph`.
+/// * `cyclic` - The nodes that are part of a cycle, whose paths are not cached.
+/// * `stack` - The nodes on the current path.
+/// * `memo` - Already computed paths per node.
+///
+/// # Returns
+///
+/// The object names along the longest path starting at `node`.
+fn longest_path_from<'a>(
+    node: &'a str,
+    graph: &'a DependencyGraph,
+    cyclic: &HashSet<&str>,
+    stack: &mut HashSet<&'a str>,
+    memo: &mut BTreeMap<&'a str, Vec<String>>,
+) -> Vec<String> {
+    if let Some(path) = memo.get(node) {
+        return path.clone();
+    }
+
+    stack.insert(node);
+
+    let mut longest = Vec::new();
+    for dependency in graph.get(node).into_iter().flatten() {
+        if stack.contains(dependency.as_str()) {
+            continue;
+        }
+
+        let path = longest_path_from(dependency, graph, cyclic, stack, memo);
+        if path.len() > longest.len() {
+            longest = path;
+        }
+    }
+
+    stack.remove(node);
+
+    let mut path = vec![node.to_string()];
+    path.extend(longest);
+
+    if !cyclic.contains(node) {
+        memo.insert(node, path.clone());
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn graph(edges: &[(&str, &[&str])]) -> DependencyGraph {
+        edges
+            .iter()
+            .map(|(node, deps)| {
+                (
+                    node.to_string(),
+                    deps.iter().map(|d| d.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dependency_graph() {
+        // Arrange
+        let model = DataModel::from_markdown(std::path::Path::new("tests/data/model.md"))
+            .expect("Failed to parse markdown");
+
+        // Act
+        let graph = dependency_graph(&model);
+
+        // Assert
+        assert_eq!(graph["Test"], BTreeSet::from(["Test2".to_string()]));
+        assert!(graph["Test2"].is_empty());
+    }
+
+    #[test]
+    fn test_full_type_graph() {
+        // Arrange
+        let model = DataModel::from_markdown(std::path::Path::new("tests/data/model.md"))
+            .expect("Failed to parse markdown");
+
+        // Act
+        let graph = full_type_graph(&model);
+        let order = topological_order(&graph, &["Test", "Test2"]);
+
+        // Assert
+        assert_eq!(
+            graph["Test"],
+            BTreeSet::from(["Test2".to_string(), "Ontology".to_string()])
+        );
+        assert!(graph["Ontology"].is_empty());
+        assert_eq!(order, vec!["Ontology", "Test2", "Test"]);
+    }
+
+    #[test]
+    fn test_longest_path() {
+        // Arrange
+        let graph = graph(&[("A", &["B", "D"]), ("B", &["C"]), ("C", &[]), ("D", &[])]);
+
+        // Act
+        let path = longest_path(&graph);
+
+        // Assert
+        assert_eq!(path, vec!["A", "B", "C"]);
+    }
+
+    #[test]
+    fn test_reachable_from() {
+        // Arrange
+        let graph = graph(&[("A", &["B"]), ("B", &["A", "C"]), ("C", &[]), ("D", &["A"])]);
+
+        // Act
+        let nodes = reachable_from(&graph, &["B"]);
+
+        // Assert
+        assert_eq!(
+            nodes,
+            BTreeSet::from(["A".to_string(), "B".to_string(), "C".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_find_cycles() {
+        // Arrange
+        let model = DataModel::from_markdown_string(
+            "# Test\n\n### A\n\n- b\n  - Type: B\n\n### B\n\n- a\n  - Type: A\n- c\n  - Type: C\n\n### C\n\n- c\n  - Type: C\n\n### D\n\n- a\n  - Type: A\n",
+        )
+        .expect("Failed to parse markdown");
+
+        // Act
+        let cycles = find_cycles(&model);
+
+        // Assert
+        assert!(has_cycles(&model));
+        assert_eq!(
+            cycles,
+            vec![
+                vec!["A".to_string(), "B".to_string()],
+                vec!["C".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cycles_in_required_graph() {
+        // Arrange
+        let model = DataModel::from_markdown_string(
+            "# Test\n\n### A\n\n- b\n  - Type: B\n\n### B\n\n- __a__\n  - Type: A\n",
+        )
+        .expect("Failed to parse markdown");
+
+        // Act
+        let cycles = cycles_in(&required_dependency_graph(&model));
+
+        // Assert
+        assert!(has_cycles(&model));
+        assert!(cycles.is_empty());
+    }
+
+    #[test]
+    fn test_find_cycles_acyclic() {
+        // Arrange
+        let model = DataModel::from_markdown(std::path::Path::new("tests/data/model.md"))
+            .expect("Failed to parse markdown");
+
+        // Act & Assert
+        assert!(!has_cycles(&model));
+        assert!(find_cycles(&model).is_empty());
+    }
+
+    #[test]
+    fn test_topological_order() {
+        // Arrange
+        let graph = graph(&[("A", &["C", "B"]), ("B", &["C"]), ("C", &["A"]), ("D", &[])]);
+
+        // Act
+        let order = topological_order(&graph, &["D", "A", "B", "C"]);
+
+        // Assert
+        assert_eq!(order, vec!["D", "C", "B", "A"]);
+    }
+
+    #[test]
+    fn test_longest_path_with_cycle() {
+        // Arrange
+        let graph = graph(&[("A", &["A", "B"]), ("B", &["A"])]);
+
+        // Act
+        let path = longest_path(&graph);
+
+        // Assert
+        assert_eq!(path, vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_longest_path_independent_of_names() {
+        // Arrange
+        let cases = [
+            (["A", "B", "C", "D"], vec!["D", "C", "A", "B"]),
+            (["Z", "Y", "X", "A"], vec!["A", "X", "Z", "Y"]),
+        ];
+
+        for ([a, b, c, d], expected) in cases {
+            let graph = graph(&[(a, &[b]), (b, &[c]), (c, &[a]), (d, &[c])]);
+
+            // Act
+            let path = longest_path(&graph);
+
+            // Assert
+            assert_eq!(path, expected);
+        }
+    }
+}
@@ -22,28 +22,37 @@
  */
 
 use crate::{
-    attribute::Attribute,
+    attribute::{is_known_option, Attribute, DataType, PATTERN_OPTIONS},
     datamodel::DataModel,
-    markdown::position::Position,
+    markdown::{frontmatter::FrontMatter, parser::parse_markdown, position::Position},
     object::{Enumeration, Object},
+    tree,
 };
 use colored::Colorize;
-use log::error;
+use log::{error, warn};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use url::Url;
+
+#[cfg(feature = "python")]
+use pyo3::pyclass;
 
 #[cfg(feature = "wasm")]
 use tsify_next::Tsify;
 
+// Default maximum nesting depth of objects
+const DEFAULT_MAX_NESTING_DEPTH: usize = 10;
+
 // Basic types that are ignored in the validation process
-pub(crate) const BASIC_TYPES: [&str; 7] = [
-    "string", "number", "integer", "boolean", "float", "date", "bytes",
+pub(crate) const BASIC_TYPES: [&str; 9] = [
+    "string", "number", "integer", "boolean", "float", "date", "bytes", "decimal", "bigint",
 ];
 
 /// Represents a validation error in the data model.
 #[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 #[cfg_attr(feature = "wasm", derive(Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
 pub struct ValidationError {
@@ -83,8 +92,76 @@ impl Display for ValidationError {
     }
 }
 
+/// Represents a non-fatal validation warning in the data model.
+///
+/// Warnings mirror `ValidationError`, but do not invalidate the model.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+pub struct ValidationWarning {
+    pub message: String,
+    pub object: Option<String>,
+    pub attribute: Option<String>,
+    pub location: String,
+    pub warning_type: WarningType,
+    pub positions: Vec<Position>,
+}
+
+impl Display for ValidationWarning {
+    /// Formats the validation warning for display.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let lines: Vec<String> = self.positions.iter().map(|p| p.line.to_string()).collect();
+        let line = if lines.is_empty() {
+            "".to_string()
+        } else {
+            format!("[line: {}]", lines.join(", "))
+        };
+
+        write!(
+            f,
+            "{}[{}{}] {}: {}",
+            line,
+            self.object.clone().unwrap_or("Global".into()).bold(),
+            match &self.attribute {
+                Some(attr) => format!(".{}", attr),
+                None => "".into(),
+            },
+            self.warning_type.to_string().bold(),
+            self.message.yellow().bold(),
+        )
+    }
+}
+
+/// Enum representing the type of validation warning.
+#[derive(Debug, Clone, Serialize, PartialEq, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+pub enum WarningType {
+    DocstringWarning,
+    TermWarning,
+    EnumWarning,
+    TypeWarning,
+    OptionWarning,
+}
+
+impl Display for WarningType {
+    /// Formats the warning type for display.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WarningType::DocstringWarning => write!(f, "DocstringWarning"),
+            WarningType::TermWarning => write!(f, "TermWarning"),
+            WarningType::EnumWarning => write!(f, "EnumWarning"),
+            WarningType::TypeWarning => write!(f, "TypeWarning"),
+            WarningType::OptionWarning => write!(f, "OptionWarning"),
+        }
+    }
+}
+
 /// Enum representing the type of validation error.
 #[derive(Debug, Clone, Serialize, PartialEq, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
 #[cfg_attr(feature = "wasm", derive(Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
 pub enum ErrorType {
@@ -92,6 +169,7 @@ pub enum ErrorType {
     TypeError,
     DuplicateError,
     GlobalError,
+    ObjectError,
 }
 
 impl Display for ErrorType {
@@ -102,17 +180,21 @@ impl Display for ErrorType {
             ErrorType::TypeError => write!(f, "TypeError"),
             ErrorType::DuplicateError => write!(f, "DuplicateError"),
             ErrorType::GlobalError => write!(f, "GlobalError"),
+            ErrorType::ObjectError => write!(f, "ObjectError"),
         }
     }
 }
 
 /// Validator for checking the integrity of a data model.
 #[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 #[cfg_attr(feature = "wasm", derive(Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
 pub struct Validator {
     pub is_valid: bool,
     pub errors: Vec<ValidationError>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ValidationWarning>,
     #[serde(skip_serializing)]
     pub object_positions: HashMap<String, Vec<Position>>,
     #[serde(skip_serializing)]
@@ -121,12 +203,39 @@ pub struct Validator {
 
 impl Error for Validator {}
 
+/// Validates a markdown model without returning the parsed `DataModel`.
+///
+/// This is meant for editor integrations that only need the diagnostics.
+///
+/// # Arguments
+///
+/// * `content` - The markdown content of the model.
+///
+/// # Returns
+///
+/// An empty `Validator` if the model is valid, otherwise one holding the collected errors.
+pub fn validate_markdown_str(content: &str) -> Validator {
+    match parse_markdown(content) {
+        Ok(_) => Validator::new(),
+        Err(validator) => validator,
+    }
+}
+
 impl Display for Validator {
     /// Formats the validator for display.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         for error in &self.errors {
             error.fmt(f)?;
         }
+
+        if self.has_warnings() {
+            writeln!(f)?;
+            writeln!(f, "Warnings:")?;
+            for warning in &self.warnings {
+                writeln!(f, "{}", warning)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -137,6 +246,7 @@ impl Validator {
         Self {
             is_valid: true,
             errors: vec![],
+            warnings: vec![],
             object_positions: HashMap::new(),
             enum_positions: HashMap::new(),
         }
@@ -144,6 +254,7 @@ impl Validator {
     pub fn reset(&mut self) {
         self.is_valid = true;
         self.errors.clear();
+        self.warnings.clear();
         self.object_positions.clear();
         self.enum_positions.clear();
     }
@@ -158,13 +269,38 @@ impl Validator {
         self.is_valid = false;
     }
 
-    /// Prints all validation errors to the log.
+    /// Adds a validation warning to the validator.
+    ///
+    /// Warnings are reported to the user but do not invalidate the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `warning` - The validation warning to be added.
+    pub fn add_warning(&mut self, warning: ValidationWarning) {
+        self.warnings.push(warning);
+    }
+
+    /// Returns whether any validation warnings have been collected.
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    /// Prints all validation errors and warnings to the log.
     ///
     /// This method iterates over the `errors` vector and logs each error using the `error!` macro.
     pub fn log_result(&self) {
         for error in &self.errors {
             error!("{}", error);
         }
+
+        self.log_warnings();
+    }
+
+    /// Prints all validation warnings to the log using the `warn!` macro.
+    pub fn log_warnings(&self) {
+        for warning in &self.warnings {
+            warn!("{}", warning);
+        }
     }
 
     /// Validates the provided `DataModel`.
@@ -188,17 +324,35 @@ impl Validator {
 
         // Extract the type names from the model
         let types = Self::extract_type_names(model);
+        let enums = model
+            .enums
+            .iter()
+            .map(|enum_| enum_.name.as_str())
+            .collect::<Vec<&str>>();
 
         // Check for duplicate object names
         self.check_duplicate_objects(&model.objects);
         self.check_duplicate_enums(&model.enums);
         self.check_has_no_objects(model);
 
+        if let Some(frontmatter) = &model.config {
+            self.validate_frontmatter(frontmatter);
+        }
+        self.check_nesting_depth(model);
+        self.check_unknown_options(model);
+        self.check_circular_inheritance(model);
+        self.check_circular_references(model);
+
         // Validate the objects and enums
+        let allow_underscore_prefix = model.config().allow_underscore_prefix;
+
         for object in &model.objects {
-            self.validate_object(object, &types);
+            self.validate_object(object, &types, &enums, allow_underscore_prefix);
         }
 
+        // Collect non-fatal documentation warnings
+        self.check_documentation(model);
+
         self.sort_errors();
     }
 
@@ -279,14 +433,22 @@ impl Validator {
     ///
     /// * `object` - A reference to the `Object` to be validated.
     /// * `types` - A slice of type names that are valid within the model.
-    fn validate_object(&mut self, object: &Object, types: &[&str]) {
+    /// * `enums` - A slice of enumeration names defined within the model.
+    /// * `allow_underscore_prefix` - Whether attribute names may start with an underscore.
+    fn validate_object(
+        &mut self,
+        object: &Object,
+        types: &[&str],
+        enums: &[&str],
+        allow_underscore_prefix: bool,
+    ) {
         self.validate_object_name(&object.name);
         self.check_has_attributes(object);
         self.check_duplicate_attributes(object);
 
         // Validate the attributes of the object
         object.attributes.iter().for_each(|attribute| {
-            self.validate_attribute(attribute, types, object);
+            self.validate_attribute(attribute, types, enums, object, allow_underscore_prefix);
         });
     }
 
@@ -350,23 +512,17 @@ impl Validator {
     ///
     /// * `name` - The name of the object to be validated.
     fn validate_object_name(&mut self, name: &str) {
-        let checks = vec![
-            starts_with_character,
-            contains_white_space,
-            contains_special_characters,
-        ];
+        let messages = check_object_name(name).err().unwrap_or_default();
 
-        for check in checks {
-            if let Err(e) = check(name) {
-                self.add_error(ValidationError {
-                    message: e,
-                    object: Some(name.to_string()),
-                    attribute: None,
-                    location: "Global".into(),
-                    error_type: ErrorType::NameError,
-                    positions: self.object_positions.get(name).cloned().unwrap_or_default(),
-                });
-            }
+        for e in messages {
+            self.add_error(ValidationError {
+                message: e,
+                object: Some(name.to_string()),
+                attribute: None,
+                location: "Global".into(),
+                error_type: ErrorType::NameError,
+                positions: self.object_positions.get(name).cloned().unwrap_or_default(),
+            });
         }
     }
 
@@ -388,15 +544,254 @@ impl Validator {
         }
     }
 
+    /// Validates the front matter of the model.
+    ///
+    /// The repository and all prefix URIs have to be valid absolute URLs, the
+    /// keys of the namespace map must not contain special characters and a
+    /// prefix must not be mapped to different URIs in `prefixes` and `nsmap`.
+    ///
+    /// # Arguments
+    ///
+    /// * `frontmatter` - A reference to the `FrontMatter` to be validated.
+    pub fn validate_frontmatter(&mut self, frontmatter: &FrontMatter) {
+        let mut messages = Vec::new();
+
+        if let Err(e) = Url::parse(&frontmatter.repo) {
+            messages.push(format!(
+                "Repository '{}' is not a valid URL: {}",
+                frontmatter.repo, e
+            ));
+        }
+
+        let prefixes: BTreeMap<&String, &String> = frontmatter.prefixes.iter().flatten().collect();
+        let nsmap: BTreeMap<&String, &String> = frontmatter.nsmap.iter().flatten().collect();
+
+        for (prefix, uri) in prefixes.iter().chain(nsmap.iter()) {
+            if let Err(e) = Url::parse(uri) {
+                messages.push(format!(
+                    "URI '{}' of prefix '{}' is not a valid absolute URI: {}",
+                    uri, prefix, e
+                ));
+            }
+        }
+
+        for prefix in nsmap.keys() {
+            let valid = !prefix.is_empty()
+                && prefix
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+
+            if !valid {
+                messages.push(format!(
+                    "Namespace prefix '{}' contains special characters.",
+                    prefix
+                ));
+            }
+        }
+
+        for (prefix, uri) in &prefixes {
+            match nsmap.get(prefix) {
+                Some(other) if other != uri => messages.push(format!(
+                    "Prefix '{}' is declared in both prefixes and nsmap with different URIs.",
+                    prefix
+                )),
+                _ => {}
+            }
+        }
+
+        for message in messages {
+            self.add_error(ValidationError {
+                message,
+                object: None,
+                attribute: None,
+                location: "Global".into(),
+                error_type: ErrorType::GlobalError,
+                positions: vec![],
+            });
+        }
+    }
+
+    /// Checks if the nesting depth of the model exceeds the configured limit.
+    ///
+    /// The depth is the number of nested objects below the outermost object of
+    /// the longest path in the dependency graph. Objects on a cycle are counted
+    /// once, see `tree::longest_path`. The limit is taken from
+    /// `max-nesting-depth` in the front matter and defaults to 10.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - A reference to the `DataModel` to be checked.
+    fn check_nesting_depth(&mut self, model: &DataModel) {
+        let limit = model
+            .config()
+            .max_nesting_depth
+            .unwrap_or(DEFAULT_MAX_NESTING_DEPTH);
+
+        let path = tree::longest_path(&tree::dependency_graph(model));
+        let depth = path.len().saturating_sub(1);
+
+        if depth > limit {
+            self.add_error(ValidationError {
+                message: format!(
+                    "Nesting depth of {} exceeds the maximum of {}: {}",
+                    depth,
+                    limit,
+                    path.join(" -> ")
+                ),
+                object: path.first().cloned(),
+                attribute: None,
+                location: "Global".into(),
+                error_type: ErrorType::GlobalError,
+                positions: self
+                    .object_positions
+                    .get(&path[0])
+                    .cloned()
+                    .unwrap_or_default(),
+            });
+        }
+    }
+
+    /// Checks for cycles of required object references in the model.
+    ///
+    /// Recursive types are allowed as long as an optional or array attribute
+    /// ends the chain, otherwise no finite instance of the cycle exists. Each
+    /// cycle is reported once at the object the cycle is entered at.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - A reference to the `DataModel` to be checked.
+    fn check_circular_references(&mut self, model: &DataModel) {
+        for cycle in tree::cycles_in(&tree::required_dependency_graph(model)) {
+            let mut path = cycle.clone();
+            path.push(cycle[0].clone());
+
+            self.add_error(ValidationError {
+                message: format!("Circular reference detected: {}", path.join(" -> ")),
+                object: Some(cycle[0].clone()),
+                attribute: None,
+                location: "Global".into(),
+                error_type: ErrorType::ObjectError,
+                positions: self
+                    .object_positions
+                    .get(&cycle[0])
+                    .cloned()
+                    .unwrap_or_default(),
+            });
+        }
+    }
+
+    /// Checks for cycles in the inheritance chains of the model.
+    ///
+    /// Each cycle is reported once, starting at the first object of the
+    /// cycle in the order of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - A reference to the `DataModel` to be checked.
+    fn check_circular_inheritance(&mut self, model: &DataModel) {
+        let mut reported: HashSet<String> = HashSet::new();
+
+        for object in &model.objects {
+            if reported.contains(&object.name) {
+                continue;
+            }
+
+            let mut path = vec![object.name.clone()];
+            let mut visited = HashSet::from([object.name.clone()]);
+            let mut current = object.parent.as_ref();
+
+            while let Some(parent_name) = current {
+                path.push(parent_name.clone());
+
+                if parent_name == &object.name {
+                    reported.extend(path.iter().cloned());
+                    self.add_error(ValidationError {
+                        message: format!("Circular inheritance detected: {}", path.join(" -> ")),
+                        object: Some(object.name.clone()),
+                        attribute: None,
+                        location: "Global".into(),
+                        error_type: ErrorType::ObjectError,
+                        positions: self
+                            .object_positions
+                            .get(&object.name)
+                            .cloned()
+                            .unwrap_or_default(),
+                    });
+                    break;
+                }
+
+                // Cycles further up the chain are reported from their own objects
+                if !visited.insert(parent_name.clone()) {
+                    break;
+                }
+
+                current = model
+                    .get_object(parent_name)
+                    .and_then(|o| o.parent.as_ref());
+            }
+        }
+    }
+
+    /// Warns about unknown attribute options, if strict mode is enabled.
+    ///
+    /// Options declared in `allow-unknown-options` of the front matter are kept
+    /// as custom options and are not reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - A reference to the `DataModel` to be checked.
+    fn check_unknown_options(&mut self, model: &DataModel) {
+        if !model.config().strict {
+            return;
+        }
+
+        for object in &model.objects {
+            let attribute_positions = extract_attribute_positions(object);
+
+            for attribute in &object.attributes {
+                for option in &attribute.options {
+                    if is_known_option(&option.key) {
+                        continue;
+                    }
+
+                    self.add_warning(ValidationWarning {
+                        message: format!(
+                            "Option '{}' of property '{}' is unknown. \
+                            Add it to 'allow-unknown-options' to use it as a custom option.",
+                            option.key, attribute.name
+                        ),
+                        object: Some(object.name.clone()),
+                        attribute: Some(attribute.name.clone()),
+                        location: "Global".into(),
+                        warning_type: WarningType::OptionWarning,
+                        positions: attribute_positions
+                            .get(&attribute.name)
+                            .cloned()
+                            .unwrap_or_default(),
+                    });
+                }
+            }
+        }
+    }
+
     /// Validates a single attribute within an object.
     ///
     /// # Arguments
     ///
     /// * `attribute` - A reference to the `Attribute` to be validated.
     /// * `types` - A slice of type names that are valid within the model.
+    /// * `enums` - A slice of enumeration names defined within the model.
     /// * `obj_name` - The name of the object that contains the attribute.
-    fn validate_attribute(&mut self, attribute: &Attribute, types: &[&str], object: &Object) {
-        self.validate_attribute_name(&attribute.name, object);
+    /// * `allow_underscore_prefix` - Whether the name may start with an underscore.
+    fn validate_attribute(
+        &mut self,
+        attribute: &Attribute,
+        types: &[&str],
+        enums: &[&str],
+        object: &Object,
+        allow_underscore_prefix: bool,
+    ) {
+        self.validate_attribute_name(&attribute.name, object, allow_underscore_prefix);
 
         let attribute_positions = extract_attribute_positions(object);
 
@@ -417,6 +812,179 @@ impl Validator {
         for dtype in &attribute.dtypes {
             self.check_attr_dtype(attribute, types, object, dtype);
         }
+
+        self.check_mixed_enum_dtypes(attribute, enums, object);
+        self.validate_attribute_patterns(attribute, object);
+
+        if let Err(message) = validate_default_type(attribute) {
+            self.add_error(ValidationError {
+                message,
+                object: Some(object.name.clone()),
+                attribute: Some(attribute.name.clone()),
+                location: "Global".into(),
+                error_type: ErrorType::TypeError,
+                positions: attribute_positions
+                    .get(&attribute.name)
+                    .cloned()
+                    .unwrap_or_default(),
+            });
+        }
+
+        if let Err(message) = validate_item_bounds(attribute) {
+            self.add_error(ValidationError {
+                message,
+                object: Some(object.name.clone()),
+                attribute: Some(attribute.name.clone()),
+                location: "Global".into(),
+                error_type: ErrorType::TypeError,
+                positions: attribute_positions
+                    .get(&attribute.name)
+                    .cloned()
+                    .unwrap_or_default(),
+            });
+        }
+    }
+
+    /// Checks whether the patterns of an attribute are valid regular expressions.
+    ///
+    /// Patterns are checked when options are added, but models can also be
+    /// built without `Attribute::add_option`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute` - A reference to the `Attribute` to be checked.
+    /// * `object` - The object that contains the attribute.
+    fn validate_attribute_patterns(&mut self, attribute: &Attribute, object: &Object) {
+        let attribute_positions = extract_attribute_positions(object);
+
+        for option in &attribute.options {
+            if !PATTERN_OPTIONS.contains(&option.key.as_str()) {
+                continue;
+            }
+
+            if let Err(e) = regex::Regex::new(&option.value) {
+                self.add_error(ValidationError {
+                    message: format!(
+                        "Pattern '{}' of property '{}' is not a valid regular expression: {}",
+                        option.value, attribute.name, e
+                    ),
+                    object: Some(object.name.clone()),
+                    attribute: Some(attribute.name.clone()),
+                    location: "Global".into(),
+                    error_type: ErrorType::TypeError,
+                    positions: attribute_positions
+                        .get(&attribute.name)
+                        .cloned()
+                        .unwrap_or_default(),
+                });
+            }
+        }
+    }
+
+    /// Checks whether an attribute mixes enumeration and non-enumeration types.
+    ///
+    /// Templates handle such dtype lists inconsistently, because the attribute is
+    /// not treated as an enum. This is reported as a warning and does not
+    /// invalidate the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute` - A reference to the `Attribute` to be checked.
+    /// * `enums` - A slice of enumeration names defined within the model.
+    /// * `object` - The object that contains the attribute.
+    fn check_mixed_enum_dtypes(&mut self, attribute: &Attribute, enums: &[&str], object: &Object) {
+        let (enum_types, other_types): (Vec<&str>, Vec<&str>) = attribute
+            .dtypes
+            .iter()
+            .map(|dtype| dtype.as_str())
+            .partition(|dtype| enums.contains(dtype));
+
+        if enum_types.is_empty() || other_types.is_empty() {
+            return;
+        }
+
+        let attribute_positions = extract_attribute_positions(object);
+
+        self.add_warning(ValidationWarning {
+            message: format!(
+                "Property '{}' mixes enumeration types ({}) with non-enumeration types ({}). \
+                Consider wrapping the types in a dedicated object instead.",
+                attribute.name,
+                enum_types.join(", "),
+                other_types.join(", ")
+            ),
+            object: Some(object.name.clone()),
+            attribute: Some(attribute.name.clone()),
+            location: "Global".into(),
+            warning_type: WarningType::TypeWarning,
+            positions: attribute_positions
+                .get(&attribute.name)
+                .cloned()
+                .unwrap_or_default(),
+        });
+    }
+
+    /// Checks the documentation of objects, attributes and enumerations.
+    ///
+    /// Objects without a docstring, attributes without a term and enumerations
+    /// with fewer than two values are reported as warnings. Attributes without
+    /// a docstring are reported if `require-docstrings` is set in the frontmatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - A reference to the `DataModel` to be checked.
+    fn check_documentation(&mut self, model: &DataModel) {
+        let require_docstrings = model.config().require_docstrings;
+
+        for object in &model.objects {
+            if object.docstring.trim().is_empty() {
+                self.add_warning(ValidationWarning {
+                    message: format!("Object '{}' has no description.", object.name),
+                    object: Some(object.name.clone()),
+                    attribute: None,
+                    location: "Global".into(),
+                    warning_type: WarningType::DocstringWarning,
+                    positions: object.position.into_iter().collect(),
+                });
+            }
+
+            for attribute in &object.attributes {
+                if require_docstrings && attribute.docstring.trim().is_empty() {
+                    self.add_warning(ValidationWarning {
+                        message: format!("Property '{}' has no description.", attribute.name),
+                        object: Some(object.name.clone()),
+                        attribute: Some(attribute.name.clone()),
+                        location: "Global".into(),
+                        warning_type: WarningType::DocstringWarning,
+                        positions: attribute.position.into_iter().collect(),
+                    });
+                }
+
+                if attribute.term.is_none() {
+                    self.add_warning(ValidationWarning {
+                        message: format!("Property '{}' has no term annotation.", attribute.name),
+                        object: Some(object.name.clone()),
+                        attribute: Some(attribute.name.clone()),
+                        location: "Global".into(),
+                        warning_type: WarningType::TermWarning,
+                        positions: attribute.position.into_iter().collect(),
+                    });
+                }
+            }
+        }
+
+        for enum_ in &model.enums {
+            if enum_.mappings.len() < 2 {
+                self.add_warning(ValidationWarning {
+                    message: format!("Enumeration '{}' has fewer than two values.", enum_.name),
+                    object: Some(enum_.name.clone()),
+                    attribute: None,
+                    location: "Global".into(),
+                    warning_type: WarningType::EnumWarning,
+                    positions: enum_.position.into_iter().collect(),
+                });
+            }
+        }
     }
 
     /// Checks the data type of attribute.
@@ -455,7 +1023,7 @@ impl Validator {
             return;
         }
 
-        if !types.contains(&dtype) && !BASIC_TYPES.contains(&dtype) {
+        if !types.contains(&dtype) {
             self.add_error(ValidationError {
                 message: format!(
                     "Type '{}' of property '{}' not found. Either define the type or use a base type.",
@@ -479,9 +1047,19 @@ impl Validator {
     ///
     /// * `name` - The name of the attribute to be validated.
     /// * `obj_name` - The name of the object that contains the attribute.
-    fn validate_attribute_name(&mut self, name: &str, object: &Object) {
+    /// * `allow_underscore_prefix` - Whether the name may start with an underscore.
+    fn validate_attribute_name(
+        &mut self,
+        name: &str,
+        object: &Object,
+        allow_underscore_prefix: bool,
+    ) {
         let checks = vec![
-            starts_with_character,
+            if allow_underscore_prefix {
+                starts_with_character_or_underscore
+            } else {
+                starts_with_character
+            },
             contains_white_space,
             contains_special_characters,
         ];
@@ -502,7 +1080,11 @@ impl Validator {
         }
     }
 
-    /// Extracts the type names from the data model.
+    /// Extracts the type names that attributes of the data model may use.
+    ///
+    /// Besides objects and enumerations, these are the basic types and the
+    /// `extra-basic-types` of the front matter. With `strict-types` enabled,
+    /// the basic types have to be declared explicitly.
     ///
     /// # Arguments
     ///
@@ -512,23 +1094,40 @@ impl Validator {
     ///
     /// A vector of type names.
     fn extract_type_names(model: &DataModel) -> Vec<&str> {
-        let types = model
+        let strict_types = model.config().strict_types;
+        let basic_types = if strict_types {
+            &[][..]
+        } else {
+            &BASIC_TYPES[..]
+        };
+        let extra_basic_types = model
+            .config()
+            .extra_basic_types
+            .iter()
+            .map(|dtype| dtype.as_str());
+
+        model
             .objects
             .iter()
             .map(|object| object.name.as_str())
             .chain(model.enums.iter().map(|enum_| enum_.name.as_str()))
-            .collect::<Vec<&str>>();
-        types
+            .chain(basic_types.iter().copied())
+            .chain(extra_basic_types)
+            .collect::<Vec<&str>>()
     }
 
     /// Sorts the validation errors by their line number, allowing for easier identification
     /// of issues in the source code. The sorting is done in-place on the `errors` vector.
     fn sort_errors(&mut self) {
-        self.errors.sort_by(|a, b| {
+        let by_line = |a: &ValidationError, b: &ValidationError| {
             let line_a = a.positions.first().map(|pos| pos.line);
             let line_b = b.positions.first().map(|pos| pos.line);
             line_a.cmp(&line_b)
-        });
+        };
+
+        self.errors.sort_by(by_line);
+        self.warnings
+            .sort_by_key(|warning| warning.positions.first().map(|pos| pos.line));
     }
 }
 
@@ -580,6 +1179,108 @@ fn get_duplicates<'a>(collection: &'a [&'a str]) -> Vec<&'a str> {
     duplicates.into_iter().collect()
 }
 
+/// Checks whether the given name is a valid object name.
+///
+/// # Arguments
+///
+/// * `name` - A string slice that holds the name to be checked.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(())` if the name is valid.
+/// - `Err(Vec<String>)` with the messages of all failed checks.
+pub(crate) fn check_object_name(name: &str) -> Result<(), Vec<String>> {
+    let checks = [
+        starts_with_character,
+        contains_white_space,
+        contains_special_characters,
+    ];
+
+    let messages: Vec<String> = checks
+        .iter()
+        .filter_map(|check| check(name).err())
+        .collect();
+
+    if messages.is_empty() {
+        Ok(())
+    } else {
+        Err(messages)
+    }
+}
+
+/// Checks if the `MinItems` and `MaxItems` options of an attribute form a valid range.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be checked.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(())` if at most one bound is given or the minimum does not exceed the maximum.
+/// - `Err(String)` if the minimum exceeds the maximum.
+fn validate_item_bounds(attribute: &Attribute) -> Result<(), String> {
+    match (attribute.get_min_items(), attribute.get_max_items()) {
+        (Some(min), Some(max)) if min > max => Err(format!(
+            "MinItems '{}' of property '{}' exceeds MaxItems '{}'.",
+            min, attribute.name, max
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks if the default value of an attribute is compatible with its type.
+///
+/// Attributes without types, arrays and attributes with a type that is not
+/// a basic type, such as objects and enumerations, are not checked. For
+/// attributes with multiple types the default must fit any of them.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be checked.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(())` if the default is compatible or cannot be checked.
+/// - `Err(String)` if the default does not fit the type of the attribute.
+fn validate_default_type(attribute: &Attribute) -> Result<(), String> {
+    let Some(default) = &attribute.default else {
+        return Ok(());
+    };
+
+    if attribute.dtypes.is_empty() || attribute.is_array || attribute.has_complex_type() {
+        return Ok(());
+    }
+
+    let compatible = attribute.dtypes.iter().any(|dtype| match dtype.as_str() {
+        "integer" | "bigint" => default.is_integer(),
+        "float" | "number" | "decimal" => default.is_integer() || default.is_float(),
+        "boolean" => default.is_boolean(),
+        "date" | "bytes" => default.is_string(),
+        _ => true,
+    });
+
+    if compatible {
+        return Ok(());
+    }
+
+    let value = match default {
+        DataType::Boolean(b) => b.to_string(),
+        DataType::Integer(i) => i.to_string(),
+        DataType::Float(f) => f.to_string(),
+        DataType::String(s) => s.trim_matches('"').to_string(),
+    };
+
+    Err(format!(
+        "Default value '{}' of property '{}' is not compatible with type '{}'.",
+        value,
+        attribute.name,
+        attribute.dtypes.join(", ")
+    ))
+}
+
 /// Checks if the given name starts with an alphabetic character.
 ///
 /// # Arguments
@@ -598,6 +1299,27 @@ fn starts_with_character(name: &str) -> Result<(), String> {
     }
 }
 
+/// Checks if the given name starts with an alphabetic character or an underscore.
+///
+/// # Arguments
+///
+/// * `name` - A string slice that holds the name to be checked.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(())` if the name starts with an alphabetic character or an underscore.
+/// - `Err(String)` if the name starts with any other character.
+fn starts_with_character_or_underscore(name: &str) -> Result<(), String> {
+    match name.chars().next() {
+        Some(c) if c.is_alphabetic() || c == '_' => Ok(()),
+        _ => Err(format!(
+            "Name '{}' must start with a letter or an underscore.",
+            name
+        )),
+    }
+}
+
 /// Checks if the given name contains whitespace.
 ///
 /// # Arguments
@@ -610,14 +1332,14 @@ fn starts_with_character(name: &str) -> Result<(), String> {
 /// - `Ok(())` if the name does not contain whitespace.
 /// - `Err(String)` if the name contains whitespace.
 fn contains_white_space(name: &str) -> Result<(), String> {
-    name.contains(' ')
-        .then(|| {
-            Err(format!(
-                "Name '{}' contains whitespace, which is not valid. Use underscores instead.",
-                name
-            ))
-        })
-        .unwrap_or(Ok(()))
+    if name.contains(' ') {
+        Err(format!(
+            "Name '{}' contains whitespace, which is not valid. Use underscores instead.",
+            name
+        ))
+    } else {
+        Ok(())
+    }
 }
 
 /// Checks if the given name contains special characters, except for underscores.
@@ -632,9 +1354,17 @@ fn contains_white_space(name: &str) -> Result<(), String> {
 /// - `Ok(())` if the name does not contain special characters.
 /// - `Err(String)` if the name contains special characters.
 fn contains_special_characters(name: &str) -> Result<(), String> {
-    name.chars().any(|c| !c.is_alphanumeric() && c != '_' && c != ' ').then(
-        || Err(format!("Name '{}' contains special characters, which are not valid except for underscores.", name))
-    ).unwrap_or(Ok(()))
+    if name
+        .chars()
+        .any(|c| !c.is_alphanumeric() && c != '_' && c != ' ')
+    {
+        Err(format!(
+            "Name '{}' contains special characters, which are not valid except for underscores.",
+            name
+        ))
+    } else {
+        Ok(())
+    }
 }
 
 /// Extracts the positions of all objects in the data model.
@@ -0,0 +1,44 @@
+// This is a generated file. Do not modify it manually!
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json.Serialization;
+
+public record Test
+{
+    /// <summary>The name of the test.</summary>
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    [JsonPropertyName("number")]
+    public double Number { get; init; } = 1.0;
+
+    [JsonPropertyName("test2")]
+    public List<Test2> Test2 { get; init; } = new();
+
+    [JsonPropertyName("ontology")]
+    public Ontology? Ontology { get; init; }
+}
+
+public record Test2
+{
+    [JsonPropertyName("names")]
+    public List<string> Names { get; init; } = new();
+
+    [JsonPropertyName("number")]
+    public double? Number { get; init; }
+}
+
+/// <summary>
+/// Ontology endpoints for different types of sequences.
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter<Ontology>))]
+public enum Ontology
+{
+    [JsonStringEnumMemberName("https://www.evidenceontology.org/term/")]
+    ECO,
+    [JsonStringEnumMemberName("https://amigo.geneontology.org/amigo/term/")]
+    GO,
+    [JsonStringEnumMemberName("http://semanticscience.org/resource/")]
+    SIO,
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+// This is a generated file. Do not modify it manually!
+
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+@Serializable
+data class Test(
+    /** The name of the test. */
+    val name: String,
+    val number: Double = 1.0,
+    val test2: List<Test2> = emptyList(),
+    val ontology: Ontology? = null,
+)
+
+@Serializable
+data class Test2(
+    val names: List<String> = emptyList(),
+    val number: Double? = null,
+)
+
+/**
+ * Ontology endpoints for different types of sequences.
+ */
+@Serializable
+enum class Ontology(val value: String) {
+    @SerialName("https://www.evidenceontology.org/term/")
+    ECO("https://www.evidenceontology.org/term/"),
+    @SerialName("https://amigo.geneontology.org/amigo/term/")
+    GO("https://amigo.geneontology.org/amigo/term/"),
+    @SerialName("http://semanticscience.org/resource/")
+    SIO("http://semanticscience.org/resource/");
+}
\ No newline at end of file
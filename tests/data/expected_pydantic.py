@@ -6,6 +6,7 @@ from typing import Optional, Generic, TypeVar
 from enum import Enum
 from uuid import uuid4
 from datetime import date, datetime
+from decimal import Decimal
 
 # Filter Wrapper definition used to filter a list of objects
 # based on their attributes
@@ -0,0 +1,26 @@
+## This is a generated file. Do not modify it manually!
+
+from __future__ import annotations
+from attrs import Factory, define, field, validators
+from typing import List, Optional
+from enum import Enum
+from datetime import date, datetime
+from decimal import Decimal
+
+
+@define
+class Test:
+    name: str
+    number: float = field(default=1.0)
+    test2: List[Test2] = field(default=Factory(list))
+    ontology: Optional[Ontology] = field(default=None)
+
+@define
+class Test2:
+    names: List[str] = field(default=Factory(list))
+    number: Optional[float] = field(default=None, validator=validators.optional([validators.ge(0)]))
+
+class Ontology(Enum):
+    ECO = "https://www.evidenceontology.org/term/"
+    GO = "https://amigo.geneontology.org/amigo/term/"
+    SIO = "http://semanticscience.org/resource/"
\ No newline at end of file
@@ -7,6 +7,7 @@ from typing import List, Optional
 from enum import Enum
 from uuid import uuid4
 from datetime import date, datetime
+from decimal import Decimal
 
 
 @dataclass_json
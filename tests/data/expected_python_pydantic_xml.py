@@ -4,6 +4,7 @@ from __future__ import annotations
 from typing import Dict, List, Optional
 from uuid import uuid4
 from datetime import date, datetime
+from decimal import Decimal
 from xml.dom import minidom
 
 from lxml.etree import _Element
@@ -0,0 +1,27 @@
+// This is a generated file. Do not modify it manually!
+
+case class Test(
+  /** The name of the test. */
+  name: String,
+  number: Double = 1.0,
+  test2: List[Test2] = Nil,
+  ontology: Option[Ontology] = None,
+)
+
+case class Test2(
+  names: List[String] = Nil,
+  number: Option[Double] = None,
+)
+
+/**
+ * Ontology endpoints for different types of sequences.
+ */
+sealed trait Ontology(val value: String)
+
+object Ontology {
+  case object ECO extends Ontology("https://www.evidenceontology.org/term/")
+  case object GO extends Ontology("https://amigo.geneontology.org/amigo/term/")
+  case object SIO extends Ontology("http://semanticscience.org/resource/")
+
+  val values: List[Ontology] = List(ECO, GO, SIO)
+}
\ No newline at end of file
@@ -59,6 +59,9 @@ export const Test2Codec = D.lazy("Test2", () => D.struct({
 
 
 // none Enum definitions
+/**
+ *  Ontology endpoints for different types of sequences.
+**/
 export enum Ontology {
   ECO = 'https://www.evidenceontology.org/term/',
   GO = 'https://amigo.geneontology.org/amigo/term/',
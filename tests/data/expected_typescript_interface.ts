@@ -0,0 +1,21 @@
+// This is a generated file. Do not modify it manually!
+
+export interface Test {
+  /**
+   * The name of the test.
+   */
+  name: string;
+  number?: number;
+  test2?: Test2[];
+  ontology?: Ontology;
+}
+
+export interface Test2 {
+  names?: string[];
+  number?: number;
+}
+
+/**
+ * Ontology endpoints for different types of sequences.
+ */
+export type Ontology = "https://www.evidenceontology.org/term/" | "https://amigo.geneontology.org/amigo/term/" | "http://semanticscience.org/resource/";
\ No newline at end of file
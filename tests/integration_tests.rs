@@ -25,9 +25,15 @@ extern crate mdmodels;
 
 #[cfg(test)]
 mod tests {
-    use mdmodels::{self, datamodel::DataModel};
+    use mdmodels::{
+        self,
+        datamodel::{ConflictBehavior, DataModel, MarkdownDirError, MergeError, MergeStrategy},
+        exporters::Templates,
+        json::export::{to_json_schema, JsonSchemaDraft, JsonSchemaInheritance},
+        validation::{Validator, WarningType},
+    };
     use pretty_assertions::assert_eq;
-    use std::path::Path;
+    use std::{fs, path::Path};
 
     #[test]
     fn test_parse() {
@@ -47,9 +53,9 @@ mod tests {
         assert!(obj_names.contains(&"Test2".to_string()));
 
         // Check if the attributes are present
-        let test1 = model.objects.iter().find(|o| o.name == *"Test").unwrap();
+        let test1 = model.get_object("Test").unwrap();
 
-        let test2 = model.objects.iter().find(|o| o.name == *"Test2").unwrap();
+        let test2 = model.get_object("Test2").unwrap();
 
         assert_eq!(test1.attributes.len(), 4);
         assert_eq!(test2.attributes.len(), 2);
@@ -67,16 +73,12 @@ mod tests {
         assert_eq!(test1_attr_names, expected);
 
         // Check if the datatypes are correct
-        let test1_name_attribute = test1.attributes.iter().find(|a| a.name == *"name").unwrap();
+        let (_, test1_name_attribute) = model.find_attribute("Test", "name").unwrap();
 
         assert!(test1_name_attribute.dtypes.contains(&"string".to_string()));
 
         // Check if multiple datatypes are correct
-        let test2_names_attribute = test2
-            .attributes
-            .iter()
-            .find(|a| a.name == *"names")
-            .unwrap();
+        let (_, test2_names_attribute) = model.find_attribute("Test2", "names").unwrap();
 
         assert!(test2_names_attribute.dtypes.contains(&"string".to_string()));
         assert!(test2_names_attribute.is_array);
@@ -160,6 +162,192 @@ mod tests {
         assert_eq!(schema, expected);
     }
 
+    #[test]
+    fn test_json_schema_draft07() {
+        // Arrange
+        let path = Path::new("tests/data/model_json_schema.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema_draft07(None)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let expected =
+            std::fs::read_to_string("tests/data/expected_json_schema_draft07.json").unwrap();
+
+        assert_eq!(schema, expected);
+        assert!(!schema.contains("$defs"));
+    }
+
+    #[test]
+    fn test_json_schema_allof_inheritance() {
+        // Arrange
+        let path = Path::new("tests/data/model_inheritance.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = to_json_schema(
+            &model,
+            "Test",
+            false,
+            JsonSchemaDraft::Draft202012,
+            JsonSchemaInheritance::AllOf,
+        )
+        .expect("Could not generate JSON schema");
+        let schema = serde_json::to_string_pretty(&schema).unwrap();
+
+        // Assert
+        let expected =
+            std::fs::read_to_string("tests/data/expected_json_schema_allof.json").unwrap();
+
+        assert_eq!(schema, expected);
+
+        // A child of the root references the root schema itself
+        let path = Path::new("tests/data/model_inheritance_root.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let schema = to_json_schema(
+            &model,
+            "Base",
+            false,
+            JsonSchemaDraft::Draft202012,
+            JsonSchemaInheritance::AllOf,
+        )
+        .expect("Could not generate JSON schema");
+        let schema = schema.to_value().unwrap();
+
+        let definitions = schema["$defs"].as_object().unwrap();
+        assert_eq!(definitions.keys().collect::<Vec<_>>(), vec!["Kid"]);
+        assert_eq!(schema["properties"]["kids"]["items"]["$ref"], "#/$defs/Kid");
+
+        let kid = &definitions["Kid"];
+        assert_eq!(kid["allOf"][0]["$ref"], "#");
+        assert_eq!(kid["allOf"][1]["required"], serde_json::json!([]));
+        assert!(kid.get("properties").is_none());
+        assert!(kid.get("required").is_none());
+    }
+
+    #[test]
+    fn test_json_schema_deprecated() {
+        // Arrange
+        let path = Path::new("tests/data/model_deprecated.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(None, false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(schema["properties"]["label"]["deprecated"], true);
+        assert!(schema["properties"]["name"].get("deprecated").is_none());
+        assert!(model.objects[0].attributes[1].deprecated);
+    }
+
+    #[test]
+    fn test_json_schema_alias() {
+        // Arrange
+        let path = Path::new("tests/data/model_alias.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(None, false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(schema["properties"]["gene_name"]["title"], "geneName");
+        assert_eq!(schema["properties"]["organism"]["title"], "organism");
+    }
+
+    #[test]
+    fn test_to_plantuml_inheritance() {
+        // Arrange
+        let path = Path::new("tests/data/model_inheritance.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let diagram = model.to_plantuml();
+
+        // Assert
+        assert!(diagram.starts_with("@startuml"));
+        assert!(diagram.ends_with("@enduml"));
+        assert!(diagram.contains("class Test {\n  +name: string\n  +number?: integer\n}"));
+        assert!(diagram.contains("Something <|-- Test"));
+    }
+
+    #[test]
+    fn test_json_schema_readonly() {
+        // Arrange
+        let path = Path::new("tests/data/model_readonly.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Sample".to_string()), false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(schema["properties"]["id"]["readOnly"], true);
+        assert!(schema["properties"]["name"].get("readOnly").is_none());
+    }
+
+    #[test]
+    fn test_json_schema_const() {
+        // Arrange
+        let path = Path::new("tests/data/model_const.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Circle".to_string()), false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(schema["properties"]["kind"]["const"], "circle");
+        assert_eq!(schema["properties"]["version"]["const"], 2);
+        assert!(schema["properties"]["radius"].get("const").is_none());
+
+        let imported = DataModel::from_json_schema_str(&schema.to_string())
+            .expect("Could not import JSON schema");
+        let (_, kind) = imported
+            .find_attribute("Circle", "kind")
+            .expect("Attribute not found");
+        assert_eq!(kind.const_value, model.objects[0].attributes[0].const_value);
+    }
+
+    #[test]
+    fn test_json_schema_attribute_examples() {
+        // Arrange
+        let path = Path::new("tests/data/model_attribute_examples.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(None, false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(
+            schema["properties"]["name"]["examples"],
+            serde_json::json!(["Sample A", "Sample B, diluted"])
+        );
+        assert_eq!(
+            schema["properties"]["replicates"]["examples"],
+            serde_json::json!([1, 2, 3])
+        );
+        assert_eq!(
+            schema["properties"]["concentration"]["examples"],
+            serde_json::json!([0.5, 1.25])
+        );
+    }
+
     #[test]
     fn test_json_schema_openai() {
         // Arrange
@@ -246,7 +434,32 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    fn test_prune_unused_enums() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let path2 = Path::new("tests/data/model_merge.md");
+        let model2 = DataModel::from_markdown(path2).expect("Could not parse markdown");
+        model.merge(&model2);
+
+        let unreferenced: Vec<&str> = model
+            .unreferenced_enums()
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(unreferenced, vec!["AddedEnum"]);
+
+        // Act
+        model.objects.retain(|o| o.name != "Test");
+        let removed = model.prune_unused_enums();
+
+        // Assert
+        assert_eq!(removed, vec!["Ontology", "AddedEnum"]);
+        assert!(model.enums.is_empty());
+        assert!(model.prune_unused_enums().is_empty());
+    }
+
+    #[test]
     fn test_model_merge_invalid() {
         // Arrange
         let path = Path::new("tests/data/model.md");
@@ -256,6 +469,261 @@ mod tests {
 
         // Act
         model.merge(&model2);
+
+        // Assert
+        assert_eq!(model.objects.len(), 2);
+        assert_eq!(model.objects[0].attributes[0].name, "name");
+        assert!(model.enums[0].mappings.contains_key("GO"));
+    }
+
+    #[test]
+    fn test_model_merge_strict() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let original = model.clone();
+        let invalid = DataModel::from_markdown(Path::new("tests/data/model_merge_invalid.md"))
+            .expect("Could not parse markdown");
+        let valid = DataModel::from_markdown(Path::new("tests/data/model_merge.md"))
+            .expect("Could not parse markdown");
+
+        // Act
+        let conflict = model.merge_strict(&invalid);
+
+        // Assert
+        assert_eq!(
+            conflict,
+            Err(MergeError {
+                conflicting_objects: vec!["Test".to_string()],
+                conflicting_enums: vec!["Ontology".to_string()],
+            })
+        );
+        assert_eq!(model, original);
+
+        assert!(model.merge_strict(&valid).is_ok());
+        assert!(model.get_object("Added").is_some());
+    }
+
+    fn merge_with(behavior: ConflictBehavior) -> (DataModel, Result<(), MergeError>) {
+        let path = Path::new("tests/data/model.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let path2 = Path::new("tests/data/model_merge_invalid.md");
+        let model2 = DataModel::from_markdown(path2).expect("Could not parse markdown");
+
+        let result = model.merge_with_strategy(
+            &model2,
+            MergeStrategy {
+                on_conflict: behavior,
+            },
+        );
+
+        (model, result)
+    }
+
+    #[test]
+    fn test_model_merge_skip() {
+        // Act
+        let (model, result) = merge_with(ConflictBehavior::Skip);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(model.objects.len(), 2);
+        assert_eq!(model.objects[0].attributes[0].name, "name");
+        assert_eq!(model.enums.len(), 1);
+        assert!(model.enums[0].mappings.contains_key("GO"));
+    }
+
+    #[test]
+    fn test_model_merge_override() {
+        // Act
+        let (model, result) = merge_with(ConflictBehavior::Override);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(model.objects.len(), 2);
+        assert_eq!(model.objects[0].name, "Test");
+        assert_eq!(model.objects[0].attributes[0].name, "other");
+        assert_eq!(model.enums.len(), 1);
+        assert!(model.enums[0].mappings.contains_key("OTHER"));
+    }
+
+    #[test]
+    fn test_model_merge_error() {
+        // Act
+        let (model, result) = merge_with(ConflictBehavior::Error);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(MergeError {
+                conflicting_objects: vec!["Test".to_string()],
+                conflicting_enums: vec!["Ontology".to_string()],
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Conflicting definitions: Test, Ontology"
+        );
+        assert_eq!(model.objects[0].attributes[0].name, "name");
+    }
+
+    /// Creates a temporary directory with two models and a nested subdirectory
+    fn markdown_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().expect("Could not create temporary directory");
+        let nested = dir.path().join("nested");
+        fs::create_dir(&nested).expect("Could not create subdirectory");
+
+        fs::copy("tests/data/model.md", dir.path().join("a.md")).expect("Could not copy model");
+        fs::copy("tests/data/model_merge.md", dir.path().join("b.md"))
+            .expect("Could not copy model");
+        fs::copy("tests/data/model_subset.md", nested.join("c.md")).expect("Could not copy model");
+        fs::write(dir.path().join("notes.txt"), "Not a model").expect("Could not write file");
+
+        dir
+    }
+
+    #[test]
+    fn test_from_markdown_dir() {
+        // Arrange
+        let dir = markdown_dir();
+
+        // Act
+        let model = DataModel::from_markdown_dir(dir.path(), false).expect("Could not load dir");
+
+        // Assert
+        let names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, vec!["Test", "Test2", "Added"]);
+        assert_eq!(
+            model.name.as_deref(),
+            dir.path().file_name().and_then(|name| name.to_str())
+        );
+    }
+
+    #[test]
+    fn test_from_markdown_dir_recursive() {
+        // Arrange
+        let dir = markdown_dir();
+
+        // Act
+        let model = DataModel::from_markdown_dir(dir.path(), true).expect("Could not load dir");
+
+        // Assert
+        let names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Test",
+                "Test2",
+                "Added",
+                "Dataset",
+                "Measurement",
+                "Sample",
+                "Author"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_markdown_dir_invalid_files() {
+        // Arrange
+        let dir = markdown_dir();
+        fs::write(
+            dir.path().join("c.md"),
+            "### Other\n\n- missing\n  - Type: Missing\n",
+        )
+        .expect("Could not write model");
+
+        // Act
+        let error = DataModel::from_markdown_dir(dir.path(), false)
+            .expect_err("Invalid files should be reported");
+
+        // Assert
+        let error = error
+            .downcast::<MarkdownDirError>()
+            .expect("Expected a MarkdownDirError");
+        let files: Vec<&Path> = error.files.iter().map(|(path, _)| path.as_path()).collect();
+        assert_eq!(files, vec![dir.path().join("c.md")]);
+        assert!(error.files.iter().all(|(_, validator)| !validator.is_valid));
+        assert!(error.to_string().contains("c.md:\n"));
+    }
+
+    #[test]
+    fn test_from_markdown_dir_cross_file_references() {
+        // Arrange
+        let dir = tempfile::tempdir().expect("Could not create temporary directory");
+        fs::write(
+            dir.path().join("a.md"),
+            "### A\n\n- b\n  - Type: B\n- kind\n  - Type: Kind\n",
+        )
+        .expect("Could not write model");
+        fs::write(
+            dir.path().join("b.md"),
+            "### B [Base]\n\n- value\n  - Type: float\n\n## Enumerations\n\n### Kind\n\n```python\nX = \"x\"\n```\n",
+        )
+        .expect("Could not write model");
+        fs::write(
+            dir.path().join("c.md"),
+            "### Base\n\n- id\n  - Type: string\n",
+        )
+        .expect("Could not write model");
+
+        // Act
+        let model = DataModel::from_markdown_dir(dir.path(), false)
+            .expect("References across files should resolve");
+
+        // Assert
+        let a = model.get_object("A").unwrap();
+        assert_eq!(a.attributes[0].dtypes, vec!["B"]);
+        assert!(a.attributes[1].is_enum);
+
+        let b = model.get_object("B").unwrap();
+        let names: Vec<&str> = b.attributes.iter().map(|a| a.name.as_str()).collect();
+        assert!(names.contains(&"id"), "{:?}", names);
+    }
+
+    #[test]
+    fn test_from_markdown_dir_merges_front_matter() {
+        // Arrange
+        let dir = tempfile::tempdir().expect("Could not create temporary directory");
+        fs::write(
+            dir.path().join("a.md"),
+            "---\nprefix: tst\nmax-nesting-depth: 5\n---\n\n### A\n\n- name\n  - Type: string\n  - MyCustomOption: custom value\n",
+        )
+        .expect("Could not write model");
+        fs::write(
+            dir.path().join("b.md"),
+            "---\nprefix: other\nstrict: true\nstrict-types: true\nmax-nesting-depth: 3\nallow-unknown-options:\n  - MyCustomOption\nextra-basic-types:\n  - string\n  - uri\n---\n\n### B\n\n- link\n  - Type: uri\n",
+        )
+        .expect("Could not write model");
+
+        // Act
+        let model = DataModel::from_markdown_dir(dir.path(), false)
+            .expect("Options of all files should apply to the merged model");
+
+        // Assert
+        let config = model.config();
+        assert_eq!(config.prefix, "tst");
+        assert!(config.strict);
+        assert!(config.strict_types);
+        assert_eq!(config.max_nesting_depth, Some(3));
+        assert_eq!(config.allow_unknown_options, vec!["MyCustomOption"]);
+        assert_eq!(config.extra_basic_types, vec!["string", "uri"]);
+
+        let a = model.get_object("A").unwrap();
+        assert_eq!(a.attributes[0].custom_options.len(), 1);
+        assert_eq!(a.attributes[0].custom_options[0].key, "mycustomoption");
+    }
+
+    #[test]
+    fn test_from_markdown_dir_empty() {
+        // Arrange
+        let dir = tempfile::tempdir().expect("Could not create temporary directory");
+
+        // Act
+        let result = DataModel::from_markdown_dir(dir.path(), true);
+
+        // Assert
+        assert!(result.is_err());
     }
 
     #[test]
@@ -288,6 +756,90 @@ mod tests {
         assert_eq!(schema, expected_schema);
     }
 
+    #[test]
+    fn test_inheritance_circular() {
+        // Arrange
+        let path = Path::new("tests/data/model_inheritance_circular.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        let validator = result.expect_err("Circular inheritance should be invalid");
+        let messages: Vec<&str> = validator
+            .errors
+            .iter()
+            .map(|error| error.message.as_str())
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Circular inheritance detected: A -> B -> A",
+                "Parent 'Missing' does not exist.",
+            ]
+        );
+        assert!(validator
+            .errors
+            .iter()
+            .all(|error| error.error_type == mdmodels::validation::ErrorType::ObjectError));
+        assert!(!validator.errors[0].positions.is_empty());
+    }
+
+    #[test]
+    fn test_validator_reset_between_models() {
+        // Arrange
+        let invalid =
+            DataModel::from_markdown(Path::new("tests/data/model_inheritance_circular.md"))
+                .expect_err("Circular inheritance should be invalid");
+        let valid = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Could not parse markdown");
+        let mut validator = invalid;
+
+        // Act
+        validator.validate(&valid);
+
+        // Assert
+        assert!(validator.is_valid);
+        assert!(validator.errors.is_empty());
+    }
+
+    #[test]
+    fn test_circular_references() {
+        // Arrange
+        let path = Path::new("tests/data/model_circular_references.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        let validator = result.expect_err("Required circular references should be invalid");
+        assert_eq!(validator.errors.len(), 1);
+        assert_eq!(
+            validator.errors[0].message,
+            "Circular reference detected: Experiment -> Protocol -> Experiment"
+        );
+        assert_eq!(
+            validator.errors[0].error_type,
+            mdmodels::validation::ErrorType::ObjectError
+        );
+        assert!(!validator.errors[0].positions.is_empty());
+    }
+
+    #[test]
+    fn test_inheritance_nested() {
+        // Arrange
+        let path = Path::new("tests/data/model_inheritance_nested.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let child = model.get_object("Child").unwrap();
+        let names: Vec<&str> = child.attributes.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["child", "parent", "root"]);
+    }
+
     #[test]
     fn test_no_frontmatter() {
         // Arrange
@@ -326,11 +878,228 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn test_multiple_types_invalid() {
-        let path = Path::new("tests/data/model_multiple_types_invalid.md");
-        DataModel::from_markdown(path).expect("Could not parse markdown");
-    }
+    fn test_invalid_pattern() {
+        // Arrange
+        let path = Path::new("tests/data/model_invalid_pattern.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        let validator = result.expect_err("Invalid patterns should be rejected");
+        assert_eq!(validator.errors.len(), 1);
+
+        let error = &validator.errors[0];
+        assert_eq!(error.attribute, Some("barcode".to_string()));
+        assert_eq!(error.error_type, mdmodels::validation::ErrorType::TypeError);
+        assert!(error.message.starts_with(
+            "Pattern '^(ABC' of property 'barcode' is not a valid regular expression"
+        ));
+        assert_eq!(error.positions[0].line, 12);
+    }
+
+    #[test]
+    fn test_invalid_frontmatter() {
+        // Arrange
+        let path = Path::new("tests/data/model_invalid_frontmatter.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        let validator = result.expect_err("Invalid front matter should be rejected");
+        let messages: Vec<&str> = validator
+            .errors
+            .iter()
+            .map(|error| error.message.as_str())
+            .collect();
+
+        assert_eq!(messages.len(), 4);
+        assert!(messages[0].starts_with("Repository 'not a url' is not a valid URL"));
+        assert!(messages[1].starts_with("URI 'relative/path' of prefix 'my:ns'"));
+        assert_eq!(
+            messages[2],
+            "Namespace prefix 'my:ns' contains special characters."
+        );
+        assert_eq!(
+            messages[3],
+            "Prefix 'tst' is declared in both prefixes and nsmap with different URIs."
+        );
+        assert!(validator
+            .errors
+            .iter()
+            .all(|error| error.error_type == mdmodels::validation::ErrorType::GlobalError));
+    }
+
+    #[test]
+    fn test_strict_types() {
+        // Arrange
+        let path = Path::new("tests/data/model_strict_types.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        let validator = result.expect_err("Undeclared basic types should be rejected");
+        assert_eq!(validator.errors.len(), 1);
+
+        let error = &validator.errors[0];
+        assert_eq!(error.attribute, Some("name".to_string()));
+        assert_eq!(error.error_type, mdmodels::validation::ErrorType::TypeError);
+        assert!(error
+            .message
+            .starts_with("Type 'string' of property 'name' not found"));
+    }
+
+    #[test]
+    fn test_extra_basic_types() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model_strict_types.md")
+            .expect("Could not read file")
+            .replace("strict-types: true\n", "");
+
+        // Act
+        let model = DataModel::from_markdown_string(&content).expect("Could not parse markdown");
+
+        // Assert
+        let config = model.config.expect("Config not found");
+        assert!(!config.strict_types);
+        assert_eq!(config.extra_basic_types, vec!["uri".to_string()]);
+    }
+
+    #[test]
+    fn test_allow_underscore_prefix() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model_underscore_prefix.md")
+            .expect("Could not read file");
+
+        // Act
+        let model = DataModel::from_markdown_string(&content).expect("Could not parse markdown");
+        let result =
+            DataModel::from_markdown_string(&content.replace("allowUnderscorePrefix: true\n", ""));
+
+        // Assert
+        let attributes: Vec<&str> = model.objects[0]
+            .attributes
+            .iter()
+            .map(|attr| attr.name.as_str())
+            .collect();
+        assert_eq!(attributes, vec!["_id", "_type", "content"]);
+        assert!(model.config.unwrap().allow_underscore_prefix);
+
+        let errors = result
+            .expect_err("Underscore prefix should be invalid")
+            .errors;
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].message.contains("must start with a letter"));
+    }
+
+    #[test]
+    fn test_array_item_constraints() {
+        // Arrange
+        let path = Path::new("tests/data/model_array_constraints.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Sample".to_string()), false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let tags = &schema["properties"]["tags"];
+        assert_eq!(tags["type"], "array");
+        assert_eq!(tags["minItems"], 1);
+        assert_eq!(tags["maxItems"], 10);
+        assert!(tags.get("minitems").is_none());
+
+        let name = schema["properties"]["name"].as_object().unwrap();
+        assert!(!name.contains_key("minItems"));
+        assert!(!name.contains_key("minitems"));
+    }
+
+    #[test]
+    fn test_array_item_constraints_invalid_range() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model_array_constraints.md")
+            .expect("Could not read file")
+            .replace("MaxItems: 10", "MaxItems: 0");
+
+        // Act
+        let result = DataModel::from_markdown_string(&content);
+
+        // Assert
+        let validator = result.expect_err("MinItems above MaxItems should be rejected");
+        assert_eq!(validator.errors.len(), 1);
+        assert_eq!(
+            validator.errors[0].message,
+            "MinItems '1' of property 'tags' exceeds MaxItems '0'."
+        );
+    }
+
+    #[test]
+    fn test_extra_basic_types_generation() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model_strict_types.md")
+            .expect("Could not read file")
+            .replace("strict-types: true\n", "")
+            .replace("extra-basic-types:", "extraBasicTypes:");
+        let mut model =
+            DataModel::from_markdown_string(&content).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Sample".to_string()), false)
+            .expect("Could not generate JSON schema");
+        let python = model
+            .convert_to(&Templates::PythonDataclass, None)
+            .expect("Could not render template");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(schema["properties"]["homepage"]["type"], "string");
+        assert_eq!(
+            schema["$defs"]["Child"]["properties"]["link"]["type"],
+            "string"
+        );
+        assert!(python.contains("homepage: Optional[uri]"));
+    }
+
+    #[test]
+    fn test_invalid_default() {
+        // Arrange
+        let path = Path::new("tests/data/model_invalid_default.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        let validator = result.expect_err("Incompatible defaults should be rejected");
+        let attributes: Vec<Option<String>> = validator
+            .errors
+            .iter()
+            .map(|error| error.attribute.clone())
+            .collect();
+        assert_eq!(
+            attributes,
+            vec![Some("count".to_string()), Some("threshold".to_string())]
+        );
+
+        let error = &validator.errors[0];
+        assert_eq!(error.error_type, mdmodels::validation::ErrorType::TypeError);
+        assert_eq!(
+            error.message,
+            "Default value 'foo' of property 'count' is not compatible with type 'integer'."
+        );
+        assert_eq!(error.positions[0].line, 9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multiple_types_invalid() {
+        let path = Path::new("tests/data/model_multiple_types_invalid.md");
+        DataModel::from_markdown(path).expect("Could not parse markdown");
+    }
 
     #[test]
     fn test_multiple_types() {
@@ -391,4 +1160,694 @@ mod tests {
 
         assert_eq!(validation.len(), 0);
     }
+
+    #[test]
+    fn test_json_file_validation_against_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model_json_validation.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let valid_errors = model
+            .validate_json_against_schema(Path::new("tests/data/valid_dataset.json"), None)
+            .expect("Could not validate JSON");
+        let invalid_errors = model
+            .validate_json_against_schema(Path::new("tests/data/invalid_dataset.json"), None)
+            .expect("Could not validate JSON");
+
+        // Assert
+        assert!(valid_errors.is_empty());
+        assert_eq!(invalid_errors.len(), 13);
+    }
+
+    #[test]
+    fn test_json_str_validation() {
+        // Arrange
+        let path = Path::new("tests/data/model_json_validation.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let valid = fs::read_to_string("tests/data/valid_dataset.json").unwrap();
+        let invalid = fs::read_to_string("tests/data/invalid_dataset.json").unwrap();
+
+        // Act
+        let valid_errors = model
+            .validate_json_str_against_schema(&valid, None)
+            .expect("Could not validate JSON");
+        let invalid_errors = model
+            .validate_json_str_against_schema(&invalid, None)
+            .expect("Could not validate JSON");
+
+        // Assert
+        assert!(valid_errors.is_empty());
+        assert_eq!(invalid_errors.len(), 13);
+        assert!(model
+            .validate_json_str_against_schema("{ not json", None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        // Arrange
+        let path = Path::new("tests/data/model_examples.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let object = &model.objects[0];
+        assert_eq!(model.enums.len(), 1);
+        assert_eq!(object.attributes.len(), 2);
+        assert_eq!(
+            object.docstring.trim(),
+            "A molecule with a name and a formula."
+        );
+        assert_eq!(
+            object.examples,
+            vec![
+                serde_json::json!({"name": "Water", "formula": "H2O"}),
+                serde_json::json!({"name": "Ethanol", "formula": "C2H6O"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_schema_examples() {
+        // Arrange
+        let path = Path::new("tests/data/model_examples.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Molecule".to_string()), false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(
+            schema["examples"],
+            serde_json::json!([
+                {"name": "Water", "formula": "H2O"},
+                {"name": "Ethanol", "formula": "C2H6O"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_nesting_depth_exceeded() {
+        // Arrange
+        let path = Path::new("tests/data/model_nesting_deep.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        let validator = result.expect_err("Deeply nested model should be invalid");
+        assert_eq!(validator.errors.len(), 1);
+        assert_eq!(
+            validator.errors[0].error_type,
+            mdmodels::validation::ErrorType::GlobalError
+        );
+        assert_eq!(validator.errors[0].object, Some("LevelA".to_string()));
+    }
+
+    #[test]
+    fn test_nesting_depth_custom_limit() {
+        // Arrange
+        let path = Path::new("tests/data/model_nesting_limit.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        let validator = result.expect_err("Model exceeding the custom limit should be invalid");
+        assert!(validator.errors[0]
+            .message
+            .contains("LevelA -> LevelB -> LevelC -> LevelD"));
+    }
+
+    #[test]
+    fn test_nesting_depth_cycle_independent_of_names() {
+        for [a, b, c, d] in [["A", "B", "C", "D"], ["Z", "Y", "X", "A"]] {
+            // Arrange
+            let object = |name: &str, child: &str| {
+                format!("### {}\n\n- child\n  - Type: {}\n\n", name, child)
+            };
+            let content = format!(
+                "---\nmax-nesting-depth: 2\n---\n\n{}{}{}{}",
+                object(a, b),
+                object(b, c),
+                object(c, a),
+                object(d, c)
+            );
+
+            // Act
+            let result = DataModel::from_markdown_string(&content);
+
+            // Assert
+            let validator = result.expect_err("Cycle referenced by another object is too deep");
+            assert!(
+                validator.errors[0]
+                    .message
+                    .starts_with("Nesting depth of 3 exceeds the maximum of 2"),
+                "{}",
+                validator.errors[0].message
+            );
+        }
+    }
+
+    #[test]
+    fn test_nesting_depth_shallow() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_internal_schema_roundtrip_options() {
+        for path in [
+            "tests/data/model.md",
+            "tests/data/model_const.md",
+            "tests/data/model_readonly.md",
+            "tests/data/model_attribute_examples.md",
+            "tests/data/model_examples.md",
+            "tests/data/model_inheritance.md",
+        ] {
+            // Arrange
+            let model =
+                DataModel::from_markdown(Path::new(path)).expect("Could not parse markdown");
+
+            // Act
+            let schema = model.internal_schema();
+            let restored =
+                DataModel::from_internal_schema(&schema).expect("Could not parse internal schema");
+
+            // Assert
+            assert_eq!(model, restored, "Round trip failed for {}", path);
+        }
+    }
+
+    #[test]
+    fn test_from_proto_file() {
+        // Arrange
+        let path = Path::new("tests/data/model.proto");
+
+        // Act
+        let model = DataModel::from_proto_file(path).expect("Could not import proto file");
+
+        // Assert
+        let test = model.get_object("Test").expect("Object not found");
+        assert_eq!(test.attributes.len(), 7);
+        assert!(model.get_object("Test2").is_some());
+        assert_eq!(model.enums.len(), 2);
+
+        let schema = model
+            .json_schema(Some("Test".to_string()), false)
+            .expect("Could not create JSON schema");
+        assert!(schema.contains("\"ontology\""));
+    }
+
+    #[test]
+    fn test_from_proto_file_missing() {
+        let path = Path::new("tests/data/missing.proto");
+        assert!(DataModel::from_proto_file(path).is_err());
+    }
+
+    #[test]
+    fn test_from_avro_json_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model.avsc");
+
+        // Act
+        let model = DataModel::from_avro(path).expect("Could not import Avro schema");
+        let schema = model
+            .json_schema(Some("Test".to_string()), false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(
+            schema["required"],
+            serde_json::json!(["name", "test2", "ontology", "value", "created"])
+        );
+        assert_eq!(schema["properties"]["test2"]["type"], "array");
+        assert_eq!(schema["properties"]["ontology"]["$ref"], "#/$defs/Ontology");
+        assert!(schema["$defs"]["Test2"].is_object());
+    }
+
+    #[test]
+    fn test_json_schema_draft07_roundtrip() {
+        // Arrange
+        let path = Path::new("tests/data/model_json_schema.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let schema = model
+            .json_schema_draft07(None)
+            .expect("Could not generate JSON schema");
+
+        // Act
+        let imported =
+            DataModel::from_json_schema_str(&schema).expect("Could not import JSON schema");
+
+        // Assert
+        let names = |model: &DataModel| -> Vec<String> {
+            let mut names: Vec<String> = model.objects.iter().map(|o| o.name.clone()).collect();
+            names.extend(model.enums.iter().map(|e| e.name.clone()));
+            names
+        };
+        assert_eq!(names(&imported), names(&model));
+
+        for (object, attribute) in model.all_attributes() {
+            let (_, imported) = imported
+                .find_attribute(&object.name, &attribute.name)
+                .expect("Attribute is missing");
+            assert_eq!(imported.is_array, attribute.is_array, "{}", attribute.name);
+            assert_eq!(imported.required, attribute.required, "{}", attribute.name);
+        }
+    }
+
+    #[test]
+    fn test_custom_options() {
+        // Arrange
+        let path = Path::new("tests/data/model_custom_options.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Custom options should be allowed");
+
+        // Assert
+        let attribute = &model.objects[0].attributes[0];
+        assert_eq!(attribute.options.len(), 1);
+        assert_eq!(attribute.options[0].key, "minimum");
+        assert_eq!(attribute.custom_options.len(), 1);
+        assert_eq!(attribute.custom_options[0].key, "mycustomoption");
+        assert_eq!(attribute.custom_options[0].value, "custom value");
+    }
+
+    #[test]
+    fn test_json_schema_custom_options() {
+        // Arrange
+        let path = Path::new("tests/data/model_custom_options.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Sample".to_string()), false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let property = &schema["properties"]["name"];
+        assert_eq!(property["x-mycustomoption"], "custom value");
+        assert_eq!(property["minimum"], 1.0);
+        assert!(property.get("mycustomoption").is_none());
+    }
+
+    #[test]
+    fn test_strict_unknown_options() {
+        // Arrange
+        let path = Path::new("tests/data/model_unknown_options.md");
+        let model = DataModel::from_markdown(path).expect("Unknown options should not fail");
+        let mut validator = Validator::new();
+
+        // Act
+        validator.validate(&model);
+
+        // Assert
+        let warnings: Vec<_> = validator
+            .warnings
+            .iter()
+            .filter(|w| w.warning_type == WarningType::OptionWarning)
+            .collect();
+
+        assert!(validator.is_valid);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].attribute, Some("name".to_string()));
+        assert!(warnings[0].message.contains("'mycustomoption'"));
+    }
+
+    #[test]
+    fn test_json_schema_numeric_formats() {
+        // Arrange
+        let path = Path::new("tests/data/model_numeric.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Transaction".to_string()), false)
+            .expect("Could not generate JSON schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let properties = &schema["properties"];
+        assert_eq!(properties["id"]["type"], "integer");
+        assert_eq!(properties["id"]["format"], "int64");
+        assert_eq!(properties["amount"]["type"], "string");
+        assert_eq!(properties["amount"]["format"], "decimal");
+        assert_eq!(properties["fees"]["items"]["type"], "string");
+        assert_eq!(properties["fees"]["items"]["format"], "decimal");
+    }
+
+    #[test]
+    fn test_mixed_enum_dtypes_warning() {
+        // Arrange
+        let path = Path::new("tests/data/model_mixed_enum.md");
+        let model = DataModel::from_markdown(path).expect("Mixed dtypes should not be fatal");
+
+        // Act
+        let mut validator = mdmodels::validation::Validator::new();
+        validator.validate(&model);
+
+        // Assert
+        assert!(validator.is_valid);
+        assert!(validator.errors.is_empty());
+
+        let warnings: Vec<_> = validator
+            .warnings
+            .iter()
+            .filter(|w| w.warning_type == WarningType::TypeWarning)
+            .collect();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].object, Some("Test".to_string()));
+        assert_eq!(warnings[0].attribute, Some("status".to_string()));
+        assert!(warnings[0].message.contains("(Status)"));
+        assert!(warnings[0].message.contains("(string)"));
+    }
+
+    #[test]
+    fn test_documentation_warnings() {
+        // Arrange
+        let content = "\
+### Documented
+
+A documented object.
+
+- name
+  - Type: string
+  - Term: schema:name
+
+### Undocumented
+
+- value
+  - Type: Single
+
+### Single
+
+```python
+ONLY = \"only\"
+```
+";
+        let model = DataModel::from_markdown_string(content).expect("Warnings should not be fatal");
+
+        // Act
+        let mut validator = mdmodels::validation::Validator::new();
+        validator.validate(&model);
+
+        // Assert
+        assert!(validator.is_valid);
+        assert!(validator.has_warnings());
+
+        let warnings: Vec<(WarningType, Option<String>, Option<String>)> = validator
+            .warnings
+            .iter()
+            .map(|w| {
+                (
+                    w.warning_type.clone(),
+                    w.object.clone(),
+                    w.attribute.clone(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            warnings,
+            vec![
+                (
+                    WarningType::DocstringWarning,
+                    Some("Undocumented".to_string()),
+                    None
+                ),
+                (
+                    WarningType::TermWarning,
+                    Some("Undocumented".to_string()),
+                    Some("value".to_string())
+                ),
+                (WarningType::EnumWarning, Some("Single".to_string()), None),
+            ]
+        );
+        assert!(validator.to_string().contains("Warnings:"));
+    }
+
+    #[test]
+    fn test_require_docstrings_warnings() {
+        // Arrange
+        let path = Path::new("tests/data/model_docstrings.md");
+        let mut model = DataModel::from_markdown(path).expect("Warnings should not be fatal");
+
+        // Act
+        let mut validator = mdmodels::validation::Validator::new();
+        validator.validate(&model);
+
+        model.config.as_mut().unwrap().require_docstrings = false;
+        let mut relaxed = mdmodels::validation::Validator::new();
+        relaxed.validate(&model);
+
+        // Assert
+        let docstring_warnings = |validator: &mdmodels::validation::Validator| {
+            validator
+                .warnings
+                .iter()
+                .filter(|w| w.warning_type == WarningType::DocstringWarning)
+                .map(|w| (w.object.clone().unwrap(), w.attribute.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        assert!(validator.is_valid);
+        assert_eq!(
+            docstring_warnings(&validator),
+            vec![
+                ("Documented".to_string(), Some("value".to_string())),
+                ("Undocumented".to_string(), None),
+                ("Undocumented".to_string(), Some("count".to_string())),
+            ]
+        );
+        assert!(validator
+            .warnings
+            .iter()
+            .filter(|w| w.attribute.is_some())
+            .all(|w| !w.positions.is_empty()));
+        assert_eq!(
+            docstring_warnings(&relaxed),
+            vec![("Undocumented".to_string(), None)]
+        );
+    }
+
+    #[test]
+    fn test_subset() {
+        // Arrange
+        let path = Path::new("tests/data/model_subset.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let subset = model
+            .subset(&["Measurement"])
+            .expect("Could not extract subset");
+
+        // Assert
+        let objects: Vec<&str> = subset.objects.iter().map(|o| o.name.as_str()).collect();
+        let enums: Vec<&str> = subset.enums.iter().map(|e| e.name.as_str()).collect();
+
+        assert_eq!(objects, vec!["Measurement", "Sample"]);
+        assert_eq!(enums, vec!["Unit"]);
+        assert_eq!(subset.config, model.config);
+    }
+
+    #[test]
+    fn test_subset_multiple_roots() {
+        // Arrange
+        let path = Path::new("tests/data/model_subset.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let dataset = model
+            .subset_objects_reachable_from("Dataset")
+            .expect("Could not extract subset");
+        let subset = model
+            .subset(&["Sample", "Author"])
+            .expect("Could not extract subset");
+
+        // Assert
+        assert_eq!(dataset.objects.len(), 4);
+        assert_eq!(dataset.enums.len(), 2);
+        assert_eq!(subset.objects.len(), 2);
+        assert_eq!(subset.enums.len(), 1);
+    }
+
+    #[test]
+    fn test_subset_missing_roots() {
+        // Arrange
+        let path = Path::new("tests/data/model_subset.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let result = model.subset(&["Dataset", "Missing", "Unknown"]);
+
+        // Assert
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Objects not found in model: Missing, Unknown"
+        );
+    }
+
+    #[test]
+    fn test_parse_in_memory() {
+        // Arrange
+        let content = "\
+### Sample
+
+- name
+  - Type: string
+- values
+  - Type: float[]
+";
+
+        // Act
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(model.objects.len(), 1);
+        assert_eq!(model.objects[0].name, "Sample");
+        assert_eq!(model.objects[0].attributes.len(), 2);
+        assert!(model.objects[0].attributes[1].is_array);
+    }
+
+    #[test]
+    fn test_parse_in_memory_invalid() {
+        // Arrange
+        let content = "\
+### Sample
+
+- name
+  - Type: Unknown
+";
+
+        // Act
+        let result = DataModel::from_markdown_string(content);
+
+        // Assert
+        let validator = result.expect_err("Unknown types should be rejected");
+        assert_eq!(validator.errors.len(), 1);
+        assert_eq!(validator.errors[0].attribute, Some("name".to_string()));
+    }
+
+    #[test]
+    fn test_validate_markdown_str() {
+        // Arrange
+        let valid = fs::read_to_string("tests/data/model.md").unwrap();
+        let invalid = fs::read_to_string("tests/data/model_invalid_names.md").unwrap();
+
+        // Act
+        let valid_result = mdmodels::validation::validate_markdown_str(&valid);
+        let invalid_result = mdmodels::validation::validate_markdown_str(&invalid);
+
+        // Assert
+        assert!(valid_result.is_valid);
+        assert!(valid_result.errors.is_empty());
+        assert!(!invalid_result.is_valid);
+        assert!(!invalid_result.errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_toml_frontmatter() {
+        // Arrange
+        let yaml_path = Path::new("tests/data/model.md");
+        let toml_path = Path::new("tests/data/model_toml_frontmatter.md");
+
+        // Act
+        let yaml_model = DataModel::from_markdown(yaml_path).expect("Could not parse markdown");
+        let toml_model = DataModel::from_markdown(toml_path).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(toml_model.config, yaml_model.config);
+        assert_eq!(
+            toml_model
+                .json_schema(Some("Test".to_string()), false)
+                .unwrap(),
+            yaml_model
+                .json_schema(Some("Test".to_string()), false)
+                .unwrap()
+        );
+    }
+}
+
+#[cfg(feature = "fetch")]
+mod fetch_tests {
+    use mdmodels::datamodel::DataModel;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Serves the given markdown content for a fixed number of requests.
+    fn serve(
+        content: &'static str,
+        status: &'static str,
+        requests: usize,
+    ) -> (String, JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind listener");
+        let address = listener.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    content.len(),
+                    content
+                )
+                .unwrap();
+            }
+        });
+
+        (format!("http://{}/model.md", address), handle)
+    }
+
+    const MODEL: &str = "### Remote\n\n- name\n  - Type: string\n";
+
+    #[test]
+    fn test_from_url_no_cache() {
+        // Arrange
+        let (url, handle) = serve(MODEL, "200 OK", 1);
+
+        // Act
+        let model = DataModel::from_url_no_cache(&url).expect("Could not fetch model");
+        handle.join().unwrap();
+
+        // Assert
+        assert_eq!(model.objects.len(), 1);
+        assert_eq!(model.objects[0].name, "Remote");
+    }
+
+    #[test]
+    fn test_from_url_not_found() {
+        // Arrange
+        let (url, handle) = serve("", "404 Not Found", 1);
+
+        // Act
+        let result = DataModel::from_url_no_cache(&url);
+        handle.join().unwrap();
+
+        // Assert
+        assert!(result.is_err());
+    }
 }